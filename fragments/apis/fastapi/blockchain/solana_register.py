@@ -102,6 +102,8 @@ async def get_registry_state_route():
         return {
             "authority": str(account["authority"]),
             "registration_count": str(account["registration_count"]),
+            "confirmed_count": str(account["confirmed_count"]),
+            "pending_count": str(account["pending_count"]),
         }
     except HTTPException:
         raise
@@ -4,7 +4,15 @@ from solders.keypair import Keypair
 from solders.pubkey import Pubkey
 from solana.constants import LAMPORTS_PER_SOL
 
-from fragments.solana_program_round import initialise_round, get_round_account, activate_round, complete_round
+from fragments.solana_program_round import (
+    initialise_round,
+    get_round_account,
+    get_next_round_id,
+    activate_round,
+    complete_round,
+    cancel_round,
+    join_round,
+)
 from fragments.solana_airdrop import send_and_confirm_airdrop
 from fragments.solana_transaction import confirm_recent_signature
 from fragments.solana_rpc import init_rpc_client
@@ -16,6 +24,11 @@ logger = logging.getLogger(__name__)
 # In-memory storage for keypairs
 # In production, use a secure key management service or encrypted database
 keypair_storage: dict[str, Keypair] = {}
+# Each stored authority runs a single round, keyed by the same address
+round_id_storage: dict[str, int] = {}
+
+# Rounds run for this many slots after their start slot
+ROUND_LENGTH_SLOTS = 150
 
 
 def get_program_address() -> Pubkey:
@@ -38,8 +51,10 @@ async def initialise_round_route():
         client = init_rpc_client()
         recent_slot = (await client.get_slot()).value
         start_slot = recent_slot + 3
+        end_slot = start_slot + ROUND_LENGTH_SLOTS
 
-        signature = await initialise_round(signer, program_address, start_slot)
+        round_id = await get_next_round_id(signer.pubkey(), program_address)
+        signature = await initialise_round(signer, program_address, round_id, start_slot, end_slot)
         confirmed = await confirm_recent_signature(signature)
 
         if not confirmed:
@@ -48,7 +63,14 @@ async def initialise_round_route():
                 detail="Transaction sent but confirmation timed out",
             )
 
-        return {"address": address, "start_slot": str(start_slot)}
+        round_id_storage[address] = round_id
+
+        return {
+            "address": address,
+            "round_id": str(round_id),
+            "start_slot": str(start_slot),
+            "end_slot": str(end_slot),
+        }
     except HTTPException:
         raise
     except Exception as e:
@@ -64,14 +86,30 @@ async def get_round(address: str):
             raise HTTPException(status_code=404)
 
         program_address = get_program_address()
-        account = await get_round_account(keypair.pubkey(), program_address)
+        account = await get_round_account(keypair.pubkey(), program_address, round_id_storage[address])
 
         return {
-            "start_slot": str(account["start_slot"]),
+            "round_id": str(account["round_id"]),
+            "start_slot": str(account["start_at"]),
+            "end_slot": str(account["end_at"]),
             "authority": str(account["authority"]),
+            "status": account["status"],
             "activated_at": str(account["activated_at"]) if account["activated_at"] is not None else None,
             "activated_by": str(account["activated_by"]) if account["activated_by"] is not None else None,
             "completed_at": str(account["completed_at"]) if account["completed_at"] is not None else None,
+            "cancelled_at": str(account["cancelled_at"]) if account["cancelled_at"] is not None else None,
+            "participant_count": str(account["participant_count"]),
+            "max_participants": (
+                str(account["max_participants"]) if account["max_participants"] is not None else None
+            ),
+            "entry_fee": str(account["entry_fee"]),
+            "commission_bps": account["commission_bps"],
+            "winner": str(account["winner"]) if account["winner"] is not None else None,
+            "draw_slot": str(account["draw_slot"]),
+            "drawn_index": account["draw"]["index"] if account["draw"] is not None else None,
+            "activation_bounty": str(account["activation_bounty"]),
+            "reschedule_count": account["reschedule_count"],
+            "schedule_mode": account["schedule_mode"],
         }
     except HTTPException:
         raise
@@ -92,7 +130,7 @@ async def activate_round_route(address: str):
         payer = Keypair()
         await send_and_confirm_airdrop(payer.pubkey(), LAMPORTS_PER_SOL)
 
-        signature = await activate_round(payer, program_address, round_authority.pubkey())
+        signature = await activate_round(payer, program_address, round_authority.pubkey(), round_id_storage[address])
         confirmed = await confirm_recent_signature(signature)
 
         if not confirmed:
@@ -118,7 +156,7 @@ async def complete_round_route(address: str):
 
         program_address = get_program_address()
 
-        signature = await complete_round(keypair, program_address)
+        signature = await complete_round(keypair, program_address, keypair.pubkey(), round_id_storage[address])
         confirmed = await confirm_recent_signature(signature)
 
         if not confirmed:
@@ -133,3 +171,58 @@ async def complete_round_route(address: str):
     except Exception as e:
         logger.error("Error completing round: %s", e)
         raise HTTPException(status_code=500, detail="Internal Server Error") from e
+
+
+@solana_round_router.post("/{address}/join", status_code=200)
+async def join_round_route(address: str):
+    try:
+        round_authority = keypair_storage.get(address)
+        if round_authority is None:
+            raise HTTPException(status_code=404)
+
+        program_address = get_program_address()
+
+        participant = Keypair()
+        await send_and_confirm_airdrop(participant.pubkey(), LAMPORTS_PER_SOL)
+
+        signature = await join_round(participant, program_address, round_authority.pubkey(), round_id_storage[address])
+        confirmed = await confirm_recent_signature(signature)
+
+        if not confirmed:
+            raise HTTPException(
+                status_code=500,
+                detail="Transaction sent but confirmation timed out",
+            )
+
+        return {"address": str(participant.pubkey())}
+    except HTTPException:
+        raise
+    except Exception as e:
+        logger.error("Error joining round: %s", e)
+        raise HTTPException(status_code=500, detail="Internal Server Error") from e
+
+
+@solana_round_router.patch("/{address}/cancel", status_code=200)
+async def cancel_round_route(address: str):
+    try:
+        keypair = keypair_storage.get(address)
+        if keypair is None:
+            raise HTTPException(status_code=404)
+
+        program_address = get_program_address()
+
+        signature = await cancel_round(keypair, program_address, round_id_storage[address])
+        confirmed = await confirm_recent_signature(signature)
+
+        if not confirmed:
+            raise HTTPException(
+                status_code=500,
+                detail="Transaction sent but confirmation timed out",
+            )
+
+        return None
+    except HTTPException:
+        raise
+    except Exception as e:
+        logger.error("Error cancelling round: %s", e)
+        raise HTTPException(status_code=500, detail="Internal Server Error") from e
@@ -44,6 +44,10 @@ class TestSolanaRegisterRoutes(unittest.TestCase):
         self.assertIn("authority", body)
         self.assertIn("registration_count", body)
         self.assertGreaterEqual(int(body["registration_count"]), 0)
+        self.assertEqual(
+            int(body["confirmed_count"]) + int(body["pending_count"]),
+            int(body["registration_count"]),
+        )
 
     def test_get_unconfirmed_registration(self):
         self.client.post("/solana/register/initialise")
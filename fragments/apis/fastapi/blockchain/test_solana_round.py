@@ -22,8 +22,10 @@ class TestSolanaRoundRoutes(unittest.IsolatedAsyncioTestCase):
         body = response.json()
         self.assertIn("address", body)
         self.assertIsInstance(body["address"], str)
+        self.assertEqual(body["round_id"], "0")
         self.assertIn("start_slot", body)
         self.assertIsInstance(body["start_slot"], str)
+        self.assertGreater(int(body["end_slot"]), int(body["start_slot"]))
 
     def test_get_pending_round(self):
         init_response = self.client.post("/solana/round/initialise")
@@ -33,8 +35,10 @@ class TestSolanaRoundRoutes(unittest.IsolatedAsyncioTestCase):
         get_response = self.client.get(f"/solana/round/{init_body['address']}")
         self.assertEqual(get_response.status_code, 200)
         get_body = get_response.json()
+        self.assertEqual(get_body["round_id"], "0")
         self.assertIsNotNone(get_body["start_slot"])
         self.assertIsNotNone(get_body["authority"])
+        self.assertEqual(get_body["status"], "Scheduled")
         self.assertIsNone(get_body["activated_at"])
         self.assertIsNone(get_body["activated_by"])
         self.assertIsNone(get_body["completed_at"])
@@ -59,6 +63,33 @@ class TestSolanaRoundRoutes(unittest.IsolatedAsyncioTestCase):
         self.assertIsNotNone(get_body["activated_by"])
         self.assertIsNone(get_body["completed_at"])
 
+    async def test_join_round(self):
+        init_response = self.client.post("/solana/round/initialise")
+        self.assertEqual(init_response.status_code, 200)
+        init_body = init_response.json()
+
+        at_slot = await wait_for_slot(int(init_body["start_slot"]))
+        self.assertTrue(at_slot, f"Round start slot {init_body['start_slot']} not reached within timeout")
+
+        activate_response = self.client.patch(f"/solana/round/{init_body['address']}/activate")
+        self.assertEqual(activate_response.status_code, 200)
+
+        join_response = self.client.post(f"/solana/round/{init_body['address']}/join")
+        self.assertEqual(join_response.status_code, 200)
+        self.assertIn("address", join_response.json())
+
+        get_response = self.client.get(f"/solana/round/{init_body['address']}")
+        self.assertEqual(get_response.status_code, 200)
+        get_body = get_response.json()
+        self.assertEqual(get_body["participant_count"], "1")
+        self.assertIsNone(get_body["max_participants"])
+        self.assertEqual(get_body["entry_fee"], "0")
+        self.assertIsNone(get_body["winner"])
+        self.assertIsNone(get_body["drawn_index"])
+        self.assertEqual(get_body["activation_bounty"], "0")
+        self.assertEqual(get_body["reschedule_count"], 0)
+        self.assertEqual(get_body["schedule_mode"], "Slot")
+
     async def test_complete_round(self):
         init_response = self.client.post("/solana/round/initialise")
         self.assertEqual(init_response.status_code, 200)
@@ -81,6 +112,22 @@ class TestSolanaRoundRoutes(unittest.IsolatedAsyncioTestCase):
         self.assertIsNotNone(get_body["activated_at"])
         self.assertIsNotNone(get_body["activated_by"])
         self.assertIsNotNone(get_body["completed_at"])
+        self.assertEqual(get_body["status"], "Completed")
+
+    def test_cancel_round(self):
+        init_response = self.client.post("/solana/round/initialise")
+        self.assertEqual(init_response.status_code, 200)
+        init_body = init_response.json()
+
+        cancel_response = self.client.patch(f"/solana/round/{init_body['address']}/cancel")
+        self.assertEqual(cancel_response.status_code, 200)
+
+        get_response = self.client.get(f"/solana/round/{init_body['address']}")
+        self.assertEqual(get_response.status_code, 200)
+        get_body = get_response.json()
+        self.assertEqual(get_body["status"], "Cancelled")
+        self.assertIsNotNone(get_body["cancelled_at"])
+        self.assertIsNone(get_body["activated_at"])
 
     def test_get_round_returns_404_for_non_existent_round(self):
         response = self.client.get("/solana/round/11111111111111111111111111111111")
@@ -53,6 +53,10 @@ describe("Solana register API", () => {
     assert.ok(res.authority);
     assert.ok(res.registration_count);
     assert.ok(Number(res.registration_count) >= 0);
+    assert.strictEqual(
+      BigInt(res.confirmed_count) + BigInt(res.pending_count),
+      BigInt(res.registration_count),
+    );
   });
 
   test("GET /solana/register/:address - fetches unconfirmed registration", async () => {
@@ -99,7 +99,7 @@ export const routes = (fastify: FastifyInstance, _: FastifyPluginOptions) => {
 
   fastify.get<{
     Reply: {
-      200: { authority: string; registration_count: string };
+      200: { authority: string; registration_count: string; confirmed_count: string; pending_count: string };
       500: { error: string };
     };
   }>("/register/registry", async (request, reply) => {
@@ -110,6 +110,8 @@ export const routes = (fastify: FastifyInstance, _: FastifyPluginOptions) => {
       return reply.code(200).send({
         authority: account.authority,
         registration_count: account.registration_count.toString(),
+        confirmed_count: account.confirmed_count.toString(),
+        pending_count: account.pending_count.toString(),
       });
     } catch (error) {
       request.log.error(error, "Error fetching registry state");
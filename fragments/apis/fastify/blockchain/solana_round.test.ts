@@ -19,7 +19,9 @@ describe("Solana round API", () => {
     assert.strictEqual(response.statusCode, 200);
     const res = response.json();
     assert.ok(res.address);
+    assert.strictEqual(res.round_id, "0");
     assert.ok(res.start_slot);
+    assert.ok(BigInt(res.end_slot) > BigInt(res.start_slot));
   });
 
   test("GET /solana/round/:address - fetches PENDING round info", async () => {
@@ -38,8 +40,10 @@ describe("Solana round API", () => {
 
     assert.strictEqual(getResponse.statusCode, 200);
     const getRes = getResponse.json();
+    assert.strictEqual(getRes.round_id, "0");
     assert.ok(getRes.start_slot);
     assert.ok(getRes.authority);
+    assert.strictEqual(getRes.status, "Scheduled");
     assert.strictEqual(getRes.activated_at, null);
     assert.strictEqual(getRes.activated_by, null);
     assert.strictEqual(getRes.completed_at, null);
@@ -79,6 +83,49 @@ describe("Solana round API", () => {
     assert.strictEqual(getRes.completed_at, null);
   });
 
+  test("POST /solana/round/:address/join - joins an active round", async () => {
+    const initResponse = await api.inject({
+      method: "POST",
+      url: "/solana/round/initialise",
+    });
+    assert.strictEqual(initResponse.statusCode, 200);
+    const initBody = initResponse.json();
+
+    const atSlot = await waitForSlot(BigInt(initBody.start_slot));
+    if (!atSlot) {
+      assert.fail(`Round start slot ${initBody.start_slot} not reached within timeout`);
+    }
+
+    const activateResponse = await api.inject({
+      method: "PATCH",
+      url: `/solana/round/${initBody.address}/activate`,
+    });
+    assert.strictEqual(activateResponse.statusCode, 200);
+
+    const joinResponse = await api.inject({
+      method: "POST",
+      url: `/solana/round/${initBody.address}/join`,
+    });
+    assert.strictEqual(joinResponse.statusCode, 200);
+    assert.ok(joinResponse.json().address);
+
+    const getResponse = await api.inject({
+      method: "GET",
+      url: `/solana/round/${initBody.address}`,
+    });
+
+    assert.strictEqual(getResponse.statusCode, 200);
+    const getRes = getResponse.json();
+    assert.strictEqual(getRes.participant_count, "1");
+    assert.strictEqual(getRes.max_participants, null);
+    assert.strictEqual(getRes.entry_fee, "0");
+    assert.strictEqual(getRes.winner, null);
+    assert.strictEqual(getRes.drawn_index, null);
+    assert.strictEqual(getRes.activation_bounty, "0");
+    assert.strictEqual(getRes.reschedule_count, 0);
+    assert.strictEqual(getRes.schedule_mode, "Slot");
+  });
+
   test("PATCH /solana/round/:address/complete - completes a round", async () => {
     const initResponse = await api.inject({
       method: "POST",
@@ -116,6 +163,33 @@ describe("Solana round API", () => {
     assert.ok(getRes.activated_at);
     assert.ok(getRes.activated_by);
     assert.ok(getRes.completed_at);
+    assert.strictEqual(getRes.status, "Completed");
+  });
+
+  test("PATCH /solana/round/:address/cancel - cancels a round", async () => {
+    const initResponse = await api.inject({
+      method: "POST",
+      url: "/solana/round/initialise",
+    });
+    assert.strictEqual(initResponse.statusCode, 200);
+    const initBody = initResponse.json();
+
+    const patchResponse = await api.inject({
+      method: "PATCH",
+      url: `/solana/round/${initBody.address}/cancel`,
+    });
+    assert.strictEqual(patchResponse.statusCode, 200);
+
+    const getResponse = await api.inject({
+      method: "GET",
+      url: `/solana/round/${initBody.address}`,
+    });
+
+    assert.strictEqual(getResponse.statusCode, 200);
+    const getRes = getResponse.json();
+    assert.strictEqual(getRes.status, "Cancelled");
+    assert.ok(getRes.cancelled_at);
+    assert.strictEqual(getRes.activated_at, null);
   });
 
   test("GET /solana/round/:address - returns 404 for non-existent round", async () => {
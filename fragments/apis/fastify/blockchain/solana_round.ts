@@ -2,27 +2,35 @@ import { type FastifyInstance, type FastifyPluginOptions } from "fastify";
 import { Address, address, generateKeyPairSigner, type KeyPairSigner } from "@solana/kit";
 import {
   activateRound,
+  cancelRound,
   completeRound,
+  getNextRoundId,
   getRoundAccount,
   initialiseRound,
+  joinRound,
 } from "../../../solana_program_round/solana_round_interface";
 import { getEnvVar } from "../../../env_vars/env_vars_utils";
 import { sendAndConfirmAirdrop } from "../../../solana_airdrop/solana_airdrop_utils";
 import { confirmRecentSignature } from "../../../solana_transaction/solana_transaction_utils";
 import { initRpcClient } from "../../../solana_rpc/solana_rpc_utils";
 import { LAMPORTS_PER_SOL } from "@solana/web3.js";
-import { isSome, unwrapOption } from "@solana/kit";
+import { isSome } from "@solana/kit";
 
 // In-memory storage for keypairs
 // In production, use a secure key management service or encrypted database
 const keypairStorage: Record<string, KeyPairSigner> = {};
+// Each stored authority runs a single round, keyed by the same address
+const roundIdStorage: Record<string, bigint> = {};
+
+// Rounds run for this many slots after their start slot
+const ROUND_LENGTH_SLOTS = 150n;
 
 export const routes = (fastify: FastifyInstance, _: FastifyPluginOptions) => {
   const client = initRpcClient();
 
   fastify.post<{
     Reply: {
-      200: { address: string; start_slot: string };
+      200: { address: string; round_id: string; start_slot: string; end_slot: string };
       400: { error: string };
       500: { error: string };
     };
@@ -37,17 +45,23 @@ export const routes = (fastify: FastifyInstance, _: FastifyPluginOptions) => {
 
       const recentSlot = await client.getSlot({ commitment: "confirmed" }).send();
       const startSlot = recentSlot + 3n;
+      const endSlot = startSlot + ROUND_LENGTH_SLOTS;
 
-      const signature = await initialiseRound(signer, programAddress, startSlot);
+      const roundId = await getNextRoundId(signer.address, programAddress);
+      const signature = await initialiseRound(signer, programAddress, roundId, startSlot, endSlot);
       const confirmed = await confirmRecentSignature(signature);
 
       if (!confirmed) {
         return reply.code(500).send({ error: "Transaction sent but confirmation timed out" });
       }
 
+      roundIdStorage[signer.address] = roundId;
+
       return reply.code(200).send({
         address: signer.address,
+        round_id: roundId.toString(),
         start_slot: startSlot.toString(),
+        end_slot: endSlot.toString(),
       });
     } catch (error) {
       request.log.error(error, "Error initialising round");
@@ -59,11 +73,25 @@ export const routes = (fastify: FastifyInstance, _: FastifyPluginOptions) => {
     Params: { address: string };
     Reply: {
       200: {
+        round_id: string;
         start_slot: string;
+        end_slot: string;
         authority: string;
+        status: string;
         activated_at: string | null;
         activated_by: string | null;
         completed_at: string | null;
+        cancelled_at: string | null;
+        participant_count: string;
+        max_participants: string | null;
+        entry_fee: string;
+        commission_bps: number;
+        winner: string | null;
+        draw_slot: string;
+        drawn_index: number | null;
+        activation_bounty: string;
+        reschedule_count: number;
+        schedule_mode: string;
       };
       404: void;
       500: { error: string };
@@ -78,14 +106,30 @@ export const routes = (fastify: FastifyInstance, _: FastifyPluginOptions) => {
         return reply.code(404).send();
       }
 
-      const account = await getRoundAccount(keypair.address, programAddress);
+      const account = await getRoundAccount(keypair.address, programAddress, roundIdStorage[address]);
+      const { status } = account;
+      const activated = status.__kind === "Active" || status.__kind === "Completed" ? status : null;
 
       return reply.code(200).send({
-        start_slot: account.start_slot.toString(),
+        round_id: account.round_id.toString(),
+        start_slot: account.start_at.toString(),
+        end_slot: account.end_at.toString(),
         authority: account.authority,
-        activated_at: isSome(account.activated_at) && unwrapOption(account.activated_at)?.toString() || null,
-        activated_by: isSome(account.activated_by) && unwrapOption(account.activated_by) || null,
-        completed_at: isSome(account.completed_at) && unwrapOption(account.completed_at)?.toString() || null,
+        status: status.__kind,
+        activated_at: activated?.activated_at.toString() ?? null,
+        activated_by: activated?.activated_by ?? null,
+        completed_at: status.__kind === "Completed" ? status.completed_at.toString() : null,
+        cancelled_at: status.__kind === "Cancelled" ? status.cancelled_at.toString() : null,
+        participant_count: account.participant_count.toString(),
+        max_participants: isSome(account.max_participants) ? account.max_participants.value.toString() : null,
+        entry_fee: account.entry_fee.toString(),
+        commission_bps: account.commission_bps,
+        winner: isSome(account.winner) ? account.winner.value : null,
+        draw_slot: account.draw_slot.toString(),
+        drawn_index: isSome(account.draw) ? account.draw.value.index : null,
+        activation_bounty: account.activation_bounty.toString(),
+        reschedule_count: account.reschedule_count,
+        schedule_mode: account.schedule_mode,
       });
     } catch (error) {
       request.log.error(error, "Error fetching round account");
@@ -113,7 +157,12 @@ export const routes = (fastify: FastifyInstance, _: FastifyPluginOptions) => {
       const signer = await generateKeyPairSigner();
       await sendAndConfirmAirdrop(signer.address, BigInt(LAMPORTS_PER_SOL));
 
-      const signature = await activateRound(signer, programAddress, address(roundAddress));
+      const signature = await activateRound(
+        signer,
+        programAddress,
+        address(roundAddress),
+        roundIdStorage[roundAddress],
+      );
       const confirmed = await confirmRecentSignature(signature);
 
       if (!confirmed) {
@@ -144,7 +193,7 @@ export const routes = (fastify: FastifyInstance, _: FastifyPluginOptions) => {
         return reply.code(404).send();
       }
 
-      const signature = await completeRound(keypair, programAddress);
+      const signature = await completeRound(keypair, programAddress, keypair.address, roundIdStorage[address]);
       const confirmed = await confirmRecentSignature(signature);
 
       if (!confirmed) {
@@ -157,6 +206,76 @@ export const routes = (fastify: FastifyInstance, _: FastifyPluginOptions) => {
       return reply.code(500).send({ error: "Internal Server Error" });
     }
   });
+
+  fastify.post<{
+    Params: { address: string };
+    Reply: {
+      200: { address: string };
+      404: void;
+      500: { error: string };
+    };
+  }>("/round/:address/join", async (request, reply) => {
+    try {
+      const { address: roundAddress } = request.params;
+      const programAddress = getProgramAddress();
+      const roundExists = keypairStorage[roundAddress];
+
+      if (!roundExists) {
+        return reply.code(404).send();
+      }
+
+      const participant = await generateKeyPairSigner();
+      await sendAndConfirmAirdrop(participant.address, BigInt(LAMPORTS_PER_SOL));
+
+      const signature = await joinRound(
+        participant,
+        programAddress,
+        address(roundAddress),
+        roundIdStorage[roundAddress],
+      );
+      const confirmed = await confirmRecentSignature(signature);
+
+      if (!confirmed) {
+        return reply.code(500).send({ error: "Transaction sent but confirmation timed out" });
+      }
+
+      return reply.code(200).send({ address: participant.address });
+    } catch (error) {
+      request.log.error(error, "Error joining round");
+      return reply.code(500).send({ error: "Internal Server Error" });
+    }
+  });
+
+  fastify.patch<{
+    Params: { address: string };
+    Reply: {
+      200: void;
+      404: void;
+      500: { error: string };
+    };
+  }>("/round/:address/cancel", async (request, reply) => {
+    try {
+      const { address } = request.params;
+      const programAddress = getProgramAddress();
+      const keypair = keypairStorage[address];
+
+      if (!keypair) {
+        return reply.code(404).send();
+      }
+
+      const signature = await cancelRound(keypair, programAddress, roundIdStorage[address]);
+      const confirmed = await confirmRecentSignature(signature);
+
+      if (!confirmed) {
+        return reply.code(500).send({ error: "Transaction sent but confirmation timed out" });
+      }
+
+      return reply.code(200).send();
+    } catch (error) {
+      request.log.error(error, "Error cancelling round");
+      return reply.code(500).send({ error: "Internal Server Error" });
+    }
+  });
 };
 
 const getProgramAddress = (): Address => {
@@ -0,0 +1,86 @@
+// Builds the merkle tree behind the `register` program's allowlist.
+// Hashing must mirror `register::verify_allowlist_proof`:
+// - leaves are `sha256(0x00 || pubkey)`
+// - nodes are `sha256(0x01 || min(a, b) || max(a, b))`, so proofs don't need left/right flags
+// - an odd node at the end of a layer is carried up to the next layer unchanged
+
+use sha2::{Digest, Sha256};
+
+pub type Hash = [u8; 32];
+
+const LEAF_PREFIX: &[u8] = &[0];
+const NODE_PREFIX: &[u8] = &[1];
+
+pub fn leaf_hash(key: &[u8; 32]) -> Hash {
+    let mut hasher = Sha256::new();
+    hasher.update(LEAF_PREFIX);
+    hasher.update(key);
+    hasher.finalize().into()
+}
+
+pub fn node_hash(a: &Hash, b: &Hash) -> Hash {
+    let (left, right) = if a <= b { (a, b) } else { (b, a) };
+    let mut hasher = Sha256::new();
+    hasher.update(NODE_PREFIX);
+    hasher.update(left);
+    hasher.update(right);
+    hasher.finalize().into()
+}
+
+pub struct AllowlistTree {
+    keys: Vec<[u8; 32]>,
+    // `layers[0]` holds the leaves, the last layer holds the root.
+    layers: Vec<Vec<Hash>>,
+}
+
+impl AllowlistTree {
+    pub fn new(keys: &[[u8; 32]]) -> Self {
+        assert!(!keys.is_empty(), "an allowlist needs at least one key");
+
+        let mut layers = vec![keys.iter().map(leaf_hash).collect::<Vec<_>>()];
+        while layers.last().unwrap().len() > 1 {
+            let next = layers
+                .last()
+                .unwrap()
+                .chunks(2)
+                .map(|pair| match pair {
+                    [a, b] => node_hash(a, b),
+                    [a] => *a,
+                    _ => unreachable!(),
+                })
+                .collect();
+            layers.push(next);
+        }
+
+        Self {
+            keys: keys.to_vec(),
+            layers,
+        }
+    }
+
+    pub fn root(&self) -> Hash {
+        self.layers.last().unwrap()[0]
+    }
+
+    // Sibling hashes from leaf to root, or `None` if the key isn't on the allowlist.
+    pub fn proof(&self, key: &[u8; 32]) -> Option<Vec<Hash>> {
+        let mut index = self.keys.iter().position(|k| k == key)?;
+        let mut proof = Vec::new();
+
+        for layer in &self.layers[..self.layers.len() - 1] {
+            if let Some(sibling) = layer.get(index ^ 1) {
+                proof.push(*sibling);
+            }
+            index /= 2;
+        }
+
+        Some(proof)
+    }
+}
+
+pub fn verify(root: &Hash, key: &[u8; 32], proof: &[Hash]) -> bool {
+    let computed = proof
+        .iter()
+        .fold(leaf_hash(key), |acc, sibling| node_hash(&acc, sibling));
+    computed == *root
+}
@@ -0,0 +1,51 @@
+// Reads base58 pubkeys (one per line) from stdin and prints the allowlist
+// root and every key's proof as JSON, hex encoded.
+//
+// cargo run -p allowlist < invitees.txt
+
+use std::io::{self, BufRead};
+
+use allowlist::AllowlistTree;
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{b:02x}")).collect()
+}
+
+fn main() {
+    let keys: Vec<(String, [u8; 32])> = io::stdin()
+        .lock()
+        .lines()
+        .map(|line| line.expect("failed to read stdin").trim().to_string())
+        .filter(|line| !line.is_empty())
+        .map(|line| {
+            let key: [u8; 32] = bs58::decode(&line)
+                .into_vec()
+                .ok()
+                .and_then(|bytes| bytes.try_into().ok())
+                .unwrap_or_else(|| panic!("invalid pubkey: {line}"));
+            (line, key)
+        })
+        .collect();
+
+    let tree = AllowlistTree::new(&keys.iter().map(|(_, key)| *key).collect::<Vec<_>>());
+
+    let proofs: Vec<String> = keys
+        .iter()
+        .map(|(address, key)| {
+            let proof: Vec<String> = tree
+                .proof(key)
+                .unwrap()
+                .iter()
+                .map(|hash| format!("\"{}\"", to_hex(hash)))
+                .collect();
+            format!("    \"{address}\": [{}]", proof.join(", "))
+        })
+        .collect();
+
+    println!("{{");
+    println!("  \"root\": \"{}\",", to_hex(&tree.root()));
+    println!("  \"proofs\": {{");
+    println!("{}", proofs.join(",\n"));
+    println!("  }}");
+    println!("}}");
+}
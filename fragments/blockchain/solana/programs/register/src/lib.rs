@@ -3,82 +3,571 @@
 
 use anchor_lang::prelude::*;
 use anchor_lang::solana_program::bpf_loader_upgradeable;
+use anchor_lang::solana_program::ed25519_program;
+use anchor_lang::solana_program::hash::hashv;
+use anchor_lang::solana_program::sysvar::instructions::{
+    self as instructions_sysvar, load_current_index_checked, load_instruction_at_checked,
+};
+use anchor_lang::system_program;
 
 declare_id!("DPEfE7E9LExX61taVQRQHpxZGkFEKLzRqwfCDMtzFg2K");
 
 const ACCOUNT_DISCRIMINATOR_SPACE: usize = 8;
+// Approvals are recorded as a `u8` bitmap on each `Registration`
+const MAX_APPROVERS: usize = 8;
+// Layout of the Ed25519 program's instruction data: a signature count and padding byte,
+// followed by one 14 byte offsets struct per signature
+const ED25519_OFFSETS_START: usize = 2;
+const ED25519_OFFSETS_SIZE: usize = 14;
+
+// Layout versions of the register accounts. New fields are only ever appended, after `version`.
+// An older account's data can end in stale bytes, left behind when `approvers` shrank, so the
+// new fields don't reliably decode as zero: the migrate instructions set every field added since
+// the account's version and zero the whole account before writing it back.
+// Bump these whenever a field is added and set its default in the migrate instructions.
+const REGISTRY_STATE_VERSION: u8 = 3;
+const REGISTRATION_VERSION: u8 = 2;
+
+// Governance fallback for when the program has been made immutable and so has no upgrade authority
+// to initialise the registry. The key is supplied at build time through
+// `REGISTER_BOOTSTRAP_AUTHORITY`, so no key is compiled in unless the builder names one.
+#[cfg(feature = "bootstrap-authority")]
+const BOOTSTRAP_AUTHORITY: Pubkey = Pubkey::from_str_const(env!("REGISTER_BOOTSTRAP_AUTHORITY"));
 
 #[program]
 pub mod register {
     use super::*;
 
-    pub fn initialise_registry(ctx: Context<InitialiseRegistry>) -> Result<()> {
+    pub fn initialise_registry(ctx: Context<InitialiseRegistry>, fifo_only: bool) -> Result<()> {
         let registry_state = &mut ctx.accounts.registry_state;
         registry_state.authority = ctx.accounts.authority.key();
         registry_state.registration_count = 0;
+        registry_state.fifo_only = fifo_only;
+        registry_state.next_to_confirm = 0;
+        registry_state.approvers = Vec::new();
+        registry_state.approval_threshold = 0;
+        registry_state.allowlist_root = None;
+        registry_state.version = REGISTRY_STATE_VERSION;
+        registry_state.confirmed_count = 0;
+        registry_state.pending_count = 0;
         msg!("Registry initialised by {}", ctx.accounts.authority.key());
+
+        emit_cpi!(RegistryInitialisedEvent {
+            registry: registry_state.key(),
+            authority: registry_state.authority,
+            fifo_only,
+            initialised_at: Clock::get()?.slot,
+        });
+
         Ok(())
     }
 
-    pub fn register(ctx: Context<Register>) -> Result<()> {
+    pub fn register(ctx: Context<Register>, proof: Vec<[u8; 32]>) -> Result<()> {
         let registry_state = &mut ctx.accounts.registry_state;
         let registration = &mut ctx.accounts.registration;
         let clock = Clock::get()?;
 
-        registration.registrant = ctx.accounts.registrant.key();
-        registration.registration_index = registry_state.registration_count;
-        registration.registered_at = clock.slot;
-        registration.confirmed_at = None;
+        if let Some(root) = registry_state.allowlist_root {
+            require!(
+                verify_allowlist_proof(&root, &ctx.accounts.registrant.key(), &proof),
+                RegisterError::NotOnAllowlist
+            );
+        }
 
-        registry_state.registration_count += 1;
+        let event = record_registration(
+            registry_state,
+            registration,
+            ctx.accounts.registrant.key(),
+            clock.slot,
+        )?;
+        emit_cpi!(event);
 
-        emit!(RegisteredEvent {
-            registrant: registration.registrant,
-            registration_index: registration.registration_index,
-            registered_at: registration.registered_at,
-        });
+        Ok(())
+    }
 
-        msg!(
-            "Registrant {} registered at index {}",
-            ctx.accounts.registrant.key(),
-            registration.registration_index
+    // The registry authority vouches for a registrant off-chain by signing
+    // `registrant || registry_state || expiry_slot || nonce` with ed25519. The signature is
+    // checked by an Ed25519 program instruction earlier in the same transaction, which this
+    // instruction finds via the instructions sysvar. Vouched registrants skip the allowlist.
+    pub fn register_with_voucher(
+        ctx: Context<RegisterWithVoucher>,
+        expiry_slot: u64,
+        nonce: u64,
+    ) -> Result<()> {
+        let registry_state = &mut ctx.accounts.registry_state;
+        let registration = &mut ctx.accounts.registration;
+        let registrant = ctx.accounts.registrant.key();
+        let clock = Clock::get()?;
+
+        require!(clock.slot <= expiry_slot, RegisterError::VoucherExpired);
+
+        let message = [
+            registrant.as_ref(),
+            registry_state.key().as_ref(),
+            &expiry_slot.to_le_bytes(),
+            &nonce.to_le_bytes(),
+        ]
+        .concat();
+        require!(
+            has_ed25519_signature(
+                &ctx.accounts.instructions,
+                &registry_state.authority,
+                &message
+            )?,
+            RegisterError::InvalidVoucher
         );
 
+        ctx.accounts.voucher_nonce.registrant = registrant;
+
+        let event = record_registration(registry_state, registration, registrant, clock.slot)?;
+        emit_cpi!(event);
+
         Ok(())
     }
 
     pub fn confirm_registration(ctx: Context<ConfirmRegistration>) -> Result<()> {
+        let registry_state = &mut ctx.accounts.registry_state;
+        let registration = &mut ctx.accounts.registration;
+        let clock = Clock::get()?;
+
+        // Registries with approvers configured can only be confirmed via `approve_registration`
+        require!(
+            registry_state.approval_threshold == 0,
+            RegisterError::ApprovalRequired
+        );
+        require!(
+            registration.confirmed_at.is_none(),
+            RegisterError::RegistrationAlreadyConfirmed
+        );
+
+        let event = confirm(
+            registry_state,
+            registration,
+            ctx.accounts.authority.key(),
+            clock.slot,
+        )?;
+        emit_cpi!(event);
+
+        Ok(())
+    }
+
+    pub fn configure_approvers(
+        ctx: Context<ConfigureApprovers>,
+        approvers: Vec<Pubkey>,
+        approval_threshold: u8,
+    ) -> Result<()> {
+        require!(
+            approvers.len() <= MAX_APPROVERS,
+            RegisterError::TooManyApprovers
+        );
+        // An empty approver set with a zero threshold hands confirmation back to the authority
+        require!(
+            usize::from(approval_threshold) <= approvers.len()
+                && (approval_threshold == 0) == approvers.is_empty(),
+            RegisterError::InvalidApprovalThreshold
+        );
+        for (i, approver) in approvers.iter().enumerate() {
+            require!(
+                !approvers[..i].contains(approver),
+                RegisterError::DuplicateApprover
+            );
+        }
+
+        let registry_state = &mut ctx.accounts.registry_state;
+        registry_state.approvers = approvers;
+        registry_state.approval_threshold = approval_threshold;
+        // Approval bits are positions in the old set, so pending registrations start over
+        registry_state.approver_epoch += 1;
+
+        msg!(
+            "Registry approvers configured: {} of {}",
+            approval_threshold,
+            registry_state.approvers.len()
+        );
+
+        emit_cpi!(ApproversConfiguredEvent {
+            registry: registry_state.key(),
+            authority: registry_state.authority,
+            approvers: registry_state.approvers.clone(),
+            approval_threshold,
+            configured_at: Clock::get()?.slot,
+        });
+
+        Ok(())
+    }
+
+    pub fn approve_registration(ctx: Context<ApproveRegistration>) -> Result<()> {
+        let registry_state = &mut ctx.accounts.registry_state;
         let registration = &mut ctx.accounts.registration;
+        let approver = ctx.accounts.approver.key();
         let clock = Clock::get()?;
 
+        // Each approver owns the bit matching their position in `RegistryState.approvers`
+        let position = registry_state
+            .approvers
+            .iter()
+            .position(|a| *a == approver)
+            .ok_or(RegisterError::NotAnApprover)?;
+        let approval_bit = 1u8 << position;
+
         require!(
             registration.confirmed_at.is_none(),
             RegisterError::RegistrationAlreadyConfirmed
         );
+        // Approvals given under an earlier approver set no longer count
+        if registration.approver_epoch != registry_state.approver_epoch {
+            registration.approvals = 0;
+            registration.approver_epoch = registry_state.approver_epoch;
+        }
+        require!(
+            registration.approvals & approval_bit == 0,
+            RegisterError::RegistrationAlreadyApproved
+        );
 
-        registration.confirmed_at = Some(clock.slot);
+        registration.approvals |= approval_bit;
 
-        emit!(ConfirmedEvent {
+        msg!(
+            "Registration for {} approved by {}",
+            registration.registrant,
+            approver
+        );
+
+        emit_cpi!(RegistrationApprovedEvent {
+            registry: registry_state.key(),
+            authority: registry_state.authority,
+            approver,
             registrant: registration.registrant,
             registration_index: registration.registration_index,
-            confirmed_at: clock.slot,
+            approved_at: clock.slot,
+        });
+
+        // The approval that reaches the threshold confirms the registration
+        if registration.approvals.count_ones() >= u32::from(registry_state.approval_threshold) {
+            let event = confirm(registry_state, registration, approver, clock.slot)?;
+            emit_cpi!(event);
+        }
+
+        Ok(())
+    }
+
+    // Pass `None` to open the registry to anyone
+    pub fn set_allowlist_root(
+        ctx: Context<SetAllowlistRoot>,
+        allowlist_root: Option<[u8; 32]>,
+    ) -> Result<()> {
+        let registry_state = &mut ctx.accounts.registry_state;
+        registry_state.allowlist_root = allowlist_root;
+
+        msg!("Registry allowlist root set to {:?}", allowlist_root);
+
+        emit_cpi!(AllowlistRootSetEvent {
+            registry: registry_state.key(),
+            authority: registry_state.authority,
+            allowlist_root,
+            set_at: Clock::get()?.slot,
         });
 
+        Ok(())
+    }
+
+    // Accounts written before `version` existed are too short for `Account<RegistryState>` to
+    // deserialise (and so for Anchor's `realloc` constraint), so the account is taken unchecked,
+    // grown here and then decoded, with each field added since its version then reset.
+    // The registration counts arrived in version 2 and can't be derived on-chain, so when
+    // migrating from an earlier version the authority passes in `confirmed_count`, counted
+    // off-chain from the `Registration` accounts. It is ignored for later versions.
+    pub fn migrate_registry_state(
+        ctx: Context<MigrateRegistryState>,
+        confirmed_count: u64,
+    ) -> Result<()> {
+        let registry_state_info = ctx.accounts.registry_state.to_account_info();
+        grow_account(
+            &registry_state_info,
+            &ctx.accounts.authority,
+            &ctx.accounts.system_program,
+            ACCOUNT_DISCRIMINATOR_SPACE + RegistryState::INIT_SPACE,
+        )?;
+        let mut registry_state =
+            RegistryState::try_deserialize(&mut &registry_state_info.data.borrow()[..])?;
+
+        require_keys_eq!(
+            registry_state.authority,
+            ctx.accounts.authority.key(),
+            RegisterError::Unauthorised
+        );
+        require!(
+            registry_state.version < REGISTRY_STATE_VERSION,
+            RegisterError::AccountAlreadyMigrated
+        );
+
+        let from_version = registry_state.version;
+        if from_version < 2 {
+            require!(
+                confirmed_count <= registry_state.registration_count,
+                RegisterError::InvalidConfirmedCount
+            );
+            registry_state.confirmed_count = confirmed_count;
+            registry_state.pending_count = registry_state.registration_count - confirmed_count;
+        }
+        if from_version < 3 {
+            registry_state.approver_epoch = 0;
+        }
+        registry_state.version = REGISTRY_STATE_VERSION;
+        let mut data = registry_state_info.data.borrow_mut();
+        data.fill(0);
+        registry_state.try_serialize(&mut &mut data[..])?;
+
         msg!(
-            "Registration confirmed for {} at index {}",
-            registration.registrant,
-            registration.registration_index
+            "Registry state migrated from version {} to {}",
+            from_version,
+            REGISTRY_STATE_VERSION
+        );
+
+        Ok(())
+    }
+
+    // Anyone can migrate a registration, the payer covers the extra rent
+    pub fn migrate_registration(
+        ctx: Context<MigrateRegistration>,
+        registrant: Pubkey,
+    ) -> Result<()> {
+        let registration_info = ctx.accounts.registration.to_account_info();
+        grow_account(
+            &registration_info,
+            &ctx.accounts.payer,
+            &ctx.accounts.system_program,
+            ACCOUNT_DISCRIMINATOR_SPACE + Registration::INIT_SPACE,
+        )?;
+        let mut registration =
+            Registration::try_deserialize(&mut &registration_info.data.borrow()[..])?;
+
+        require!(
+            registration.version < REGISTRATION_VERSION,
+            RegisterError::AccountAlreadyMigrated
+        );
+
+        let from_version = registration.version;
+        if from_version < 2 {
+            registration.approver_epoch = 0;
+        }
+        registration.version = REGISTRATION_VERSION;
+        let mut data = registration_info.data.borrow_mut();
+        data.fill(0);
+        registration.try_serialize(&mut &mut data[..])?;
+
+        msg!(
+            "Registration for {} migrated from version {} to {}",
+            registrant,
+            from_version,
+            REGISTRATION_VERSION
         );
 
         Ok(())
     }
 }
 
+// Same steps as Anchor's `realloc` constraint: top the account up to the rent exempt minimum
+// for `space`, then grow it, zeroing the new bytes.
+fn grow_account<'info>(
+    account: &AccountInfo<'info>,
+    payer: &Signer<'info>,
+    system_program: &Program<'info, System>,
+    space: usize,
+) -> Result<()> {
+    if account.data_len() >= space {
+        return Ok(());
+    }
+
+    let rent_minimum = Rent::get()?.minimum_balance(space);
+    if rent_minimum > account.lamports() {
+        system_program::transfer(
+            CpiContext::new(
+                system_program.to_account_info(),
+                system_program::Transfer {
+                    from: payer.to_account_info(),
+                    to: account.clone(),
+                },
+            ),
+            rent_minimum - account.lamports(),
+        )?;
+    }
+
+    account.realloc(space, true)?;
+
+    Ok(())
+}
+
+// The upgrade authority initialises the registry. Only once the program is immutable, and only
+// when built with the `bootstrap-authority` feature, does the bootstrap key take over.
+fn can_initialise_registry(upgrade_authority: Option<Pubkey>, authority: &Pubkey) -> bool {
+    match upgrade_authority {
+        Some(upgrade_authority) => upgrade_authority == *authority,
+        #[cfg(feature = "bootstrap-authority")]
+        None => *authority == BOOTSTRAP_AUTHORITY,
+        #[cfg(not(feature = "bootstrap-authority"))]
+        None => false,
+    }
+}
+
+// Returns the event for the caller to `emit_cpi!`, which needs the instruction's `ctx`
+fn record_registration(
+    registry_state: &mut Account<RegistryState>,
+    registration: &mut Registration,
+    registrant: Pubkey,
+    slot: u64,
+) -> Result<RegisteredEvent> {
+    registration.registrant = registrant;
+    registration.registration_index = registry_state.registration_count;
+    registration.registered_at = slot;
+    registration.confirmed_at = None;
+    registration.approvals = 0;
+    registration.version = REGISTRATION_VERSION;
+    registration.approver_epoch = registry_state.approver_epoch;
+
+    registry_state.registration_count += 1;
+    registry_state.pending_count += 1;
+
+    msg!(
+        "Registrant {} registered at index {}",
+        registrant,
+        registration.registration_index
+    );
+
+    Ok(RegisteredEvent {
+        registry: registry_state.key(),
+        authority: registry_state.authority,
+        registrant: registration.registrant,
+        registration_index: registration.registration_index,
+        registered_at: registration.registered_at,
+    })
+}
+
+// Looks for an Ed25519 program instruction, before the current one, that verified `message`
+// against `signer`. Offsets must point into the Ed25519 instruction's own data (`u16::MAX`),
+// otherwise the verified bytes could differ from the ones read here.
+fn has_ed25519_signature(
+    instructions: &AccountInfo,
+    signer: &Pubkey,
+    message: &[u8],
+) -> Result<bool> {
+    let current_index = load_current_index_checked(instructions)?;
+
+    for index in 0..current_index {
+        let instruction = load_instruction_at_checked(index.into(), instructions)?;
+        if instruction.program_id != ed25519_program::ID {
+            continue;
+        }
+
+        let data = &instruction.data;
+        let signature_count = data.first().copied().unwrap_or(0);
+
+        for i in 0..usize::from(signature_count) {
+            let start = ED25519_OFFSETS_START + i * ED25519_OFFSETS_SIZE;
+            let Some(offsets) = data.get(start..start + ED25519_OFFSETS_SIZE) else {
+                break;
+            };
+            let read_u16 =
+                |at: usize| usize::from(u16::from_le_bytes([offsets[at], offsets[at + 1]]));
+
+            let signature_instruction_index = read_u16(2);
+            let public_key_offset = read_u16(4);
+            let public_key_instruction_index = read_u16(6);
+            let message_offset = read_u16(8);
+            let message_size = read_u16(10);
+            let message_instruction_index = read_u16(12);
+
+            let self_referencing = [
+                signature_instruction_index,
+                public_key_instruction_index,
+                message_instruction_index,
+            ]
+            .iter()
+            .all(|index| *index == usize::from(u16::MAX));
+
+            if self_referencing
+                && data.get(public_key_offset..public_key_offset + 32) == Some(signer.as_ref())
+                && data.get(message_offset..message_offset + message_size) == Some(message)
+            {
+                return Ok(true);
+            }
+        }
+    }
+
+    Ok(false)
+}
+
+// Mirrors the `allowlist` crate used to build the tree off-chain:
+// leaves are `sha256(0x00 || pubkey)` and nodes are `sha256(0x01 || min(a, b) || max(a, b))`.
+fn verify_allowlist_proof(root: &[u8; 32], registrant: &Pubkey, proof: &[[u8; 32]]) -> bool {
+    let leaf = hashv(&[&[0], registrant.as_ref()]).to_bytes();
+    let computed = proof.iter().fold(leaf, |acc, sibling| {
+        let (left, right) = if acc <= *sibling {
+            (acc, *sibling)
+        } else {
+            (*sibling, acc)
+        };
+        hashv(&[&[1], &left, &right]).to_bytes()
+    });
+    computed == *root
+}
+
+// `confirmed_by` is the registry authority, or the approver whose approval met the threshold.
+// Returns the event for the caller to `emit_cpi!`.
+fn confirm(
+    registry_state: &mut Account<RegistryState>,
+    registration: &mut Registration,
+    confirmed_by: Pubkey,
+    slot: u64,
+) -> Result<ConfirmedEvent> {
+    // In FIFO mode registrations can only be confirmed in the order they were made
+    if registry_state.fifo_only {
+        require!(
+            registration.registration_index == registry_state.next_to_confirm,
+            RegisterError::RegistrationNotNextInQueue
+        );
+        registry_state.next_to_confirm += 1;
+    }
+
+    registration.confirmed_at = Some(slot);
+    // Only off if a migration was given more confirmations than had really been made
+    registry_state.pending_count = registry_state
+        .pending_count
+        .checked_sub(1)
+        .ok_or(RegisterError::NoPendingRegistrations)?;
+    registry_state.confirmed_count += 1;
+
+    msg!(
+        "Registration confirmed for {} at index {}",
+        registration.registrant,
+        registration.registration_index
+    );
+
+    Ok(ConfirmedEvent {
+        registry: registry_state.key(),
+        authority: registry_state.authority,
+        confirmed_by,
+        registrant: registration.registrant,
+        registration_index: registration.registration_index,
+        confirmed_at: slot,
+    })
+}
+
 #[account]
 #[derive(InitSpace)]
 pub struct RegistryState {
     pub authority: Pubkey,
     pub registration_count: u64,
+    pub fifo_only: bool,
+    pub next_to_confirm: u64,
+    #[max_len(MAX_APPROVERS)]
+    pub approvers: Vec<Pubkey>,
+    pub approval_threshold: u8,
+    pub allowlist_root: Option<[u8; 32]>,
+    pub version: u8,
+    pub confirmed_count: u64,
+    pub pending_count: u64,
+    // Bumped whenever the approver set changes
+    pub approver_epoch: u64,
 }
 
 #[account]
@@ -88,8 +577,19 @@ pub struct Registration {
     pub registration_index: u64,
     pub registered_at: u64,
     pub confirmed_at: Option<u64>,
+    pub approvals: u8,
+    pub version: u8,
+    // The registry's `approver_epoch` that `approvals` were given under
+    pub approver_epoch: u64,
 }
 
+#[account]
+#[derive(InitSpace)]
+pub struct VoucherNonce {
+    pub registrant: Pubkey,
+}
+
+#[event_cpi]
 #[derive(Accounts)]
 pub struct InitialiseRegistry<'info> {
     #[account(mut)]
@@ -109,7 +609,7 @@ pub struct InitialiseRegistry<'info> {
             &[crate::ID.as_ref()],
             &bpf_loader_upgradeable::id()
         ).0,
-        constraint = program_data.upgrade_authority_address == Some(authority.key())
+        constraint = can_initialise_registry(program_data.upgrade_authority_address, &authority.key())
             @ RegisterError::Unauthorised
     )]
     pub program_data: Account<'info, ProgramData>,
@@ -117,9 +617,41 @@ pub struct InitialiseRegistry<'info> {
     pub system_program: Program<'info, System>,
 }
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct Register<'info> {
+    // Covers the `Registration` rent; a sponsor, or the registrant themselves
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub registrant: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"registry_state"],
+        bump
+    )]
+    pub registry_state: Account<'info, RegistryState>,
+
+    #[account(
+        init,
+        seeds = [b"registration", registrant.key().as_ref()],
+        bump,
+        payer = payer,
+        space = ACCOUNT_DISCRIMINATOR_SPACE + Registration::INIT_SPACE
+    )]
+    pub registration: Account<'info, Registration>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(expiry_slot: u64, nonce: u64)]
+pub struct RegisterWithVoucher<'info> {
     #[account(mut)]
+    pub payer: Signer<'info>,
+
     pub registrant: Signer<'info>,
 
     #[account(
@@ -133,17 +665,68 @@ pub struct Register<'info> {
         init,
         seeds = [b"registration", registrant.key().as_ref()],
         bump,
-        payer = registrant,
+        payer = payer,
         space = ACCOUNT_DISCRIMINATOR_SPACE + Registration::INIT_SPACE
     )]
     pub registration: Account<'info, Registration>,
 
+    // Initialising the nonce PDA fails if the voucher has already been used
+    #[account(
+        init,
+        seeds = [b"voucher_nonce", nonce.to_le_bytes().as_ref()],
+        bump,
+        payer = payer,
+        space = ACCOUNT_DISCRIMINATOR_SPACE + VoucherNonce::INIT_SPACE
+    )]
+    pub voucher_nonce: Account<'info, VoucherNonce>,
+
+    /// CHECK: address is checked against the instructions sysvar
+    #[account(address = instructions_sysvar::ID)]
+    pub instructions: UncheckedAccount<'info>,
+
     pub system_program: Program<'info, System>,
 }
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct ConfirmRegistration<'info> {
     #[account(
+        mut,
+        seeds = [b"registry_state"],
+        bump,
+        has_one = authority
+    )]
+    pub registry_state: Account<'info, RegistryState>,
+
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"registration", registration.registrant.as_ref()],
+        bump
+    )]
+    pub registration: Account<'info, Registration>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ConfigureApprovers<'info> {
+    #[account(
+        mut,
+        seeds = [b"registry_state"],
+        bump,
+        has_one = authority
+    )]
+    pub registry_state: Account<'info, RegistryState>,
+
+    pub authority: Signer<'info>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetAllowlistRoot<'info> {
+    #[account(
+        mut,
         seeds = [b"registry_state"],
         bump,
         has_one = authority
@@ -151,6 +734,19 @@ pub struct ConfirmRegistration<'info> {
     pub registry_state: Account<'info, RegistryState>,
 
     pub authority: Signer<'info>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ApproveRegistration<'info> {
+    #[account(
+        mut,
+        seeds = [b"registry_state"],
+        bump
+    )]
+    pub registry_state: Account<'info, RegistryState>,
+
+    pub approver: Signer<'info>,
 
     #[account(
         mut,
@@ -160,8 +756,56 @@ pub struct ConfirmRegistration<'info> {
     pub registration: Account<'info, Registration>,
 }
 
+#[derive(Accounts)]
+pub struct MigrateRegistryState<'info> {
+    /// CHECK: may still have an older layout, it is grown and decoded in the handler
+    #[account(
+        mut,
+        seeds = [b"registry_state"],
+        bump,
+        owner = crate::ID
+    )]
+    pub registry_state: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(registrant: Pubkey)]
+pub struct MigrateRegistration<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: may still have an older layout, it is grown and decoded in the handler
+    #[account(
+        mut,
+        seeds = [b"registration", registrant.as_ref()],
+        bump,
+        owner = crate::ID
+    )]
+    pub registration: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+// Events are emitted through `emit_cpi!`, so they are recorded as inner instructions rather
+// than in the (truncatable) program logs. Each carries the registry and its authority.
+
+#[event]
+pub struct RegistryInitialisedEvent {
+    pub registry: Pubkey,
+    pub authority: Pubkey,
+    pub fifo_only: bool,
+    pub initialised_at: u64,
+}
+
 #[event]
 pub struct RegisteredEvent {
+    pub registry: Pubkey,
+    pub authority: Pubkey,
     pub registrant: Pubkey,
     pub registration_index: u64,
     pub registered_at: u64,
@@ -169,15 +813,71 @@ pub struct RegisteredEvent {
 
 #[event]
 pub struct ConfirmedEvent {
+    pub registry: Pubkey,
+    pub authority: Pubkey,
+    pub confirmed_by: Pubkey,
     pub registrant: Pubkey,
     pub registration_index: u64,
     pub confirmed_at: u64,
 }
 
+#[event]
+pub struct ApproversConfiguredEvent {
+    pub registry: Pubkey,
+    pub authority: Pubkey,
+    pub approvers: Vec<Pubkey>,
+    pub approval_threshold: u8,
+    pub configured_at: u64,
+}
+
+#[event]
+pub struct RegistrationApprovedEvent {
+    pub registry: Pubkey,
+    pub authority: Pubkey,
+    pub approver: Pubkey,
+    pub registrant: Pubkey,
+    pub registration_index: u64,
+    pub approved_at: u64,
+}
+
+#[event]
+pub struct AllowlistRootSetEvent {
+    pub registry: Pubkey,
+    pub authority: Pubkey,
+    pub allowlist_root: Option<[u8; 32]>,
+    pub set_at: u64,
+}
+
 #[error_code]
 pub enum RegisterError {
     #[msg("The registration has already been confirmed")]
     RegistrationAlreadyConfirmed,
     #[msg("Signer is not the program upgrade authority")]
     Unauthorised,
+    #[msg("Registrations must be confirmed in the order they were made")]
+    RegistrationNotNextInQueue,
+    #[msg("The registry requires registrations to be approved by its approvers")]
+    ApprovalRequired,
+    #[msg("A registry can have at most 8 approvers")]
+    TooManyApprovers,
+    #[msg("The approval threshold must be between 1 and the number of approvers")]
+    InvalidApprovalThreshold,
+    #[msg("The approver appears more than once")]
+    DuplicateApprover,
+    #[msg("Signer is not a registry approver")]
+    NotAnApprover,
+    #[msg("The registration has already been approved by this approver")]
+    RegistrationAlreadyApproved,
+    #[msg("The registrant is not on the registry allowlist")]
+    NotOnAllowlist,
+    #[msg("The voucher has expired")]
+    VoucherExpired,
+    #[msg("No voucher signed by the registry authority was found in the transaction")]
+    InvalidVoucher,
+    #[msg("The account is already at the current layout version")]
+    AccountAlreadyMigrated,
+    #[msg("The confirmed count cannot exceed the registration count")]
+    InvalidConfirmedCount,
+    #[msg("The registry counts no pending registrations to confirm")]
+    NoPendingRegistrations,
 }
@@ -4,96 +4,1943 @@
 use std::str;
 
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hashv;
+use anchor_lang::solana_program::sysvar::slot_hashes;
+use anchor_lang::system_program;
+use register::Registration;
 
 declare_id!("5kS2nb5CSCVcdb4N7iA1kQuAZYKFttXagoHv2TxWmzg9");
 
 const ACCOUNT_DISCRIMINATOR_SPACE: usize = 8;
 
+// Layout version of `Round`, rounds on any other version are rejected with `AccountNotMigrated`.
+// Rounds from before round ids predate it and are moved onto it by `migrate_round`.
+const ROUND_VERSION: u8 = 1;
+
+const MAX_COMMISSION_BPS: u16 = 10_000;
+
+// How long the authority has to settle a completed round, about a day of slots. An unsettled
+// round's entries become refundable once it passes.
+pub const SETTLEMENT_WINDOW_SLOTS: u64 = 216_000;
+
+// Entries kept on a round's `Leaderboard`, lower scores drop off the bottom
+pub const LEADERBOARD_CAPACITY: usize = 10;
+
+// SlotHashes holds a `u64` entry count, then `(slot, hash)` entries, newest first
+const SLOT_HASH_ENTRY_SPACE: usize = 8 + 32;
+
 #[program]
 pub mod round {
     use super::*;
 
-    // IRL only an admin can initialise a round
-    pub fn initialise_round(ctx: Context<InitialiseRound>, start_slot: u64) -> Result<()> {
-        let round = &mut ctx.accounts.round;
-        let current_slot = Clock::get()?.slot;
+    // IRL only an admin can initialise a round.
+    // Round ids are allocated per authority in sequence, `round_id` must be the authority's
+    // `next_round_id` (0 for its first round), see `RoundAuthority`.
+    pub fn initialise_round(
+        ctx: Context<InitialiseRound>,
+        round_id: u64,
+        start_at: u64,
+        end_at: u64,
+        config: RoundConfig,
+    ) -> Result<()> {
+        let RoundConfig {
+            max_participants,
+            entry_fee,
+            commission_bps,
+            activation_bounty,
+            schedule_mode,
+            reveal_start_at,
+            min_participants,
+            ..
+        } = config;
+        let round_key = ctx.accounts.round.key();
+        let round_authority = &mut ctx.accounts.round_authority;
+        let round = &mut ctx.accounts.round;
+        let clock = Clock::get()?;
+        let now = schedule_mode.now(&clock);
+
+        require!(
+            round_id == round_authority.next_round_id,
+            RoundError::InvalidRoundId
+        );
+        require!(start_at > now, RoundError::InvalidStartSlot);
+        require!(end_at > start_at, RoundError::InvalidEndSlot);
+        require!(
+            max_participants != Some(0),
+            RoundError::InvalidMaxParticipants
+        );
+        require!(
+            commission_bps <= MAX_COMMISSION_BPS,
+            RoundError::InvalidCommission
+        );
+        if let Some(max_participants) = max_participants {
+            require!(
+                min_participants <= max_participants,
+                RoundError::InvalidMinParticipants
+            );
+        }
+        if let Some(reveal_start_at) = reveal_start_at {
+            require!(
+                reveal_start_at > start_at && reveal_start_at <= end_at,
+                RoundError::InvalidRevealStartSlot
+            );
+        }
+
+        round_authority.authority = ctx.accounts.authority.key();
+        round_authority.next_round_id += 1;
+
+        round.set_inner(Round::scheduled(
+            ctx.accounts.authority.key(),
+            round_id,
+            start_at,
+            end_at,
+            config,
+        ));
+
+        // The bounty sits in the round account on top of its rent until `activate_round` pays it
+        if activation_bounty > 0 {
+            system_program::transfer(
+                CpiContext::new(
+                    ctx.accounts.system_program.to_account_info(),
+                    system_program::Transfer {
+                        from: ctx.accounts.authority.to_account_info(),
+                        to: round.to_account_info(),
+                    },
+                ),
+                activation_bounty,
+            )?;
+        }
+
+        // The pot is a plain system account, fund it up front so that it stays rent exempt
+        // whatever the entry fee, and payouts never need to drain it
+        if entry_fee > 0 {
+            let rent_minimum = Rent::get()?.minimum_balance(0);
+            let pot = &ctx.accounts.pot;
+            if rent_minimum > pot.lamports() {
+                system_program::transfer(
+                    CpiContext::new(
+                        ctx.accounts.system_program.to_account_info(),
+                        system_program::Transfer {
+                            from: ctx.accounts.authority.to_account_info(),
+                            to: pot.to_account_info(),
+                        },
+                    ),
+                    rent_minimum - pot.lamports(),
+                )?;
+            }
+        }
+
+        emit!(RoundInitialised {
+            round: round_key,
+            authority: round.authority,
+            actor: ctx.accounts.authority.key(),
+            slot: clock.slot,
+            round_id,
+            start_at,
+            end_at,
+        });
+
+        msg!(
+            "Round {} ({}) initialised by {}",
+            round_id,
+            start_at,
+            ctx.accounts.authority.key()
+        );
+
+        Ok(())
+    }
+
+    pub fn activate_round(ctx: Context<ActivateRound>) -> Result<()> {
+        let round_key = ctx.accounts.round.key();
+        let round = &mut ctx.accounts.round;
+        let clock = Clock::get()?;
+        let current_slot = clock.slot;
+        let now = round.schedule_mode.now(&clock);
+
+        match round.status {
+            RoundStatus::Scheduled => {}
+            RoundStatus::Active { .. } => return err!(RoundError::RoundAlreadyActive),
+            RoundStatus::Completed { .. } => return err!(RoundError::RoundAlreadyComplete),
+            RoundStatus::Cancelled { .. } => return err!(RoundError::RoundCancelled),
+        }
+        require!(
+            now >= round.start_at,
+            RoundError::InvalidRoundActivationSlot
+        );
+        require!(now <= round.end_at, RoundError::RoundExpired);
+        require!(
+            round.interest_count >= round.min_participants,
+            RoundError::NotEnoughInterest
+        );
+        if round.activation_requires_registration {
+            check_registration(&ctx.accounts.registration)?;
+        }
+
+        round.status = RoundStatus::Active {
+            activated_at: current_slot,
+            activated_by: ctx.accounts.user.key(),
+        };
+        // The first slot after entries close, so its hash is unknown while they're open.
+        // A timestamp scheduled round only learns that slot once completed.
+        if round.schedule_mode == ScheduleMode::Slot {
+            round.draw_slot = round.end_at + 1;
+        }
+
+        if round.activation_bounty > 0 {
+            round.sub_lamports(round.activation_bounty)?;
+            ctx.accounts.user.add_lamports(round.activation_bounty)?;
+        }
+
+        emit!(RoundActivated {
+            round: round_key,
+            authority: round.authority,
+            actor: ctx.accounts.user.key(),
+            slot: current_slot,
+        });
+
+        msg!(
+            "Round {} activated by {} at slot {}",
+            round.start_at,
+            ctx.accounts.user.key(),
+            current_slot
+        );
+
+        Ok(())
+    }
+
+    // The authority can complete a round early, anyone can once its end has passed.
+    // The winner is named afterwards, by `settle_round`.
+    pub fn complete_round(ctx: Context<CompleteRound>) -> Result<()> {
+        let round_key = ctx.accounts.round.key();
+        let round = &mut ctx.accounts.round;
+        let clock = Clock::get()?;
+        let current_slot = clock.slot;
+
+        match round.status {
+            RoundStatus::Active { .. } => {}
+            RoundStatus::Completed { .. } => return err!(RoundError::RoundAlreadyComplete),
+            RoundStatus::Cancelled { .. } => return err!(RoundError::RoundCancelled),
+            RoundStatus::Scheduled => return err!(RoundError::RoundNotYetActive),
+        }
+        require!(
+            ctx.accounts.user.key() == round.authority
+                || round.schedule_mode.now(&clock) > round.end_at,
+            RoundError::RoundNotYetEnded
+        );
+
+        complete_active_round(
+            round_key,
+            round,
+            &ctx.accounts.leaderboard,
+            ctx.accounts.user.key(),
+            current_slot,
+        )?;
+
+        msg!(
+            "Round {} marked as complete by {} at slot {}",
+            round.start_at,
+            ctx.accounts.user.key(),
+            current_slot
+        );
+
+        Ok(())
+    }
+
+    // Names the winner of a completed round, who must have entered it, and pays them the pot minus
+    // the authority's commission. Only the authority can settle, within `SETTLEMENT_WINDOW_SLOTS`
+    // of completion. A drawn round is settled with the participant whose entry index was drawn.
+    pub fn settle_round(ctx: Context<SettleRound>) -> Result<()> {
+        let round_key = ctx.accounts.round.key();
+        let round = &mut ctx.accounts.round;
+        let current_slot = Clock::get()?.slot;
+
+        let completed_at = match round.status {
+            RoundStatus::Completed { completed_at, .. } => completed_at,
+            RoundStatus::Cancelled { .. } => return err!(RoundError::RoundCancelled),
+            _ => return err!(RoundError::RoundNotYetComplete),
+        };
+        require!(round.winner.is_none(), RoundError::RoundAlreadySettled);
+        require!(
+            current_slot <= completed_at + SETTLEMENT_WINDOW_SLOTS,
+            RoundError::SettlementWindowClosed
+        );
+
+        let winner = &ctx.accounts.winner;
+        let winner_participant = &ctx.accounts.winner_participant;
+        require!(
+            winner_participant.round == round_key && winner_participant.user == winner.key(),
+            RoundError::WinnerNotEntered
+        );
+        if let Some(draw) = round.draw {
+            require!(
+                winner_participant.entry_index == draw.index as u64,
+                RoundError::WinnerNotDrawn
+            );
+        }
+
+        let pot = &ctx.accounts.pot;
+        let prize = pot
+            .lamports()
+            .saturating_sub(Rent::get()?.minimum_balance(0));
+        let commission =
+            (prize as u128 * round.commission_bps as u128 / MAX_COMMISSION_BPS as u128) as u64;
+        let signer_seeds: &[&[&[u8]]] = &[&[b"pot", round_key.as_ref(), &[ctx.bumps.pot]]];
+
+        pay_from_pot(
+            pot,
+            &ctx.accounts.authority,
+            &ctx.accounts.system_program,
+            signer_seeds,
+            commission,
+        )?;
+        pay_from_pot(
+            pot,
+            winner,
+            &ctx.accounts.system_program,
+            signer_seeds,
+            prize - commission,
+        )?;
+
+        round.winner = Some(winner.key());
+        round.held_entries = 0;
+
+        emit!(RoundSettled {
+            round: round_key,
+            authority: round.authority,
+            actor: ctx.accounts.authority.key(),
+            slot: current_slot,
+            winner: winner.key(),
+            prize: prize - commission,
+            commission,
+        });
+
+        msg!(
+            "{} won round {}, paid {} lamports with {} commission",
+            winner.key(),
+            round.start_at,
+            prize - commission,
+            commission
+        );
+
+        Ok(())
+    }
+
+    // Scheduled and active rounds can be called off by their authority. A scheduled round's
+    // unpaid activation bounty goes back to the authority.
+    pub fn cancel_round(ctx: Context<CancelRound>) -> Result<()> {
+        let round = &mut ctx.accounts.round;
+        let current_slot = Clock::get()?.slot;
+
+        match round.status {
+            RoundStatus::Scheduled => {
+                if round.activation_bounty > 0 {
+                    round.sub_lamports(round.activation_bounty)?;
+                    ctx.accounts
+                        .authority
+                        .add_lamports(round.activation_bounty)?;
+                }
+            }
+            RoundStatus::Active { .. } => {}
+            RoundStatus::Completed { .. } => return err!(RoundError::RoundAlreadyComplete),
+            RoundStatus::Cancelled { .. } => return err!(RoundError::RoundCancelled),
+        }
+
+        round.status = RoundStatus::Cancelled {
+            cancelled_at: current_slot,
+        };
+
+        msg!(
+            "Round {} cancelled at slot {}",
+            round.start_at,
+            current_slot
+        );
+
+        Ok(())
+    }
+
+    // Joining creates the user's participant account, which also stops them joining twice.
+    // Paid rounds are entered through `enter_round` instead.
+    pub fn join_round(ctx: Context<JoinRound>) -> Result<()> {
+        let round = &mut ctx.accounts.round;
+        let clock = Clock::get()?;
+        let current_slot = clock.slot;
+
+        require!(round.entry_fee == 0, RoundError::EntryFeeRequired);
+        if round.participation_requires_registration {
+            check_registration(&ctx.accounts.registration)?;
+        }
+
+        add_participant(
+            round,
+            &mut ctx.accounts.participant,
+            ctx.accounts.user.key(),
+            &clock,
+        )?;
+
+        msg!(
+            "{} joined round {} at slot {}",
+            ctx.accounts.user.key(),
+            round.start_at,
+            current_slot
+        );
+
+        Ok(())
+    }
+
+    // Same as `join_round` for paid rounds, the entry fee goes into the round's pot
+    pub fn enter_round(ctx: Context<EnterRound>) -> Result<()> {
+        let round = &mut ctx.accounts.round;
+        let clock = Clock::get()?;
+        let current_slot = clock.slot;
+
+        require!(round.entry_fee > 0, RoundError::RoundHasNoEntryFee);
+        if round.participation_requires_registration {
+            check_registration(&ctx.accounts.registration)?;
+        }
+
+        add_participant(
+            round,
+            &mut ctx.accounts.participant,
+            ctx.accounts.user.key(),
+            &clock,
+        )?;
+
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.user.to_account_info(),
+                    to: ctx.accounts.pot.to_account_info(),
+                },
+            ),
+            round.entry_fee,
+        )?;
+        round.held_entries += 1;
+
+        msg!(
+            "{} entered round {} for {} lamports at slot {}",
+            ctx.accounts.user.key(),
+            round.start_at,
+            round.entry_fee,
+            current_slot
+        );
+
+        Ok(())
+    }
+
+    // Once a round is cancelled, or completed and left unsettled past its settlement window, each
+    // participant can take back their entry fee. Closing the participant account stops a second
+    // refund.
+    pub fn refund_entry(ctx: Context<RefundEntry>) -> Result<()> {
+        let round_key = ctx.accounts.round.key();
+        let round = &mut ctx.accounts.round;
+        let current_slot = Clock::get()?.slot;
+
+        match (round.status, round.winner) {
+            (RoundStatus::Cancelled { .. }, _) => {}
+            (RoundStatus::Completed { completed_at, .. }, None)
+                if current_slot > completed_at + SETTLEMENT_WINDOW_SLOTS => {}
+            _ => return err!(RoundError::RefundUnavailable),
+        }
+
+        pay_from_pot(
+            &ctx.accounts.pot,
+            &ctx.accounts.user,
+            &ctx.accounts.system_program,
+            &[&[b"pot", round_key.as_ref(), &[ctx.bumps.pot]]],
+            round.entry_fee,
+        )?;
+        round.held_entries -= 1;
+
+        msg!(
+            "{} refunded {} lamports from round {}",
+            ctx.accounts.user.key(),
+            round.entry_fee,
+            round.start_at
+        );
+
+        Ok(())
+    }
+
+    // Counts the signer towards the round's `min_participants` ahead of its start, once per
+    // user. Interest doesn't join the round, users still join once it's active, and it is gated on
+    // registration like joining.
+    pub fn signal_interest(ctx: Context<SignalInterest>) -> Result<()> {
+        let round = &mut ctx.accounts.round;
+        let clock = Clock::get()?;
+
+        match round.status {
+            RoundStatus::Scheduled => {}
+            RoundStatus::Active { .. } => return err!(RoundError::RoundAlreadyActive),
+            RoundStatus::Completed { .. } => return err!(RoundError::RoundAlreadyComplete),
+            RoundStatus::Cancelled { .. } => return err!(RoundError::RoundCancelled),
+        }
+        require!(
+            round.schedule_mode.now(&clock) < round.start_at,
+            RoundError::InterestClosed
+        );
+        if round.participation_requires_registration {
+            check_registration(&ctx.accounts.registration)?;
+        }
+
+        round.interest_count += 1;
+
+        let interest = &mut ctx.accounts.interest;
+        interest.round = round.key();
+        interest.user = ctx.accounts.user.key();
+        interest.signalled_at = clock.slot;
+
+        msg!(
+            "{} signalled interest in round {}, {} of {} needed",
+            ctx.accounts.user.key(),
+            round.start_at,
+            round.interest_count,
+            round.min_participants
+        );
+
+        Ok(())
+    }
+
+    // Sets up a series of rounds `interval_slots` apart, each open for `duration_slots`, and
+    // schedules its first round at `start_at`. Series rounds are free rounds of the authority,
+    // taking its next round ids like any other, so it can cancel, settle and close them as usual.
+    // See `roll_series`.
+    pub fn initialise_series(
+        ctx: Context<InitialiseSeries>,
+        series_id: u64,
+        start_at: u64,
+        interval_slots: u64,
+        duration_slots: u64,
+    ) -> Result<()> {
+        let round_authority = &mut ctx.accounts.round_authority;
+        let series = &mut ctx.accounts.series;
+        let round = &mut ctx.accounts.round;
+        let current_slot = Clock::get()?.slot;
+        let round_id = round_authority.next_round_id;
+
+        require!(start_at > current_slot, RoundError::InvalidStartSlot);
+        require!(
+            duration_slots > 0 && duration_slots < interval_slots,
+            RoundError::InvalidSeriesCadence
+        );
+
+        round_authority.authority = ctx.accounts.authority.key();
+        round_authority.next_round_id += 1;
+
+        series.authority = ctx.accounts.authority.key();
+        series.series_id = series_id;
+        series.interval_slots = interval_slots;
+        series.duration_slots = duration_slots;
+        series.round_id = round_id;
+        series.round_start_at = start_at;
+
+        round.set_inner(Round::scheduled(
+            ctx.accounts.authority.key(),
+            round_id,
+            start_at,
+            start_at + duration_slots,
+            RoundConfig::default(),
+        ));
+
+        emit!(RoundInitialised {
+            round: round.key(),
+            authority: round.authority,
+            actor: ctx.accounts.authority.key(),
+            slot: current_slot,
+            round_id,
+            start_at,
+            end_at: round.end_at,
+        });
+
+        msg!(
+            "Series {} of {} initialised, a round every {} slots from {}",
+            series_id,
+            ctx.accounts.authority.key(),
+            interval_slots,
+            start_at
+        );
+
+        Ok(())
+    }
+
+    // Permissionless. Once the series' next start is due, completes its current round (cancels it
+    // if nobody activated it) and schedules the next one under the authority's next round id.
+    // A late roll skips the starts it missed, keeping the series on its cadence, and never schedules
+    // a round that has already ended. The signer pays
+    // the next round's rent, which goes to the authority when it closes the round.
+    pub fn roll_series(ctx: Context<RollSeries>) -> Result<()> {
+        let current_round_key = ctx.accounts.current_round.key();
+        let round_authority = &mut ctx.accounts.round_authority;
+        let series = &mut ctx.accounts.series;
+        let next_round = &mut ctx.accounts.next_round;
+        let current_slot = Clock::get()?.slot;
+
+        let previous_start_at = series.round_start_at;
+        require!(
+            current_slot >= previous_start_at + series.interval_slots,
+            RoundError::SeriesNotDue
+        );
+
+        // The duration is shorter than the interval, so the current round has already ended. Its
+        // authority may have closed it since, then there's nothing left to complete.
+        let current_round_info = ctx.accounts.current_round.to_account_info();
+        if current_round_info.owner == &crate::ID {
+            let mut data = current_round_info.try_borrow_mut_data()?;
+            let mut current_round = Round::try_deserialize(&mut &data[..])?;
+            require!(
+                current_round.version == ROUND_VERSION,
+                RoundError::AccountNotMigrated
+            );
+
+            match current_round.status {
+                RoundStatus::Scheduled => {
+                    current_round.status = RoundStatus::Cancelled {
+                        cancelled_at: current_slot,
+                    };
+                }
+                RoundStatus::Active { .. } => complete_active_round(
+                    current_round_key,
+                    &mut current_round,
+                    &ctx.accounts.leaderboard,
+                    ctx.accounts.user.key(),
+                    current_slot,
+                )?,
+                RoundStatus::Completed { .. } | RoundStatus::Cancelled { .. } => {}
+            }
+            current_round.try_serialize(&mut &mut data[..])?;
+        }
+
+        // The latest start that has passed, or the one after if that round would already be over
+        let missed_intervals = (current_slot - previous_start_at) / series.interval_slots;
+        let mut start_at = previous_start_at + missed_intervals * series.interval_slots;
+        if start_at + series.duration_slots <= current_slot {
+            start_at += series.interval_slots;
+        }
+        let round_id = round_authority.next_round_id;
+        round_authority.next_round_id += 1;
+        series.round_id = round_id;
+        series.round_start_at = start_at;
+
+        next_round.set_inner(Round::scheduled(
+            series.authority,
+            round_id,
+            start_at,
+            start_at + series.duration_slots,
+            RoundConfig::default(),
+        ));
+
+        emit!(RoundInitialised {
+            round: next_round.key(),
+            authority: series.authority,
+            actor: ctx.accounts.user.key(),
+            slot: current_slot,
+            round_id,
+            start_at,
+            end_at: next_round.end_at,
+        });
+
+        msg!(
+            "Series {} of {} rolled to round {} at slot {}",
+            series.series_id,
+            series.authority,
+            round_id,
+            start_at
+        );
+
+        Ok(())
+    }
+
+    // Stops a series rolling and returns its rent to the authority. Its rounds are the authority's
+    // own, so the current one carries on, or can be cancelled, like any other.
+    pub fn close_series(ctx: Context<CloseSeries>) -> Result<()> {
+        msg!(
+            "Series {} of {} closed",
+            ctx.accounts.series.series_id,
+            ctx.accounts.authority.key()
+        );
+
+        Ok(())
+    }
+
+    // Moves a round that has not started yet to `new_start_at`. Its end and reveal start
+    // move with it, so the round keeps its length.
+    pub fn reschedule_round(ctx: Context<RescheduleRound>, new_start_at: u64) -> Result<()> {
+        let round_key = ctx.accounts.round.key();
+        let round = &mut ctx.accounts.round;
+        let clock = Clock::get()?;
+        let current_slot = clock.slot;
+
+        match round.status {
+            RoundStatus::Scheduled => {}
+            RoundStatus::Active { .. } => return err!(RoundError::RoundAlreadyActive),
+            RoundStatus::Completed { .. } => return err!(RoundError::RoundAlreadyComplete),
+            RoundStatus::Cancelled { .. } => return err!(RoundError::RoundCancelled),
+        }
+        require!(
+            new_start_at > round.schedule_mode.now(&clock),
+            RoundError::InvalidStartSlot
+        );
+
+        let old_start_at = round.start_at;
+        round.end_at = new_start_at + (round.end_at - round.start_at);
+        round.reveal_start_at = round
+            .reveal_start_at
+            .map(|reveal_start_at| new_start_at + (reveal_start_at - round.start_at));
+        round.start_at = new_start_at;
+        round.reschedule_count += 1;
+
+        emit!(RoundRescheduled {
+            round: round_key,
+            authority: round.authority,
+            actor: ctx.accounts.authority.key(),
+            slot: current_slot,
+            old_start_at,
+            new_start_at,
+            end_at: round.end_at,
+            reschedule_count: round.reschedule_count,
+        });
+
+        Ok(())
+    }
+
+    // Seals a participant's submission as `hash(round, user, value, salt)` until the reveal
+    // starts. Hashing in the round and user stops anyone replaying another's commitment.
+    pub fn commit(ctx: Context<CommitSubmission>, commitment: [u8; 32]) -> Result<()> {
+        let round = &ctx.accounts.round;
+        let clock = Clock::get()?;
+
+        let Some(reveal_start_at) = round.reveal_start_at else {
+            return err!(RoundError::SubmissionsDisabled);
+        };
+        match round.status {
+            RoundStatus::Active { .. } => {}
+            RoundStatus::Scheduled => return err!(RoundError::RoundNotYetActive),
+            RoundStatus::Completed { .. } => return err!(RoundError::RoundAlreadyComplete),
+            RoundStatus::Cancelled { .. } => return err!(RoundError::RoundCancelled),
+        }
+        require!(
+            round.schedule_mode.now(&clock) < reveal_start_at,
+            RoundError::CommitPhaseOver
+        );
+
+        let submission = &mut ctx.accounts.submission;
+        submission.round = round.key();
+        submission.user = ctx.accounts.user.key();
+        submission.commitment = commitment;
+        submission.committed_at = clock.slot;
+        submission.value = None;
+        submission.revealed_at = None;
+
+        msg!(
+            "{} committed to round {} at slot {}",
+            ctx.accounts.user.key(),
+            round.start_at,
+            clock.slot
+        );
+
+        Ok(())
+    }
+
+    // Opens a commitment from the round's reveal start until its end. Only reveals
+    // matching their commitment are recorded and counted.
+    pub fn reveal(ctx: Context<RevealSubmission>, value: u64, salt: [u8; 32]) -> Result<()> {
+        let round_key = ctx.accounts.round.key();
+        let round = &mut ctx.accounts.round;
+        let submission = &mut ctx.accounts.submission;
+        let clock = Clock::get()?;
+        let now = round.schedule_mode.now(&clock);
+
+        let Some(reveal_start_at) = round.reveal_start_at else {
+            return err!(RoundError::SubmissionsDisabled);
+        };
+        match round.status {
+            RoundStatus::Active { .. } => {}
+            RoundStatus::Scheduled => return err!(RoundError::RoundNotYetActive),
+            RoundStatus::Completed { .. } => return err!(RoundError::RoundAlreadyComplete),
+            RoundStatus::Cancelled { .. } => return err!(RoundError::RoundCancelled),
+        }
+        require!(now >= reveal_start_at, RoundError::RevealNotStarted);
+        require!(now <= round.end_at, RoundError::RoundExpired);
+        require!(submission.value.is_none(), RoundError::AlreadyRevealed);
+        require!(
+            submission_commitment(&round_key, &submission.user, value, &salt)
+                == submission.commitment,
+            RoundError::CommitmentMismatch
+        );
+
+        submission.value = Some(value);
+        submission.revealed_at = Some(clock.slot);
+        round.reveal_count += 1;
+
+        msg!(
+            "{} revealed {} in round {} at slot {}",
+            submission.user,
+            value,
+            round.start_at,
+            clock.slot
+        );
+
+        Ok(())
+    }
+
+    // Records a participant's score while the round is active, signed by the authority or the
+    // round's scorer. Each participant keeps their best score, the leaderboard the top
+    // `LEADERBOARD_CAPACITY` of them. Ties rank in the order they were first reached.
+    pub fn submit_score(ctx: Context<SubmitScore>, user: Pubkey, score: u64) -> Result<()> {
+        let round = &ctx.accounts.round;
+        let leaderboard = &mut ctx.accounts.leaderboard;
+        let clock = Clock::get()?;
+
+        require!(
+            ctx.accounts.scorer.key() == round.authority
+                || Some(ctx.accounts.scorer.key()) == round.scorer,
+            RoundError::ScorerNotAllowed
+        );
+        require!(
+            leaderboard.frozen_at.is_none(),
+            RoundError::LeaderboardFrozen
+        );
+        match round.status {
+            RoundStatus::Active { .. } => {}
+            RoundStatus::Scheduled => return err!(RoundError::RoundNotYetActive),
+            RoundStatus::Completed { .. } => return err!(RoundError::RoundAlreadyComplete),
+            RoundStatus::Cancelled { .. } => return err!(RoundError::RoundCancelled),
+        }
+        require!(
+            round.schedule_mode.now(&clock) <= round.end_at,
+            RoundError::RoundExpired
+        );
+
+        // Created by the round's first score
+        leaderboard.round = round.key();
+        let ranked = record_score(
+            &mut leaderboard.entries,
+            LeaderboardEntry {
+                user,
+                score,
+                scored_at: clock.slot,
+            },
+        );
+
+        msg!(
+            "{} scored {} in round {}{}",
+            user,
+            score,
+            round.start_at,
+            if ranked { "" } else { ", not ranked" }
+        );
+
+        Ok(())
+    }
+
+    // Draws an index in `0..candidates` from the hash of the first slot at or after the round's
+    // `draw_slot`, for resolving an entry list (on-chain participants or kept off-chain).
+    // Anyone can draw over on-chain participants, whose count fixes `candidates`. Only the
+    // authority can draw over an off-chain list, as nothing on-chain pins its length. Anyone can
+    // recompute the index from the recorded slot hash.
+    pub fn draw_winner(ctx: Context<DrawWinner>, candidates: u32) -> Result<()> {
+        let round = &mut ctx.accounts.round;
+
+        match round.status {
+            RoundStatus::Completed { .. } => {}
+            RoundStatus::Cancelled { .. } => return err!(RoundError::RoundCancelled),
+            _ => return err!(RoundError::RoundNotYetComplete),
+        }
+        require!(round.draw.is_none(), RoundError::WinnerAlreadyDrawn);
+        require!(candidates > 0, RoundError::InvalidCandidates);
+        if round.participant_count == 0 {
+            require_keys_eq!(
+                ctx.accounts.user.key(),
+                round.authority,
+                RoundError::DrawNotAllowed
+            );
+        } else {
+            require!(
+                candidates as u64 == round.participant_count,
+                RoundError::CandidatesMismatch
+            );
+        }
+
+        let data = ctx.accounts.slot_hashes.try_borrow_data()?;
+        let (slot, slot_hash) = first_slot_hash_from(&data, round.draw_slot)?;
+
+        // Modulo bias is below candidates / 2^64, negligible for a u32 count
+        let index =
+            (u64::from_le_bytes(slot_hash[..8].try_into().unwrap()) % candidates as u64) as u32;
+
+        round.draw = Some(Draw {
+            candidates,
+            index,
+            slot,
+            slot_hash,
+        });
+
+        msg!(
+            "Drew index {} of {} candidates for round {} from slot {}",
+            index,
+            candidates,
+            round.start_at,
+            slot
+        );
+
+        Ok(())
+    }
+
+    // Closes a finished round, returning its rent, its leaderboard's and whatever is left in the
+    // pot to the authority. Paid rounds must have no entry fees held, settled or all refunded, so
+    // refunds can't be stranded. Users take back the rent of their own accounts afterwards, see `close_participant`.
+    // Round ids are never reused, the authority's next round takes `next_round_id`.
+    pub fn close_round(ctx: Context<CloseRound>) -> Result<()> {
+        let round_key = ctx.accounts.round.key();
+        let round = &ctx.accounts.round;
+
+        match round.status {
+            RoundStatus::Completed { .. } | RoundStatus::Cancelled { .. } => {}
+            _ => return err!(RoundError::RoundNotYetComplete),
+        }
+
+        // Counted rather than read off the pot's balance, which anyone can top up
+        require!(round.held_entries == 0, RoundError::PotNotEmpty);
+        let pot = &ctx.accounts.pot;
+        pay_from_pot(
+            pot,
+            &ctx.accounts.authority,
+            &ctx.accounts.system_program,
+            &[&[b"pot", round_key.as_ref(), &[ctx.bumps.pot]]],
+            pot.lamports(),
+        )?;
+
+        // Only scored rounds have a leaderboard, closed by hand as it may not exist
+        let leaderboard_info = ctx.accounts.leaderboard.to_account_info();
+        if leaderboard_info.owner == &crate::ID {
+            let lamports = leaderboard_info.lamports();
+            leaderboard_info.sub_lamports(lamports)?;
+            ctx.accounts.authority.add_lamports(lamports)?;
+            leaderboard_info.assign(&system_program::ID);
+            leaderboard_info.realloc(0, false)?;
+        }
+
+        msg!(
+            "Round {} of {} closed",
+            round.round_id,
+            ctx.accounts.authority.key()
+        );
+
+        Ok(())
+    }
+
+    // Once its round is closed, a user's participant, submission and interest accounts have no
+    // further use and the user can take back their rent
+    pub fn close_participant(ctx: Context<CloseParticipant>) -> Result<()> {
+        msg!(
+            "Participant {} of closed round {} closed",
+            ctx.accounts.user.key(),
+            ctx.accounts.round.key()
+        );
+
+        Ok(())
+    }
+
+    pub fn close_submission(ctx: Context<CloseSubmission>) -> Result<()> {
+        msg!(
+            "Submission of {} to closed round {} closed",
+            ctx.accounts.user.key(),
+            ctx.accounts.round.key()
+        );
+
+        Ok(())
+    }
+
+    pub fn close_interest(ctx: Context<CloseInterest>) -> Result<()> {
+        msg!(
+            "Interest of {} in closed round {} closed",
+            ctx.accounts.user.key(),
+            ctx.accounts.round.key()
+        );
+
+        Ok(())
+    }
+
+    // Moves a round created before round ids, keyed by its authority alone, onto the current
+    // layout as the authority's next round and closes the old account. Its status follows from the
+    // old activation and completion. Those rounds had no end, so the authority picks one.
+    pub fn migrate_round(ctx: Context<MigrateRound>, round_id: u64, end_at: u64) -> Result<()> {
+        let legacy_info = ctx.accounts.legacy_round.to_account_info();
+        let legacy = {
+            let data = legacy_info.try_borrow_data()?;
+            require!(
+                data.starts_with(Round::DISCRIMINATOR),
+                ErrorCode::AccountDiscriminatorMismatch
+            );
+            LegacyRound::deserialize(&mut &data[ACCOUNT_DISCRIMINATOR_SPACE..])?
+        };
+        let round_authority = &mut ctx.accounts.round_authority;
+        let current_slot = Clock::get()?.slot;
+
+        require!(
+            round_id == round_authority.next_round_id,
+            RoundError::InvalidRoundId
+        );
+        require!(end_at > legacy.start_slot, RoundError::InvalidEndSlot);
+
+        round_authority.authority = ctx.accounts.authority.key();
+        round_authority.next_round_id += 1;
+
+        let mut round = Round::scheduled(
+            legacy.authority,
+            round_id,
+            legacy.start_slot,
+            end_at,
+            RoundConfig::default(),
+        );
+        match (
+            legacy.activated_at.zip(legacy.activated_by),
+            legacy.completed_at,
+        ) {
+            (None, _) => {}
+            (Some((activated_at, activated_by)), None) => {
+                round.status = RoundStatus::Active {
+                    activated_at,
+                    activated_by,
+                };
+                // As `activate_round` sets it, unless that slot has passed and its hash is known
+                round.draw_slot = end_at.max(current_slot) + 1;
+            }
+            (Some((activated_at, activated_by)), Some(completed_at)) => {
+                round.status = RoundStatus::Completed {
+                    activated_at,
+                    activated_by,
+                    completed_at,
+                };
+                round.draw_slot = current_slot + 1;
+            }
+        }
+        ctx.accounts.round.set_inner(round);
+
+        // Closed by hand, the old layout can't be loaded as a `Round`
+        let lamports = legacy_info.lamports();
+        legacy_info.sub_lamports(lamports)?;
+        ctx.accounts.authority.add_lamports(lamports)?;
+        legacy_info.assign(&system_program::ID);
+        legacy_info.realloc(0, false)?;
+
+        msg!(
+            "Round {} of {} migrated to round id {}",
+            legacy.start_slot,
+            legacy.authority,
+            round_id
+        );
+
+        Ok(())
+    }
+}
+
+// Checks the round is open for participants and records `user` as one
+fn add_participant(
+    round: &mut Account<Round>,
+    participant: &mut Account<Participant>,
+    user: Pubkey,
+    clock: &Clock,
+) -> Result<()> {
+    match round.status {
+        RoundStatus::Active { .. } => {}
+        RoundStatus::Scheduled => return err!(RoundError::RoundNotYetActive),
+        RoundStatus::Completed { .. } => return err!(RoundError::RoundAlreadyComplete),
+        RoundStatus::Cancelled { .. } => return err!(RoundError::RoundCancelled),
+    }
+    require!(
+        round.schedule_mode.now(clock) <= round.end_at,
+        RoundError::RoundExpired
+    );
+    if let Some(max_participants) = round.max_participants {
+        require!(
+            round.participant_count < max_participants,
+            RoundError::RoundFull
+        );
+    }
+
+    participant.round = round.key();
+    participant.user = user;
+    participant.joined_at = clock.slot;
+    participant.entry_index = round.participant_count;
+
+    round.participant_count += 1;
+
+    Ok(())
+}
+
+// What `commit` seals and `reveal` checks, see `commit`
+pub fn submission_commitment(
+    round: &Pubkey,
+    user: &Pubkey,
+    value: u64,
+    salt: &[u8; 32],
+) -> [u8; 32] {
+    hashv(&[round.as_ref(), user.as_ref(), &value.to_le_bytes(), salt]).to_bytes()
+}
+
+// Places `entry` on a leaderboard sorted by descending score, replacing the user's lower score.
+// Returns whether it ranked.
+fn record_score(entries: &mut Vec<LeaderboardEntry>, entry: LeaderboardEntry) -> bool {
+    if let Some(index) = entries.iter().position(|ranked| ranked.user == entry.user) {
+        if entries[index].score >= entry.score {
+            return false;
+        }
+        entries.remove(index);
+    }
+
+    // After any equal scores, so earlier ones keep their place
+    let rank = entries
+        .iter()
+        .position(|ranked| ranked.score < entry.score)
+        .unwrap_or(entries.len());
+    if rank >= LEADERBOARD_CAPACITY {
+        return false;
+    }
+
+    entries.insert(rank, entry);
+    entries.truncate(LEADERBOARD_CAPACITY);
+
+    true
+}
+
+// The `registration` account is already pinned to the signer's PDA in the `register` program,
+// a gated round also needs the registration to be present and confirmed
+fn check_registration(registration: &Option<Account<Registration>>) -> Result<()> {
+    let registration = registration
+        .as_ref()
+        .ok_or(error!(RoundError::RegistrationRequired))?;
+    require!(
+        registration.confirmed_at.is_some(),
+        RoundError::RegistrationNotConfirmed
+    );
+
+    Ok(())
+}
+
+// Finds the hash of the first slot at or after `slot` in the raw SlotHashes data. Only trusted
+// when the sysvar still reaches back past `slot`, otherwise an earlier slot may have dropped out.
+fn first_slot_hash_from(data: &[u8], slot: u64) -> Result<(u64, [u8; 32])> {
+    let count = u64::from_le_bytes(data[..8].try_into().unwrap()) as usize;
+    let mut found = None;
+
+    for entry in data[8..].chunks_exact(SLOT_HASH_ENTRY_SPACE).take(count) {
+        let entry_slot = u64::from_le_bytes(entry[..8].try_into().unwrap());
+        if entry_slot < slot {
+            return found.ok_or(error!(RoundError::DrawSlotNotReached));
+        }
+        found = Some((entry_slot, entry[8..].try_into().unwrap()));
+    }
+
+    match found {
+        Some((entry_slot, hash)) if entry_slot == slot => Ok((entry_slot, hash)),
+        Some(_) => err!(RoundError::DrawSlotHashExpired),
+        None => err!(RoundError::DrawSlotNotReached),
+    }
+}
+
+// Completes an active round for `complete_round` and `roll_series`. Only scored rounds have a
+// leaderboard, it keeps its final standings from here on.
+fn complete_active_round(
+    round_key: Pubkey,
+    round: &mut Round,
+    leaderboard: &UncheckedAccount,
+    actor: Pubkey,
+    current_slot: u64,
+) -> Result<()> {
+    let RoundStatus::Active {
+        activated_at,
+        activated_by,
+    } = round.status
+    else {
+        return err!(RoundError::RoundNotYetActive);
+    };
+
+    round.status = RoundStatus::Completed {
+        activated_at,
+        activated_by,
+        completed_at: current_slot,
+    };
+    if round.schedule_mode == ScheduleMode::UnixTimestamp {
+        round.draw_slot = current_slot + 1;
+    }
+
+    let leaderboard_info = leaderboard.to_account_info();
+    if leaderboard_info.owner == &crate::ID {
+        let mut data = leaderboard_info.try_borrow_mut_data()?;
+        let mut leaderboard = Leaderboard::try_deserialize(&mut &data[..])?;
+        leaderboard.frozen_at = Some(current_slot);
+        leaderboard.try_serialize(&mut &mut data[..])?;
+    }
+
+    emit!(RoundCompleted {
+        round: round_key,
+        authority: round.authority,
+        actor,
+        slot: current_slot,
+    });
+
+    Ok(())
+}
+
+// The pot PDA is owned by the system program, so lamports leave it through a signed transfer
+fn pay_from_pot<'info>(
+    pot: &SystemAccount<'info>,
+    to: &AccountInfo<'info>,
+    system_program: &Program<'info, System>,
+    signer_seeds: &[&[&[u8]]],
+    amount: u64,
+) -> Result<()> {
+    if amount == 0 {
+        return Ok(());
+    }
+
+    system_program::transfer(
+        CpiContext::new_with_signer(
+            system_program.to_account_info(),
+            system_program::Transfer {
+                from: pot.to_account_info(),
+                to: to.clone(),
+            },
+            signer_seeds,
+        ),
+        amount,
+    )
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct Round {
+    pub start_at: u64,
+    pub authority: Pubkey,
+    pub version: u8,
+    pub round_id: u64,
+    pub end_at: u64,
+    pub status: RoundStatus,
+    pub participant_count: u64,
+    pub max_participants: Option<u64>,
+    pub entry_fee: u64,
+    pub commission_bps: u16,
+    pub winner: Option<Pubkey>,
+    pub draw_slot: u64,
+    pub draw: Option<Draw>,
+    pub activation_bounty: u64,
+    pub reschedule_count: u32,
+    pub schedule_mode: ScheduleMode,
+    pub activation_requires_registration: bool,
+    pub participation_requires_registration: bool,
+    pub reveal_start_at: Option<u64>,
+    pub reveal_count: u64,
+    pub scorer: Option<Pubkey>,
+    pub min_participants: u64,
+    pub interest_count: u64,
+    // Entry fees in the pot, until the round is settled or each is refunded
+    pub held_entries: u64,
+}
+
+impl Round {
+    // A new round awaiting activation, on the current layout
+    fn scheduled(
+        authority: Pubkey,
+        round_id: u64,
+        start_at: u64,
+        end_at: u64,
+        config: RoundConfig,
+    ) -> Self {
+        Round {
+            start_at,
+            authority,
+            version: ROUND_VERSION,
+            round_id,
+            end_at,
+            status: RoundStatus::Scheduled,
+            participant_count: 0,
+            max_participants: config.max_participants,
+            entry_fee: config.entry_fee,
+            commission_bps: config.commission_bps,
+            winner: None,
+            draw_slot: 0,
+            draw: None,
+            activation_bounty: config.activation_bounty,
+            reschedule_count: 0,
+            schedule_mode: config.schedule_mode,
+            activation_requires_registration: config.activation_requires_registration,
+            participation_requires_registration: config.participation_requires_registration,
+            reveal_start_at: config.reveal_start_at,
+            reveal_count: 0,
+            scorer: config.scorer,
+            min_participants: config.min_participants,
+            interest_count: 0,
+            held_entries: 0,
+        }
+    }
+}
+
+// The optional settings of `initialise_round`, the default is a free round without a
+// participant cap or bounty.
+// A non-zero `entry_fee` makes the round paid, see `enter_round`. The authority keeps
+// `commission_bps` of the pot when paying out a winner. `activation_bounty` lamports are paid
+// to whoever activates the round. `schedule_mode` sets what its start and end count.
+// The registration flags limit activating or joining the round to users holding a confirmed
+// registration in the `register` program. A `reveal_start_at` within the round lets
+// participants `commit` sealed submissions before it and `reveal` them after. A `scorer` can
+// `submit_score` alongside the authority. A round can't activate until `min_participants` users
+// `signal_interest` in it.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, Default)]
+pub struct RoundConfig {
+    pub max_participants: Option<u64>,
+    pub entry_fee: u64,
+    pub commission_bps: u16,
+    pub activation_bounty: u64,
+    pub schedule_mode: ScheduleMode,
+    pub activation_requires_registration: bool,
+    pub participation_requires_registration: bool,
+    pub reveal_start_at: Option<u64>,
+    pub scorer: Option<Pubkey>,
+    pub min_participants: u64,
+}
+
+// What a round's `start_at` and `end_at` are measured in. Slot times drift against the wall
+// clock, `UnixTimestamp` rounds open and close at the times announced to users instead.
+// Statuses, participants and `draw_slot` record slots either way.
+#[derive(
+    AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq, InitSpace,
+)]
+pub enum ScheduleMode {
+    #[default]
+    Slot,
+    UnixTimestamp,
+}
+
+impl ScheduleMode {
+    // The current point on the round's schedule, validator clock timestamps are never negative
+    fn now(self, clock: &Clock) -> u64 {
+        match self {
+            ScheduleMode::Slot => clock.slot,
+            ScheduleMode::UnixTimestamp => clock.unix_timestamp.max(0) as u64,
+        }
+    }
+}
+
+// The outcome of `draw_winner`, with the inputs needed to verify it
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, InitSpace)]
+pub struct Draw {
+    pub candidates: u32,
+    pub index: u32,
+    pub slot: u64,
+    pub slot_hash: [u8; 32],
+}
+
+// `entry_index` is the participant's place in the entry order, the candidate list `draw_winner`
+// indexes into
+#[account]
+#[derive(InitSpace)]
+pub struct Participant {
+    pub round: Pubkey,
+    pub user: Pubkey,
+    pub joined_at: u64,
+    pub entry_index: u64,
+}
+
+// A user's interest in a round before it starts, see `signal_interest`
+#[account]
+#[derive(InitSpace)]
+pub struct Interest {
+    pub round: Pubkey,
+    pub user: Pubkey,
+    pub signalled_at: u64,
+}
+
+// A participant's sealed submission, `value` is only set once revealed
+#[account]
+#[derive(InitSpace)]
+pub struct Submission {
+    pub round: Pubkey,
+    pub user: Pubkey,
+    pub commitment: [u8; 32],
+    pub committed_at: u64,
+    pub value: Option<u64>,
+    pub revealed_at: Option<u64>,
+}
+
+// A round's top scores, best first. Frozen once the round completes.
+#[account]
+#[derive(InitSpace)]
+pub struct Leaderboard {
+    pub round: Pubkey,
+    pub frozen_at: Option<u64>,
+    #[max_len(LEADERBOARD_CAPACITY)]
+    pub entries: Vec<LeaderboardEntry>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, InitSpace)]
+pub struct LeaderboardEntry {
+    pub user: Pubkey,
+    pub score: u64,
+    pub scored_at: u64,
+}
+
+// Each variant keeps the slots (and activator) of the transitions that led to it
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, InitSpace)]
+pub enum RoundStatus {
+    Scheduled,
+    Active {
+        activated_at: u64,
+        activated_by: Pubkey,
+    },
+    Completed {
+        activated_at: u64,
+        activated_by: Pubkey,
+        completed_at: u64,
+    },
+    Cancelled {
+        cancelled_at: u64,
+    },
+}
+
+// `Round` as created before round ids, at `[b"round", authority]`. Only read by `migrate_round`.
+#[derive(AnchorDeserialize)]
+struct LegacyRound {
+    start_slot: u64,
+    authority: Pubkey,
+    activated_at: Option<u64>,
+    activated_by: Option<Pubkey>,
+    completed_at: Option<u64>,
+}
+
+// Lifecycle events, for clients to follow rounds without parsing log text. `actor` signed the
+// transition and `slot` is when it happened, whatever the round's schedule mode.
+#[event]
+pub struct RoundInitialised {
+    pub round: Pubkey,
+    pub authority: Pubkey,
+    pub actor: Pubkey,
+    pub slot: u64,
+    pub round_id: u64,
+    pub start_at: u64,
+    pub end_at: u64,
+}
+
+#[event]
+pub struct RoundActivated {
+    pub round: Pubkey,
+    pub authority: Pubkey,
+    pub actor: Pubkey,
+    pub slot: u64,
+}
+
+#[event]
+pub struct RoundCompleted {
+    pub round: Pubkey,
+    pub authority: Pubkey,
+    pub actor: Pubkey,
+    pub slot: u64,
+}
+
+#[event]
+pub struct RoundSettled {
+    pub round: Pubkey,
+    pub authority: Pubkey,
+    pub actor: Pubkey,
+    pub slot: u64,
+    pub winner: Pubkey,
+    pub prize: u64,
+    pub commission: u64,
+}
+
+#[event]
+pub struct RoundRescheduled {
+    pub round: Pubkey,
+    pub authority: Pubkey,
+    pub actor: Pubkey,
+    pub slot: u64,
+    pub old_start_at: u64,
+    pub new_start_at: u64,
+    pub end_at: u64,
+    pub reschedule_count: u32,
+}
+
+// The cadence of a recurring series of rounds, see `initialise_series`. `round_id` is the
+// authority's round id of its current round and `round_start_at` its start, kept here as the
+// authority can close that round once it's over.
+#[account]
+#[derive(InitSpace)]
+pub struct RoundSeries {
+    pub authority: Pubkey,
+    pub series_id: u64,
+    pub interval_slots: u64,
+    pub duration_slots: u64,
+    pub round_id: u64,
+    pub round_start_at: u64,
+}
+
+// Tracks the id of an authority's next round, so one authority can run any number of rounds
+#[account]
+#[derive(InitSpace)]
+pub struct RoundAuthority {
+    pub authority: Pubkey,
+    pub next_round_id: u64,
+}
+
+#[derive(Accounts)]
+#[instruction(round_id: u64)]
+pub struct InitialiseRound<'info> {
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = ACCOUNT_DISCRIMINATOR_SPACE + RoundAuthority::INIT_SPACE,
+        seeds = [b"round_authority", authority.key().as_ref()],
+        bump
+    )]
+    pub round_authority: Account<'info, RoundAuthority>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = ACCOUNT_DISCRIMINATOR_SPACE + Round::INIT_SPACE,
+        seeds = [b"round", authority.key().as_ref(), &round_id.to_le_bytes()],
+        bump
+    )]
+    pub round: Account<'info, Round>,
+
+    #[account(mut, seeds = [b"pot", round.key().as_ref()], bump)]
+    pub pot: SystemAccount<'info>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(series_id: u64)]
+pub struct InitialiseSeries<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = ACCOUNT_DISCRIMINATOR_SPACE + RoundSeries::INIT_SPACE,
+        seeds = [b"series", authority.key().as_ref(), &series_id.to_le_bytes()],
+        bump
+    )]
+    pub series: Account<'info, RoundSeries>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = ACCOUNT_DISCRIMINATOR_SPACE + RoundAuthority::INIT_SPACE,
+        seeds = [b"round_authority", authority.key().as_ref()],
+        bump
+    )]
+    pub round_authority: Account<'info, RoundAuthority>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = ACCOUNT_DISCRIMINATOR_SPACE + Round::INIT_SPACE,
+        seeds = [
+            b"round",
+            authority.key().as_ref(),
+            &round_authority.next_round_id.to_le_bytes()
+        ],
+        bump
+    )]
+    pub round: Account<'info, Round>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RollSeries<'info> {
+    #[account(
+        mut,
+        seeds = [b"series", series.authority.as_ref(), &series.series_id.to_le_bytes()],
+        bump
+    )]
+    pub series: Account<'info, RoundSeries>,
+
+    #[account(
+        mut,
+        seeds = [b"round_authority", series.authority.as_ref()],
+        bump
+    )]
+    pub round_authority: Account<'info, RoundAuthority>,
+
+    /// CHECK: closed once its authority has closed the round, completed in the handler otherwise
+    #[account(
+        mut,
+        seeds = [b"round", series.authority.as_ref(), &series.round_id.to_le_bytes()],
+        bump
+    )]
+    pub current_round: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = user,
+        space = ACCOUNT_DISCRIMINATOR_SPACE + Round::INIT_SPACE,
+        seeds = [
+            b"round",
+            series.authority.as_ref(),
+            &round_authority.next_round_id.to_le_bytes()
+        ],
+        bump
+    )]
+    pub next_round: Account<'info, Round>,
+
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    /// CHECK: only exists once the current round is scored, frozen in the handler when it does
+    #[account(mut, seeds = [b"leaderboard", current_round.key().as_ref()], bump)]
+    pub leaderboard: UncheckedAccount<'info>,
+}
+
+#[derive(Accounts)]
+pub struct CloseSeries<'info> {
+    #[account(
+        mut,
+        has_one = authority,
+        close = authority,
+        seeds = [b"series", authority.key().as_ref(), &series.series_id.to_le_bytes()],
+        bump
+    )]
+    pub series: Account<'info, RoundSeries>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ActivateRound<'info> {
+    #[account(
+        mut,
+        seeds = [b"round", round.authority.as_ref(), &round.round_id.to_le_bytes()],
+        bump,
+        constraint = round.version == ROUND_VERSION @ RoundError::AccountNotMigrated
+    )]
+    pub round: Account<'info, Round>,
+
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    // Required by rounds gated on registration, see `check_registration`
+    #[account(
+        seeds = [b"registration", user.key().as_ref()],
+        bump,
+        seeds::program = register::ID
+    )]
+    pub registration: Option<Account<'info, Registration>>,
+}
+
+#[derive(Accounts)]
+pub struct CompleteRound<'info> {
+    #[account(
+        mut,
+        seeds = [b"round", round.authority.as_ref(), &round.round_id.to_le_bytes()],
+        bump,
+        constraint = round.version == ROUND_VERSION @ RoundError::AccountNotMigrated
+    )]
+    pub round: Account<'info, Round>,
+
+    pub user: Signer<'info>,
+
+    /// CHECK: only exists once the round is scored, frozen in the handler when it does
+    #[account(mut, seeds = [b"leaderboard", round.key().as_ref()], bump)]
+    pub leaderboard: UncheckedAccount<'info>,
+}
+
+#[derive(Accounts)]
+pub struct SettleRound<'info> {
+    #[account(
+        mut,
+        has_one = authority,
+        seeds = [b"round", round.authority.as_ref(), &round.round_id.to_le_bytes()],
+        bump,
+        constraint = round.version == ROUND_VERSION @ RoundError::AccountNotMigrated
+    )]
+    pub round: Account<'info, Round>,
+
+    // Receives the commission
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(mut, seeds = [b"pot", round.key().as_ref()], bump)]
+    pub pot: SystemAccount<'info>,
+
+    /// CHECK: only receives the prize, checked against `winner_participant` in the handler
+    #[account(mut)]
+    pub winner: UncheckedAccount<'info>,
+
+    pub winner_participant: Account<'info, Participant>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct CancelRound<'info> {
+    #[account(
+        mut,
+        has_one = authority,
+        seeds = [b"round", round.authority.as_ref(), &round.round_id.to_le_bytes()],
+        bump,
+        constraint = round.version == ROUND_VERSION @ RoundError::AccountNotMigrated
+    )]
+    pub round: Account<'info, Round>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+}
 
-        require!(start_slot > current_slot, RoundError::InvalidStartSlot);
+#[derive(Accounts)]
+pub struct SignalInterest<'info> {
+    #[account(
+        mut,
+        seeds = [b"round", round.authority.as_ref(), &round.round_id.to_le_bytes()],
+        bump,
+        constraint = round.version == ROUND_VERSION @ RoundError::AccountNotMigrated
+    )]
+    pub round: Account<'info, Round>,
 
-        round.start_slot = start_slot;
-        round.authority = ctx.accounts.authority.key();
+    #[account(
+        init,
+        payer = user,
+        space = ACCOUNT_DISCRIMINATOR_SPACE + Interest::INIT_SPACE,
+        seeds = [b"interest", round.key().as_ref(), user.key().as_ref()],
+        bump
+    )]
+    pub interest: Account<'info, Interest>,
 
-        msg!(
-            "Round {} initialised by {}",
-            start_slot,
-            ctx.accounts.authority.key()
-        );
+    #[account(mut)]
+    pub user: Signer<'info>,
 
-        Ok(())
-    }
+    pub system_program: Program<'info, System>,
 
-    pub fn activate_round(ctx: Context<ActivateRound>) -> Result<()> {
-        let round = &mut ctx.accounts.round;
-        let current_slot = Clock::get()?.slot;
+    // Required by rounds gated on registration, see `check_registration`
+    #[account(
+        seeds = [b"registration", user.key().as_ref()],
+        bump,
+        seeds::program = register::ID
+    )]
+    pub registration: Option<Account<'info, Registration>>,
+}
 
-        require!(round.activated_at.is_none(), RoundError::RoundAlreadyActive);
-        require!(
-            current_slot >= round.start_slot,
-            RoundError::InvalidRoundActivationSlot
-        );
+#[derive(Accounts)]
+pub struct RescheduleRound<'info> {
+    #[account(
+        mut,
+        has_one = authority,
+        seeds = [b"round", round.authority.as_ref(), &round.round_id.to_le_bytes()],
+        bump,
+        constraint = round.version == ROUND_VERSION @ RoundError::AccountNotMigrated
+    )]
+    pub round: Account<'info, Round>,
 
-        round.activated_by = Some(ctx.accounts.user.key());
-        round.activated_at = Some(current_slot);
+    pub authority: Signer<'info>,
+}
 
-        msg!(
-            "Round {} activated by {} at slot {}",
-            round.start_slot,
-            ctx.accounts.user.key(),
-            current_slot
-        );
+#[derive(Accounts)]
+pub struct CloseRound<'info> {
+    #[account(
+        mut,
+        has_one = authority,
+        close = authority,
+        seeds = [b"round", round.authority.as_ref(), &round.round_id.to_le_bytes()],
+        bump,
+        constraint = round.version == ROUND_VERSION @ RoundError::AccountNotMigrated
+    )]
+    pub round: Account<'info, Round>,
 
-        Ok(())
-    }
+    #[account(mut, seeds = [b"pot", round.key().as_ref()], bump)]
+    pub pot: SystemAccount<'info>,
 
-    pub fn complete_round(ctx: Context<CompleteRound>) -> Result<()> {
-        let round = &mut ctx.accounts.round;
-        let current_slot = Clock::get()?.slot;
+    #[account(mut)]
+    pub authority: Signer<'info>,
 
-        require!(round.activated_at.is_some(), RoundError::RoundNotYetActive);
-        require!(
-            round.completed_at.is_none(),
-            RoundError::RoundAlreadyComplete
-        );
+    pub system_program: Program<'info, System>,
+
+    /// CHECK: only exists once the round is scored, closed in the handler when it does
+    #[account(mut, seeds = [b"leaderboard", round.key().as_ref()], bump)]
+    pub leaderboard: UncheckedAccount<'info>,
+}
 
-        round.completed_at = Some(current_slot);
+#[derive(Accounts)]
+pub struct CloseParticipant<'info> {
+    /// CHECK: only checked to have been closed, its seeds pin the account below to it
+    #[account(constraint = round.data_is_empty() @ RoundError::RoundNotClosed)]
+    pub round: UncheckedAccount<'info>,
 
-        msg!(
-            "Round {} marked as complete at slot {}",
-            round.start_slot,
-            current_slot
-        );
+    #[account(
+        mut,
+        close = user,
+        seeds = [b"participant", round.key().as_ref(), user.key().as_ref()],
+        bump
+    )]
+    pub participant: Account<'info, Participant>,
 
-        Ok(())
-    }
+    #[account(mut)]
+    pub user: Signer<'info>,
 }
 
-#[account]
-#[derive(InitSpace)]
-pub struct Round {
-    pub start_slot: u64,
-    pub authority: Pubkey,
-    pub activated_at: Option<u64>,
-    pub activated_by: Option<Pubkey>,
-    pub completed_at: Option<u64>,
+#[derive(Accounts)]
+pub struct CloseSubmission<'info> {
+    /// CHECK: only checked to have been closed, its seeds pin the account below to it
+    #[account(constraint = round.data_is_empty() @ RoundError::RoundNotClosed)]
+    pub round: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        close = user,
+        seeds = [b"submission", round.key().as_ref(), user.key().as_ref()],
+        bump
+    )]
+    pub submission: Account<'info, Submission>,
+
+    #[account(mut)]
+    pub user: Signer<'info>,
 }
 
 #[derive(Accounts)]
-pub struct InitialiseRound<'info> {
+pub struct CloseInterest<'info> {
+    /// CHECK: only checked to have been closed, its seeds pin the account below to it
+    #[account(constraint = round.data_is_empty() @ RoundError::RoundNotClosed)]
+    pub round: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        close = user,
+        seeds = [b"interest", round.key().as_ref(), user.key().as_ref()],
+        bump
+    )]
+    pub interest: Account<'info, Interest>,
+
+    #[account(mut)]
+    pub user: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct CommitSubmission<'info> {
+    #[account(
+        seeds = [b"round", round.authority.as_ref(), &round.round_id.to_le_bytes()],
+        bump,
+        constraint = round.version == ROUND_VERSION @ RoundError::AccountNotMigrated
+    )]
+    pub round: Account<'info, Round>,
+
+    // Only participants can submit
+    #[account(seeds = [b"participant", round.key().as_ref(), user.key().as_ref()], bump)]
+    pub participant: Account<'info, Participant>,
+
+    #[account(
+        init,
+        payer = user,
+        space = ACCOUNT_DISCRIMINATOR_SPACE + Submission::INIT_SPACE,
+        seeds = [b"submission", round.key().as_ref(), user.key().as_ref()],
+        bump
+    )]
+    pub submission: Account<'info, Submission>,
+
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RevealSubmission<'info> {
+    #[account(
+        mut,
+        seeds = [b"round", round.authority.as_ref(), &round.round_id.to_le_bytes()],
+        bump,
+        constraint = round.version == ROUND_VERSION @ RoundError::AccountNotMigrated
+    )]
+    pub round: Account<'info, Round>,
+
+    #[account(
+        mut,
+        seeds = [b"submission", round.key().as_ref(), user.key().as_ref()],
+        bump
+    )]
+    pub submission: Account<'info, Submission>,
+
+    pub user: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(user: Pubkey)]
+pub struct SubmitScore<'info> {
+    #[account(
+        seeds = [b"round", round.authority.as_ref(), &round.round_id.to_le_bytes()],
+        bump,
+        constraint = round.version == ROUND_VERSION @ RoundError::AccountNotMigrated
+    )]
+    pub round: Account<'info, Round>,
+
+    // Only participants can be scored
+    #[account(seeds = [b"participant", round.key().as_ref(), user.as_ref()], bump)]
+    pub participant: Account<'info, Participant>,
+
+    #[account(
+        init_if_needed,
+        payer = scorer,
+        space = ACCOUNT_DISCRIMINATOR_SPACE + Leaderboard::INIT_SPACE,
+        seeds = [b"leaderboard", round.key().as_ref()],
+        bump
+    )]
+    pub leaderboard: Account<'info, Leaderboard>,
+
+    #[account(mut)]
+    pub scorer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct DrawWinner<'info> {
+    #[account(
+        mut,
+        seeds = [b"round", round.authority.as_ref(), &round.round_id.to_le_bytes()],
+        bump,
+        constraint = round.version == ROUND_VERSION @ RoundError::AccountNotMigrated
+    )]
+    pub round: Account<'info, Round>,
+
+    pub user: Signer<'info>,
+
+    /// CHECK: read by hand in the handler, deserialising all of SlotHashes costs too much compute
+    #[account(address = slot_hashes::ID)]
+    pub slot_hashes: UncheckedAccount<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(round_id: u64)]
+pub struct MigrateRound<'info> {
+    /// CHECK: on the layout from before round ids, decoded and closed in the handler
+    #[account(
+        mut,
+        seeds = [b"round", authority.key().as_ref()],
+        bump,
+        owner = crate::ID
+    )]
+    pub legacy_round: UncheckedAccount<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = ACCOUNT_DISCRIMINATOR_SPACE + RoundAuthority::INIT_SPACE,
+        seeds = [b"round_authority", authority.key().as_ref()],
+        bump
+    )]
+    pub round_authority: Account<'info, RoundAuthority>,
+
     #[account(
         init,
         payer = authority,
         space = ACCOUNT_DISCRIMINATOR_SPACE + Round::INIT_SPACE,
-        seeds = [b"round", authority.key().as_ref()],
+        seeds = [b"round", authority.key().as_ref(), &round_id.to_le_bytes()],
         bump
     )]
     pub round: Account<'info, Round>,
@@ -105,33 +1952,104 @@ pub struct InitialiseRound<'info> {
 }
 
 #[derive(Accounts)]
-pub struct ActivateRound<'info> {
+pub struct JoinRound<'info> {
     #[account(
         mut,
-        seeds = [b"round", round.authority.as_ref()],
-        bump
+        seeds = [b"round", round.authority.as_ref(), &round.round_id.to_le_bytes()],
+        bump,
+        constraint = round.version == ROUND_VERSION @ RoundError::AccountNotMigrated
     )]
     pub round: Account<'info, Round>,
 
+    #[account(
+        init,
+        payer = user,
+        space = ACCOUNT_DISCRIMINATOR_SPACE + Participant::INIT_SPACE,
+        seeds = [b"participant", round.key().as_ref(), user.key().as_ref()],
+        bump
+    )]
+    pub participant: Account<'info, Participant>,
+
+    #[account(mut)]
     pub user: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    // Required by rounds gated on registration, see `check_registration`
+    #[account(
+        seeds = [b"registration", user.key().as_ref()],
+        bump,
+        seeds::program = register::ID
+    )]
+    pub registration: Option<Account<'info, Registration>>,
 }
 
 #[derive(Accounts)]
-pub struct CompleteRound<'info> {
+pub struct EnterRound<'info> {
     #[account(
         mut,
-        has_one = authority,
-        seeds = [b"round", round.authority.as_ref()],
+        seeds = [b"round", round.authority.as_ref(), &round.round_id.to_le_bytes()],
+        bump,
+        constraint = round.version == ROUND_VERSION @ RoundError::AccountNotMigrated
+    )]
+    pub round: Account<'info, Round>,
+
+    #[account(
+        init,
+        payer = user,
+        space = ACCOUNT_DISCRIMINATOR_SPACE + Participant::INIT_SPACE,
+        seeds = [b"participant", round.key().as_ref(), user.key().as_ref()],
         bump
     )]
+    pub participant: Account<'info, Participant>,
+
+    #[account(mut, seeds = [b"pot", round.key().as_ref()], bump)]
+    pub pot: SystemAccount<'info>,
+
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    // Required by rounds gated on registration, see `check_registration`
+    #[account(
+        seeds = [b"registration", user.key().as_ref()],
+        bump,
+        seeds::program = register::ID
+    )]
+    pub registration: Option<Account<'info, Registration>>,
+}
+
+#[derive(Accounts)]
+pub struct RefundEntry<'info> {
+    #[account(
+        mut,
+        seeds = [b"round", round.authority.as_ref(), &round.round_id.to_le_bytes()],
+        bump,
+        constraint = round.version == ROUND_VERSION @ RoundError::AccountNotMigrated
+    )]
     pub round: Account<'info, Round>,
 
-    pub authority: Signer<'info>,
+    #[account(
+        mut,
+        close = user,
+        seeds = [b"participant", round.key().as_ref(), user.key().as_ref()],
+        bump
+    )]
+    pub participant: Account<'info, Participant>,
+
+    #[account(mut, seeds = [b"pot", round.key().as_ref()], bump)]
+    pub pot: SystemAccount<'info>,
+
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
 }
 
 #[error_code]
 pub enum RoundError {
-    #[msg("The start slot must be greater than the current slot")]
+    #[msg("The round must start in the future")]
     InvalidStartSlot,
 
     #[msg("The round is already active")]
@@ -143,6 +2061,128 @@ pub enum RoundError {
     #[msg("The round is already complete")]
     RoundAlreadyComplete,
 
-    #[msg("The current slot must be greater than or equal to the start slot")]
+    #[msg("The round can't be activated before its start")]
     InvalidRoundActivationSlot,
+
+    #[msg("The round id must be the authority's next round id")]
+    InvalidRoundId,
+
+    #[msg("The round must end after its start")]
+    InvalidEndSlot,
+
+    #[msg("The round's end has passed")]
+    RoundExpired,
+
+    #[msg("Only the authority can complete the round before its end")]
+    RoundNotYetEnded,
+
+    #[msg("The round has been cancelled")]
+    RoundCancelled,
+
+    #[msg("The account must be migrated to the current layout first")]
+    AccountNotMigrated,
+
+    #[msg("The participant cap must be greater than zero")]
+    InvalidMaxParticipants,
+
+    #[msg("The round has reached its participant cap")]
+    RoundFull,
+
+    #[msg("The commission must be at most 10000 basis points")]
+    InvalidCommission,
+
+    #[msg("The round has an entry fee, use enter_round")]
+    EntryFeeRequired,
+
+    #[msg("The round has no entry fee, use join_round")]
+    RoundHasNoEntryFee,
+
+    #[msg("The winner must have entered the round")]
+    WinnerNotEntered,
+
+    #[msg("The winner must be the participant at the drawn index")]
+    WinnerNotDrawn,
+
+    #[msg(
+        "Entries are only refundable once a round is cancelled, or completed and left unsettled"
+    )]
+    RefundUnavailable,
+
+    #[msg("The round has not yet been completed")]
+    RoundNotYetComplete,
+
+    #[msg("A winner has already been drawn, redrawing would let the result be ground")]
+    WinnerAlreadyDrawn,
+
+    #[msg("There must be at least one candidate, the index is the slot hash modulo candidates")]
+    InvalidCandidates,
+
+    #[msg("Candidates must match the participant count, or the count could be ground for a better index")]
+    CandidatesMismatch,
+
+    #[msg("The draw slot has not passed yet, its hash must be unknown until entries close")]
+    DrawSlotNotReached,
+
+    #[msg("The draw slot is no longer in SlotHashes, draws must run within 512 slots of it")]
+    DrawSlotHashExpired,
+
+    #[msg("The pot still holds entry fees, pay out a winner or refund the entries first")]
+    PotNotEmpty,
+
+    #[msg("The round requires the signer's registration in the register program")]
+    RegistrationRequired,
+
+    #[msg("The signer's registration has not been confirmed")]
+    RegistrationNotConfirmed,
+
+    #[msg("The reveal start must be after the round's start and no later than its end")]
+    InvalidRevealStartSlot,
+
+    #[msg("The round does not take submissions")]
+    SubmissionsDisabled,
+
+    #[msg("Commits close once the reveal start is reached")]
+    CommitPhaseOver,
+
+    #[msg("Reveals open at the reveal start")]
+    RevealNotStarted,
+
+    #[msg("The submission has already been revealed")]
+    AlreadyRevealed,
+
+    #[msg("The value and salt do not match the commitment")]
+    CommitmentMismatch,
+
+    #[msg("Only the authority or the round's scorer can submit scores")]
+    ScorerNotAllowed,
+
+    #[msg("The leaderboard was frozen when the round completed")]
+    LeaderboardFrozen,
+
+    #[msg("The minimum participants must not exceed the participant cap")]
+    InvalidMinParticipants,
+
+    #[msg("Interest can only be signalled before the round starts")]
+    InterestClosed,
+
+    #[msg("Fewer users signalled interest than the round's minimum participants")]
+    NotEnoughInterest,
+
+    #[msg("The series duration must be non-zero and shorter than its interval")]
+    InvalidSeriesCadence,
+
+    #[msg("The series' next round is due an interval after its current round's start slot")]
+    SeriesNotDue,
+
+    #[msg("The round already has a winner")]
+    RoundAlreadySettled,
+
+    #[msg("The round's settlement window has closed")]
+    SettlementWindowClosed,
+
+    #[msg("The round must be closed first")]
+    RoundNotClosed,
+
+    #[msg("Only the authority can draw over an off-chain entry list")]
+    DrawNotAllowed,
 }
@@ -1,15 +1,24 @@
-use anchor_lang::AccountDeserialize;
+use allowlist::AllowlistTree;
+use anchor_lang::{AccountDeserialize, Discriminator, Space};
 use litesvm::LiteSVM;
-use program_tests::{anchor_discriminator, assert_err_logs_contain, send_instr};
+use program_tests::{
+    anchor_discriminator, anchor_instr_data, assert_err_logs_contain, cpi_events, send_instr,
+    send_instrs, send_instrs_with_signers,
+};
+use solana_ed25519_program::new_ed25519_instruction_with_signature;
 use solana_loader_v3_interface::{get_program_data_address, state::UpgradeableLoaderState};
+#[cfg(feature = "bootstrap-authority")]
+use solana_sdk::signature::read_keypair_file;
 use solana_sdk::{
     account::Account,
+    clock::Clock,
     instruction::{AccountMeta, Instruction},
     native_token::LAMPORTS_PER_SOL,
     pubkey::Pubkey,
     signature::Keypair,
     signer::Signer,
     system_program::ID as SYSTEM_PROGRAM_ID,
+    sysvar::instructions::ID as INSTRUCTIONS_SYSVAR_ID,
 };
 
 fn registry_state_pda() -> (Pubkey, u8) {
@@ -20,14 +29,23 @@ fn registration_pda(registrant: &Pubkey) -> (Pubkey, u8) {
     Pubkey::find_program_address(&[b"registration", registrant.as_ref()], &register::ID)
 }
 
+fn voucher_nonce_pda(nonce: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"voucher_nonce", &nonce.to_le_bytes()], &register::ID)
+}
+
+// Signs the self-CPIs that `emit_cpi!` uses to publish events
+fn event_authority_pda() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"__event_authority"], &register::ID)
+}
+
 fn program_data_address() -> Pubkey {
     get_program_data_address(&register::ID)
 }
 
-fn setup_program_data_account(svm: &mut LiteSVM, upgrade_authority: &Pubkey) {
+fn setup_program_data_account(svm: &mut LiteSVM, upgrade_authority: Option<Pubkey>) {
     let program_data_state = UpgradeableLoaderState::ProgramData {
         slot: 0,
-        upgrade_authority_address: Some(*upgrade_authority),
+        upgrade_authority_address: upgrade_authority,
     };
     let data = bincode::serialize(&program_data_state).unwrap();
     svm.set_account(
@@ -43,15 +61,21 @@ fn setup_program_data_account(svm: &mut LiteSVM, upgrade_authority: &Pubkey) {
     .unwrap();
 }
 
-fn build_initialise_registry_instr(authority: &Pubkey, registry_state_pda: &Pubkey) -> Instruction {
+fn build_initialise_registry_instr(
+    authority: &Pubkey,
+    registry_state_pda: &Pubkey,
+    fifo_only: bool,
+) -> Instruction {
     Instruction::new_with_bytes(
         register::ID,
-        &anchor_discriminator("initialise_registry"),
+        &anchor_instr_data("initialise_registry", &[fifo_only as u8]),
         vec![
             AccountMeta::new(*authority, true),
             AccountMeta::new(*registry_state_pda, false),
             AccountMeta::new_readonly(program_data_address(), false),
             AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+            AccountMeta::new_readonly(event_authority_pda().0, false),
+            AccountMeta::new_readonly(register::ID, false),
         ],
     )
 }
@@ -61,14 +85,47 @@ fn build_register_instr(
     registry_state_pda: &Pubkey,
     registration_pda: &Pubkey,
 ) -> Instruction {
+    build_register_with_proof_instr(registrant, registry_state_pda, registration_pda, &[])
+}
+
+fn build_register_with_proof_instr(
+    registrant: &Pubkey,
+    registry_state_pda: &Pubkey,
+    registration_pda: &Pubkey,
+    proof: &[[u8; 32]],
+) -> Instruction {
+    build_sponsored_register_instr(
+        registrant,
+        registrant,
+        registry_state_pda,
+        registration_pda,
+        proof,
+    )
+}
+
+fn build_sponsored_register_instr(
+    payer: &Pubkey,
+    registrant: &Pubkey,
+    registry_state_pda: &Pubkey,
+    registration_pda: &Pubkey,
+    proof: &[[u8; 32]],
+) -> Instruction {
+    let mut args = (proof.len() as u32).to_le_bytes().to_vec();
+    for node in proof {
+        args.extend_from_slice(node);
+    }
+
     Instruction::new_with_bytes(
         register::ID,
-        &anchor_discriminator("register"),
+        &anchor_instr_data("register", &args),
         vec![
-            AccountMeta::new(*registrant, true),
+            AccountMeta::new(*payer, true),
+            AccountMeta::new_readonly(*registrant, true),
             AccountMeta::new(*registry_state_pda, false),
             AccountMeta::new(*registration_pda, false),
             AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+            AccountMeta::new_readonly(event_authority_pda().0, false),
+            AccountMeta::new_readonly(register::ID, false),
         ],
     )
 }
@@ -82,14 +139,168 @@ fn build_confirm_registration_instr(
         register::ID,
         &anchor_discriminator("confirm_registration"),
         vec![
-            AccountMeta::new_readonly(*registry_state_pda, false),
+            AccountMeta::new(*registry_state_pda, false),
+            AccountMeta::new_readonly(*authority, true),
+            AccountMeta::new(*registration_pda, false),
+            AccountMeta::new_readonly(event_authority_pda().0, false),
+            AccountMeta::new_readonly(register::ID, false),
+        ],
+    )
+}
+
+fn build_configure_approvers_instr(
+    authority: &Pubkey,
+    registry_state_pda: &Pubkey,
+    approvers: &[Pubkey],
+    approval_threshold: u8,
+) -> Instruction {
+    let mut args = (approvers.len() as u32).to_le_bytes().to_vec();
+    for approver in approvers {
+        args.extend_from_slice(approver.as_ref());
+    }
+    args.push(approval_threshold);
+
+    Instruction::new_with_bytes(
+        register::ID,
+        &anchor_instr_data("configure_approvers", &args),
+        vec![
+            AccountMeta::new(*registry_state_pda, false),
+            AccountMeta::new_readonly(*authority, true),
+            AccountMeta::new_readonly(event_authority_pda().0, false),
+            AccountMeta::new_readonly(register::ID, false),
+        ],
+    )
+}
+
+fn build_register_with_voucher_instr(
+    payer: &Pubkey,
+    registrant: &Pubkey,
+    registry_state_pda: &Pubkey,
+    registration_pda: &Pubkey,
+    expiry_slot: u64,
+    nonce: u64,
+) -> Instruction {
+    let args = [expiry_slot.to_le_bytes(), nonce.to_le_bytes()].concat();
+
+    Instruction::new_with_bytes(
+        register::ID,
+        &anchor_instr_data("register_with_voucher", &args),
+        vec![
+            AccountMeta::new(*payer, true),
+            AccountMeta::new_readonly(*registrant, true),
+            AccountMeta::new(*registry_state_pda, false),
+            AccountMeta::new(*registration_pda, false),
+            AccountMeta::new(voucher_nonce_pda(nonce).0, false),
+            AccountMeta::new_readonly(INSTRUCTIONS_SYSVAR_ID, false),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+            AccountMeta::new_readonly(event_authority_pda().0, false),
+            AccountMeta::new_readonly(register::ID, false),
+        ],
+    )
+}
+
+// The Ed25519 program instruction carrying `signer`'s voucher for `registrant`.
+fn build_voucher_instr(
+    signer: &Keypair,
+    registrant: &Pubkey,
+    registry_state_pda: &Pubkey,
+    expiry_slot: u64,
+    nonce: u64,
+) -> Instruction {
+    let message = [
+        registrant.as_ref(),
+        registry_state_pda.as_ref(),
+        &expiry_slot.to_le_bytes(),
+        &nonce.to_le_bytes(),
+    ]
+    .concat();
+    let signature = signer.sign_message(&message);
+
+    new_ed25519_instruction_with_signature(&message, &signature.into(), &signer.pubkey().to_bytes())
+}
+
+fn build_set_allowlist_root_instr(
+    authority: &Pubkey,
+    registry_state_pda: &Pubkey,
+    allowlist_root: Option<[u8; 32]>,
+) -> Instruction {
+    let args = match allowlist_root {
+        Some(root) => [&[1], root.as_slice()].concat(),
+        None => vec![0],
+    };
+
+    Instruction::new_with_bytes(
+        register::ID,
+        &anchor_instr_data("set_allowlist_root", &args),
+        vec![
+            AccountMeta::new(*registry_state_pda, false),
             AccountMeta::new_readonly(*authority, true),
+            AccountMeta::new_readonly(event_authority_pda().0, false),
+            AccountMeta::new_readonly(register::ID, false),
+        ],
+    )
+}
+
+fn build_migrate_registry_state_instr(
+    authority: &Pubkey,
+    registry_state_pda: &Pubkey,
+    confirmed_count: u64,
+) -> Instruction {
+    Instruction::new_with_bytes(
+        register::ID,
+        &anchor_instr_data("migrate_registry_state", &confirmed_count.to_le_bytes()),
+        vec![
+            AccountMeta::new(*registry_state_pda, false),
+            AccountMeta::new(*authority, true),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+        ],
+    )
+}
+
+fn build_migrate_registration_instr(
+    payer: &Pubkey,
+    registrant: &Pubkey,
+    registration_pda: &Pubkey,
+) -> Instruction {
+    Instruction::new_with_bytes(
+        register::ID,
+        &anchor_instr_data("migrate_registration", registrant.as_ref()),
+        vec![
+            AccountMeta::new(*payer, true),
             AccountMeta::new(*registration_pda, false),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+        ],
+    )
+}
+
+fn build_approve_registration_instr(
+    approver: &Pubkey,
+    registry_state_pda: &Pubkey,
+    registration_pda: &Pubkey,
+) -> Instruction {
+    Instruction::new_with_bytes(
+        register::ID,
+        &anchor_discriminator("approve_registration"),
+        vec![
+            AccountMeta::new(*registry_state_pda, false),
+            AccountMeta::new_readonly(*approver, true),
+            AccountMeta::new(*registration_pda, false),
+            AccountMeta::new_readonly(event_authority_pda().0, false),
+            AccountMeta::new_readonly(register::ID, false),
         ],
     )
 }
 
 fn setup(upgrade_authority: &Pubkey) -> LiteSVM {
+    setup_with_upgrade_authority(Some(*upgrade_authority))
+}
+
+// An immutable program's ProgramData has no upgrade authority
+fn setup_immutable() -> LiteSVM {
+    setup_with_upgrade_authority(None)
+}
+
+fn setup_with_upgrade_authority(upgrade_authority: Option<Pubkey>) -> LiteSVM {
     let mut svm = LiteSVM::new();
     svm.add_program_from_file(register::ID, "../target/deploy/register.so")
         .expect("Failed to load register program");
@@ -118,12 +329,16 @@ fn initialises_the_registry() {
     svm.airdrop(&authority.pubkey(), LAMPORTS_PER_SOL).unwrap();
     let (pda, _) = registry_state_pda();
 
-    let instr = build_initialise_registry_instr(&authority.pubkey(), &pda);
+    let instr = build_initialise_registry_instr(&authority.pubkey(), &pda, false);
     send_instr(&mut svm, instr, &authority).expect("initialise should succeed");
 
     let state = fetch_registry_state(&svm, &pda).expect("registry state should exist");
     assert_eq!(state.authority, authority.pubkey());
     assert_eq!(state.registration_count, 0);
+    assert!(!state.fifo_only);
+    assert_eq!(state.version, 3);
+    assert_eq!(state.confirmed_count, 0);
+    assert_eq!(state.pending_count, 0);
 }
 
 #[test]
@@ -133,14 +348,14 @@ fn fails_to_initialise_registry_if_already_initialised() {
     svm.airdrop(&authority.pubkey(), LAMPORTS_PER_SOL).unwrap();
     let (pda, _) = registry_state_pda();
 
-    let instr = build_initialise_registry_instr(&authority.pubkey(), &pda);
+    let instr = build_initialise_registry_instr(&authority.pubkey(), &pda, false);
     send_instr(&mut svm, instr, &authority).expect("first initialise should succeed");
 
     // LiteSVM rejects identical txns as `AlreadyProcessed`.
     // Expiring the blockhash forces a new one, making the txns distinct.
     svm.expire_blockhash();
 
-    let instr = build_initialise_registry_instr(&authority.pubkey(), &pda);
+    let instr = build_initialise_registry_instr(&authority.pubkey(), &pda, false);
     let result = send_instr(&mut svm, instr, &authority);
     assert!(result.is_err(), "second initialise should fail");
     assert_err_logs_contain(&result, "already in use");
@@ -165,7 +380,7 @@ fn registers_a_user() {
     svm.airdrop(&authority.pubkey(), LAMPORTS_PER_SOL).unwrap();
     let (state_pda, _) = registry_state_pda();
 
-    let instr = build_initialise_registry_instr(&authority.pubkey(), &state_pda);
+    let instr = build_initialise_registry_instr(&authority.pubkey(), &state_pda, false);
     send_instr(&mut svm, instr, &authority).expect("initialise should succeed");
 
     let user = Keypair::new();
@@ -191,7 +406,7 @@ fn registers_multiple_users_with_incrementing_index() {
     svm.airdrop(&authority.pubkey(), LAMPORTS_PER_SOL).unwrap();
     let (state_pda, _) = registry_state_pda();
 
-    let instr = build_initialise_registry_instr(&authority.pubkey(), &state_pda);
+    let instr = build_initialise_registry_instr(&authority.pubkey(), &state_pda, false);
     send_instr(&mut svm, instr, &authority).expect("initialise should succeed");
 
     for i in 0..3 {
@@ -217,7 +432,7 @@ fn fails_to_register_if_already_registered() {
     svm.airdrop(&authority.pubkey(), LAMPORTS_PER_SOL).unwrap();
     let (state_pda, _) = registry_state_pda();
 
-    let instr = build_initialise_registry_instr(&authority.pubkey(), &state_pda);
+    let instr = build_initialise_registry_instr(&authority.pubkey(), &state_pda, false);
     send_instr(&mut svm, instr, &authority).expect("initialise should succeed");
 
     let user = Keypair::new();
@@ -261,7 +476,7 @@ fn authority_confirms_a_registration() {
     svm.airdrop(&authority.pubkey(), LAMPORTS_PER_SOL).unwrap();
     let (state_pda, _) = registry_state_pda();
 
-    let instr = build_initialise_registry_instr(&authority.pubkey(), &state_pda);
+    let instr = build_initialise_registry_instr(&authority.pubkey(), &state_pda, false);
     send_instr(&mut svm, instr, &authority).expect("initialise should succeed");
 
     let user = Keypair::new();
@@ -288,7 +503,7 @@ fn fails_to_confirm_if_signer_is_not_authority() {
     svm.airdrop(&authority.pubkey(), LAMPORTS_PER_SOL).unwrap();
     let (state_pda, _) = registry_state_pda();
 
-    let instr = build_initialise_registry_instr(&authority.pubkey(), &state_pda);
+    let instr = build_initialise_registry_instr(&authority.pubkey(), &state_pda, false);
     send_instr(&mut svm, instr, &authority).expect("initialise should succeed");
 
     let user = Keypair::new();
@@ -314,7 +529,7 @@ fn fails_to_confirm_if_registration_not_found() {
     svm.airdrop(&authority.pubkey(), LAMPORTS_PER_SOL).unwrap();
     let (state_pda, _) = registry_state_pda();
 
-    let instr = build_initialise_registry_instr(&authority.pubkey(), &state_pda);
+    let instr = build_initialise_registry_instr(&authority.pubkey(), &state_pda, false);
     send_instr(&mut svm, instr, &authority).expect("initialise should succeed");
 
     let unknown_user = Keypair::new();
@@ -333,7 +548,7 @@ fn fails_to_confirm_if_already_confirmed() {
     svm.airdrop(&authority.pubkey(), LAMPORTS_PER_SOL).unwrap();
     let (state_pda, _) = registry_state_pda();
 
-    let instr = build_initialise_registry_instr(&authority.pubkey(), &state_pda);
+    let instr = build_initialise_registry_instr(&authority.pubkey(), &state_pda, false);
     send_instr(&mut svm, instr, &authority).expect("initialise should succeed");
 
     let user = Keypair::new();
@@ -379,7 +594,7 @@ fn confirms_multiple_registrations_in_fifo_order() {
     svm.airdrop(&authority.pubkey(), LAMPORTS_PER_SOL).unwrap();
     let (state_pda, _) = registry_state_pda();
 
-    let instr = build_initialise_registry_instr(&authority.pubkey(), &state_pda);
+    let instr = build_initialise_registry_instr(&authority.pubkey(), &state_pda, false);
     send_instr(&mut svm, instr, &authority).expect("initialise should succeed");
 
     let mut reg_pdas = Vec::new();
@@ -412,7 +627,7 @@ fn confirms_registrations_out_of_fifo_order() {
     svm.airdrop(&authority.pubkey(), LAMPORTS_PER_SOL).unwrap();
     let (state_pda, _) = registry_state_pda();
 
-    let instr = build_initialise_registry_instr(&authority.pubkey(), &state_pda);
+    let instr = build_initialise_registry_instr(&authority.pubkey(), &state_pda, false);
     send_instr(&mut svm, instr, &authority).expect("initialise should succeed");
 
     let mut reg_pdas = Vec::new();
@@ -448,7 +663,7 @@ fn fails_to_initialise_registry_if_signer_is_not_upgrade_authority() {
         .unwrap();
     let (pda, _) = registry_state_pda();
 
-    let instr = build_initialise_registry_instr(&non_authority.pubkey(), &pda);
+    let instr = build_initialise_registry_instr(&non_authority.pubkey(), &pda, false);
     let result = send_instr(&mut svm, instr, &non_authority);
     assert!(
         result.is_err(),
@@ -456,3 +671,1414 @@ fn fails_to_initialise_registry_if_signer_is_not_upgrade_authority() {
     );
     assert_err_logs_contain(&result, "Unauthorised");
 }
+
+// Immutable program
+
+#[test]
+fn fails_to_initialise_registry_when_program_is_immutable() {
+    let mut svm = setup_immutable();
+
+    let signer = Keypair::new();
+    svm.airdrop(&signer.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    let (pda, _) = registry_state_pda();
+
+    let instr = build_initialise_registry_instr(&signer.pubkey(), &pda, false);
+    let result = send_instr(&mut svm, instr, &signer);
+    assert!(
+        result.is_err(),
+        "initialise without an upgrade authority should fail"
+    );
+    assert_err_logs_contain(&result, "Unauthorised");
+}
+
+// Run with `cargo test -p program-tests --features bootstrap-authority` against a register
+// program built with `anchor build -p register -- --features bootstrap-authority`
+#[cfg(feature = "bootstrap-authority")]
+fn bootstrap_authority() -> Keypair {
+    read_keypair_file("fixtures/bootstrap_authority.json")
+        .expect("Failed to read bootstrap authority keypair")
+}
+
+#[cfg(feature = "bootstrap-authority")]
+#[test]
+fn bootstrap_authority_initialises_registry_when_program_is_immutable() {
+    let mut svm = setup_immutable();
+
+    let authority = bootstrap_authority();
+    svm.airdrop(&authority.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    let (pda, _) = registry_state_pda();
+
+    let instr = build_initialise_registry_instr(&authority.pubkey(), &pda, false);
+    send_instr(&mut svm, instr, &authority).expect("initialise should succeed");
+
+    let state = fetch_registry_state(&svm, &pda).expect("registry state should exist");
+    assert_eq!(state.authority, authority.pubkey());
+    assert_eq!(state.registration_count, 0);
+}
+
+#[cfg(feature = "bootstrap-authority")]
+#[test]
+fn bootstrap_authority_cannot_initialise_registry_while_program_is_upgradeable() {
+    let upgrade_authority = Keypair::new();
+    let mut svm = setup(&upgrade_authority.pubkey());
+
+    let authority = bootstrap_authority();
+    svm.airdrop(&authority.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    let (pda, _) = registry_state_pda();
+
+    let instr = build_initialise_registry_instr(&authority.pubkey(), &pda, false);
+    let result = send_instr(&mut svm, instr, &authority);
+    assert!(
+        result.is_err(),
+        "bootstrap authority should not override the upgrade authority"
+    );
+    assert_err_logs_contain(&result, "Unauthorised");
+}
+
+// FIFO-only mode
+
+#[test]
+fn initialises_a_fifo_only_registry() {
+    let authority = Keypair::new();
+    let mut svm = setup(&authority.pubkey());
+    svm.airdrop(&authority.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    let (pda, _) = registry_state_pda();
+
+    let instr = build_initialise_registry_instr(&authority.pubkey(), &pda, true);
+    send_instr(&mut svm, instr, &authority).expect("initialise should succeed");
+
+    let state = fetch_registry_state(&svm, &pda).expect("registry state should exist");
+    assert!(state.fifo_only);
+    assert_eq!(state.next_to_confirm, 0);
+}
+
+#[test]
+fn fifo_only_registry_confirms_registrations_in_fifo_order() {
+    let authority = Keypair::new();
+    let mut svm = setup(&authority.pubkey());
+    svm.airdrop(&authority.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    let (state_pda, _) = registry_state_pda();
+
+    let instr = build_initialise_registry_instr(&authority.pubkey(), &state_pda, true);
+    send_instr(&mut svm, instr, &authority).expect("initialise should succeed");
+
+    let mut reg_pdas = Vec::new();
+    for _ in 0..3 {
+        let user = Keypair::new();
+        svm.airdrop(&user.pubkey(), LAMPORTS_PER_SOL).unwrap();
+        let (reg_pda, _) = registration_pda(&user.pubkey());
+
+        let instr = build_register_instr(&user.pubkey(), &state_pda, &reg_pda);
+        send_instr(&mut svm, instr, &user).expect("register should succeed");
+
+        reg_pdas.push(reg_pda);
+    }
+
+    for (i, reg_pda) in reg_pdas.iter().enumerate() {
+        let instr = build_confirm_registration_instr(&authority.pubkey(), &state_pda, reg_pda);
+        send_instr(&mut svm, instr, &authority).expect("confirm should succeed");
+
+        let state = fetch_registry_state(&svm, &state_pda).expect("registry state should exist");
+        assert_eq!(state.next_to_confirm, i as u64 + 1);
+    }
+}
+
+#[test]
+fn fifo_only_registry_fails_to_confirm_registrations_out_of_fifo_order() {
+    let authority = Keypair::new();
+    let mut svm = setup(&authority.pubkey());
+    svm.airdrop(&authority.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    let (state_pda, _) = registry_state_pda();
+
+    let instr = build_initialise_registry_instr(&authority.pubkey(), &state_pda, true);
+    send_instr(&mut svm, instr, &authority).expect("initialise should succeed");
+
+    let mut reg_pdas = Vec::new();
+    for _ in 0..2 {
+        let user = Keypair::new();
+        svm.airdrop(&user.pubkey(), LAMPORTS_PER_SOL).unwrap();
+        let (reg_pda, _) = registration_pda(&user.pubkey());
+
+        let instr = build_register_instr(&user.pubkey(), &state_pda, &reg_pda);
+        send_instr(&mut svm, instr, &user).expect("register should succeed");
+
+        reg_pdas.push(reg_pda);
+    }
+
+    let instr = build_confirm_registration_instr(&authority.pubkey(), &state_pda, &reg_pdas[1]);
+    let result = send_instr(&mut svm, instr, &authority);
+    assert!(result.is_err(), "confirm out of order should fail");
+    assert_err_logs_contain(&result, "RegistrationNotNextInQueue");
+
+    let registration = fetch_registration(&svm, &reg_pdas[1]).expect("registration should exist");
+    assert_eq!(registration.confirmed_at, None);
+
+    let state = fetch_registry_state(&svm, &state_pda).expect("registry state should exist");
+    assert_eq!(state.next_to_confirm, 0);
+}
+
+// Multisig approval
+
+// Initialises the registry, registers a single user and configures a 2-of-3 approver set.
+fn setup_multisig_registration(
+    svm: &mut LiteSVM,
+    authority: &Keypair,
+    approvers: &[Keypair],
+) -> (Pubkey, Pubkey) {
+    svm.airdrop(&authority.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    let (state_pda, _) = registry_state_pda();
+
+    let instr = build_initialise_registry_instr(&authority.pubkey(), &state_pda, false);
+    send_instr(svm, instr, authority).expect("initialise should succeed");
+
+    let approver_keys: Vec<Pubkey> = approvers.iter().map(|a| a.pubkey()).collect();
+    let instr = build_configure_approvers_instr(&authority.pubkey(), &state_pda, &approver_keys, 2);
+    send_instr(svm, instr, authority).expect("configure approvers should succeed");
+
+    for approver in approvers {
+        svm.airdrop(&approver.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    }
+
+    let user = Keypair::new();
+    svm.airdrop(&user.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    let (reg_pda, _) = registration_pda(&user.pubkey());
+
+    let instr = build_register_instr(&user.pubkey(), &state_pda, &reg_pda);
+    send_instr(svm, instr, &user).expect("register should succeed");
+
+    (state_pda, reg_pda)
+}
+
+#[test]
+fn configures_registry_approvers() {
+    let authority = Keypair::new();
+    let mut svm = setup(&authority.pubkey());
+    let approvers = [Keypair::new(), Keypair::new(), Keypair::new()];
+    let (state_pda, _) = setup_multisig_registration(&mut svm, &authority, &approvers);
+
+    let state = fetch_registry_state(&svm, &state_pda).expect("registry state should exist");
+    let approver_keys: Vec<Pubkey> = approvers.iter().map(|a| a.pubkey()).collect();
+    assert_eq!(state.approvers, approver_keys);
+    assert_eq!(state.approval_threshold, 2);
+}
+
+#[test]
+fn fails_to_configure_approvers_if_signer_is_not_authority() {
+    let authority = Keypair::new();
+    let mut svm = setup(&authority.pubkey());
+    svm.airdrop(&authority.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    let (state_pda, _) = registry_state_pda();
+
+    let instr = build_initialise_registry_instr(&authority.pubkey(), &state_pda, false);
+    send_instr(&mut svm, instr, &authority).expect("initialise should succeed");
+
+    let non_authority = Keypair::new();
+    svm.airdrop(&non_authority.pubkey(), LAMPORTS_PER_SOL)
+        .unwrap();
+    let instr = build_configure_approvers_instr(
+        &non_authority.pubkey(),
+        &state_pda,
+        &[non_authority.pubkey()],
+        1,
+    );
+    let result = send_instr(&mut svm, instr, &non_authority);
+    assert!(result.is_err(), "configure by non-authority should fail");
+    assert_err_logs_contain(&result, "ConstraintHasOne");
+}
+
+#[test]
+fn fails_to_configure_approvers_with_invalid_threshold() {
+    let authority = Keypair::new();
+    let mut svm = setup(&authority.pubkey());
+    svm.airdrop(&authority.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    let (state_pda, _) = registry_state_pda();
+
+    let instr = build_initialise_registry_instr(&authority.pubkey(), &state_pda, false);
+    send_instr(&mut svm, instr, &authority).expect("initialise should succeed");
+
+    let approvers = [Pubkey::new_unique(), Pubkey::new_unique()];
+    let instr = build_configure_approvers_instr(&authority.pubkey(), &state_pda, &approvers, 3);
+    let result = send_instr(&mut svm, instr, &authority);
+    assert!(
+        result.is_err(),
+        "threshold above approver count should fail"
+    );
+    assert_err_logs_contain(&result, "InvalidApprovalThreshold");
+}
+
+#[test]
+fn fails_to_configure_duplicate_approvers() {
+    let authority = Keypair::new();
+    let mut svm = setup(&authority.pubkey());
+    svm.airdrop(&authority.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    let (state_pda, _) = registry_state_pda();
+
+    let instr = build_initialise_registry_instr(&authority.pubkey(), &state_pda, false);
+    send_instr(&mut svm, instr, &authority).expect("initialise should succeed");
+
+    let approver = Pubkey::new_unique();
+    let instr =
+        build_configure_approvers_instr(&authority.pubkey(), &state_pda, &[approver, approver], 2);
+    let result = send_instr(&mut svm, instr, &authority);
+    assert!(result.is_err(), "duplicate approvers should fail");
+    assert_err_logs_contain(&result, "DuplicateApprover");
+}
+
+#[test]
+fn confirms_a_registration_once_approval_threshold_is_reached() {
+    let authority = Keypair::new();
+    let mut svm = setup(&authority.pubkey());
+    let approvers = [Keypair::new(), Keypair::new(), Keypair::new()];
+    let (state_pda, reg_pda) = setup_multisig_registration(&mut svm, &authority, &approvers);
+
+    let instr = build_approve_registration_instr(&approvers[0].pubkey(), &state_pda, &reg_pda);
+    send_instr(&mut svm, instr, &approvers[0]).expect("first approval should succeed");
+
+    let registration = fetch_registration(&svm, &reg_pda).expect("registration should exist");
+    assert_eq!(registration.approvals, 0b001);
+    assert_eq!(registration.confirmed_at, None);
+
+    let instr = build_approve_registration_instr(&approvers[2].pubkey(), &state_pda, &reg_pda);
+    send_instr(&mut svm, instr, &approvers[2]).expect("second approval should succeed");
+
+    let registration = fetch_registration(&svm, &reg_pda).expect("registration should exist");
+    assert_eq!(registration.approvals, 0b101);
+    assert!(
+        registration.confirmed_at.is_some(),
+        "confirmed_at should be set"
+    );
+}
+
+#[test]
+fn fails_to_approve_if_signer_is_not_an_approver() {
+    let authority = Keypair::new();
+    let mut svm = setup(&authority.pubkey());
+    let approvers = [Keypair::new(), Keypair::new(), Keypair::new()];
+    let (state_pda, reg_pda) = setup_multisig_registration(&mut svm, &authority, &approvers);
+
+    let instr = build_approve_registration_instr(&authority.pubkey(), &state_pda, &reg_pda);
+    let result = send_instr(&mut svm, instr, &authority);
+    assert!(result.is_err(), "approval by non-approver should fail");
+    assert_err_logs_contain(&result, "NotAnApprover");
+}
+
+#[test]
+fn fails_to_approve_twice_with_the_same_approver() {
+    let authority = Keypair::new();
+    let mut svm = setup(&authority.pubkey());
+    let approvers = [Keypair::new(), Keypair::new(), Keypair::new()];
+    let (state_pda, reg_pda) = setup_multisig_registration(&mut svm, &authority, &approvers);
+
+    let instr = build_approve_registration_instr(&approvers[1].pubkey(), &state_pda, &reg_pda);
+    send_instr(&mut svm, instr, &approvers[1]).expect("first approval should succeed");
+
+    // LiteSVM rejects identical txns as `AlreadyProcessed`.
+    // Expiring the blockhash forces a new one, making the txns distinct.
+    svm.expire_blockhash();
+
+    let instr = build_approve_registration_instr(&approvers[1].pubkey(), &state_pda, &reg_pda);
+    let result = send_instr(&mut svm, instr, &approvers[1]);
+    assert!(
+        result.is_err(),
+        "second approval by same approver should fail"
+    );
+    assert_err_logs_contain(&result, "RegistrationAlreadyApproved");
+}
+
+#[test]
+fn approvals_do_not_carry_over_to_a_new_approver_set() {
+    let authority = Keypair::new();
+    let mut svm = setup(&authority.pubkey());
+    let approvers = [Keypair::new(), Keypair::new(), Keypair::new()];
+    let (state_pda, reg_pda) = setup_multisig_registration(&mut svm, &authority, &approvers);
+
+    let instr = build_approve_registration_instr(&approvers[0].pubkey(), &state_pda, &reg_pda);
+    send_instr(&mut svm, instr, &approvers[0]).expect("approval should succeed");
+
+    let new_approvers = [Keypair::new(), Keypair::new(), Keypair::new()];
+    let new_approver_keys: Vec<Pubkey> = new_approvers.iter().map(|a| a.pubkey()).collect();
+    let instr =
+        build_configure_approvers_instr(&authority.pubkey(), &state_pda, &new_approver_keys, 2);
+    send_instr(&mut svm, instr, &authority).expect("reconfigure approvers should succeed");
+
+    // The old bit 0 would otherwise reach the threshold with this one
+    svm.airdrop(&new_approvers[1].pubkey(), LAMPORTS_PER_SOL)
+        .unwrap();
+    let instr = build_approve_registration_instr(&new_approvers[1].pubkey(), &state_pda, &reg_pda);
+    send_instr(&mut svm, instr, &new_approvers[1]).expect("approval should succeed");
+
+    let registration = fetch_registration(&svm, &reg_pda).expect("registration should exist");
+    assert_eq!(registration.approvals, 0b010);
+    assert_eq!(registration.confirmed_at, None);
+
+    // Bit 0 is free again for the new set's first approver
+    svm.airdrop(&new_approvers[0].pubkey(), LAMPORTS_PER_SOL)
+        .unwrap();
+    let instr = build_approve_registration_instr(&new_approvers[0].pubkey(), &state_pda, &reg_pda);
+    send_instr(&mut svm, instr, &new_approvers[0]).expect("approval should succeed");
+
+    let registration = fetch_registration(&svm, &reg_pda).expect("registration should exist");
+    assert_eq!(registration.approvals, 0b011);
+    assert!(
+        registration.confirmed_at.is_some(),
+        "confirmed_at should be set"
+    );
+}
+
+#[test]
+fn fails_to_confirm_with_authority_when_approvers_are_configured() {
+    let authority = Keypair::new();
+    let mut svm = setup(&authority.pubkey());
+    let approvers = [Keypair::new(), Keypair::new(), Keypair::new()];
+    let (state_pda, reg_pda) = setup_multisig_registration(&mut svm, &authority, &approvers);
+
+    let instr = build_confirm_registration_instr(&authority.pubkey(), &state_pda, &reg_pda);
+    let result = send_instr(&mut svm, instr, &authority);
+    assert!(result.is_err(), "authority confirm should fail");
+    assert_err_logs_contain(&result, "ApprovalRequired");
+}
+
+// Allowlist
+
+// Initialises the registry and restricts it to an allowlist of `invitees` plus some other keys.
+fn setup_allowlist(svm: &mut LiteSVM, authority: &Keypair, invitees: &[&Keypair]) -> AllowlistTree {
+    svm.airdrop(&authority.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    let (state_pda, _) = registry_state_pda();
+
+    let instr = build_initialise_registry_instr(&authority.pubkey(), &state_pda, false);
+    send_instr(svm, instr, authority).expect("initialise should succeed");
+
+    let mut keys: Vec<[u8; 32]> = invitees.iter().map(|i| i.pubkey().to_bytes()).collect();
+    keys.extend((0..4).map(|_| Pubkey::new_unique().to_bytes()));
+    let tree = AllowlistTree::new(&keys);
+
+    let instr = build_set_allowlist_root_instr(&authority.pubkey(), &state_pda, Some(tree.root()));
+    send_instr(svm, instr, authority).expect("set allowlist root should succeed");
+
+    for invitee in invitees {
+        svm.airdrop(&invitee.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    }
+
+    tree
+}
+
+#[test]
+fn sets_the_allowlist_root() {
+    let authority = Keypair::new();
+    let mut svm = setup(&authority.pubkey());
+    let invitee = Keypair::new();
+    let tree = setup_allowlist(&mut svm, &authority, &[&invitee]);
+    let (state_pda, _) = registry_state_pda();
+
+    let state = fetch_registry_state(&svm, &state_pda).expect("registry state should exist");
+    assert_eq!(state.allowlist_root, Some(tree.root()));
+}
+
+#[test]
+fn fails_to_set_allowlist_root_if_signer_is_not_authority() {
+    let authority = Keypair::new();
+    let mut svm = setup(&authority.pubkey());
+    svm.airdrop(&authority.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    let (state_pda, _) = registry_state_pda();
+
+    let instr = build_initialise_registry_instr(&authority.pubkey(), &state_pda, false);
+    send_instr(&mut svm, instr, &authority).expect("initialise should succeed");
+
+    let non_authority = Keypair::new();
+    svm.airdrop(&non_authority.pubkey(), LAMPORTS_PER_SOL)
+        .unwrap();
+    let instr = build_set_allowlist_root_instr(&non_authority.pubkey(), &state_pda, Some([7; 32]));
+    let result = send_instr(&mut svm, instr, &non_authority);
+    assert!(
+        result.is_err(),
+        "set allowlist root by non-authority should fail"
+    );
+    assert_err_logs_contain(&result, "ConstraintHasOne");
+}
+
+#[test]
+fn registers_an_allowlisted_user_with_a_valid_proof() {
+    let authority = Keypair::new();
+    let mut svm = setup(&authority.pubkey());
+    let invitee = Keypair::new();
+    let tree = setup_allowlist(&mut svm, &authority, &[&invitee]);
+    let (state_pda, _) = registry_state_pda();
+    let (reg_pda, _) = registration_pda(&invitee.pubkey());
+
+    let proof = tree.proof(&invitee.pubkey().to_bytes()).unwrap();
+    let instr = build_register_with_proof_instr(&invitee.pubkey(), &state_pda, &reg_pda, &proof);
+    send_instr(&mut svm, instr, &invitee).expect("register should succeed");
+
+    let registration = fetch_registration(&svm, &reg_pda).expect("registration should exist");
+    assert_eq!(registration.registrant, invitee.pubkey());
+}
+
+#[test]
+fn fails_to_register_with_an_invalid_allowlist_proof() {
+    let authority = Keypair::new();
+    let mut svm = setup(&authority.pubkey());
+    let invitee = Keypair::new();
+    let tree = setup_allowlist(&mut svm, &authority, &[&invitee]);
+    let (state_pda, _) = registry_state_pda();
+
+    // An uninvited user replaying the invitee's proof
+    let user = Keypair::new();
+    svm.airdrop(&user.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    let (reg_pda, _) = registration_pda(&user.pubkey());
+
+    let proof = tree.proof(&invitee.pubkey().to_bytes()).unwrap();
+    let instr = build_register_with_proof_instr(&user.pubkey(), &state_pda, &reg_pda, &proof);
+    let result = send_instr(&mut svm, instr, &user);
+    assert!(result.is_err(), "register with invalid proof should fail");
+    assert_err_logs_contain(&result, "NotOnAllowlist");
+    assert!(fetch_registration(&svm, &reg_pda).is_none());
+}
+
+#[test]
+fn fails_to_register_without_a_proof_when_allowlist_is_set() {
+    let authority = Keypair::new();
+    let mut svm = setup(&authority.pubkey());
+    let invitee = Keypair::new();
+    setup_allowlist(&mut svm, &authority, &[&invitee]);
+    let (state_pda, _) = registry_state_pda();
+    let (reg_pda, _) = registration_pda(&invitee.pubkey());
+
+    let instr = build_register_instr(&invitee.pubkey(), &state_pda, &reg_pda);
+    let result = send_instr(&mut svm, instr, &invitee);
+    assert!(result.is_err(), "register without proof should fail");
+    assert_err_logs_contain(&result, "NotOnAllowlist");
+}
+
+#[test]
+fn fails_to_register_with_a_proof_for_a_stale_allowlist_root() {
+    let authority = Keypair::new();
+    let mut svm = setup(&authority.pubkey());
+    let invitee = Keypair::new();
+    let stale_tree = setup_allowlist(&mut svm, &authority, &[&invitee]);
+    let (state_pda, _) = registry_state_pda();
+
+    // The authority rotates the allowlist, and the invitee is no longer on it
+    let new_tree = AllowlistTree::new(&[Pubkey::new_unique().to_bytes()]);
+    let instr =
+        build_set_allowlist_root_instr(&authority.pubkey(), &state_pda, Some(new_tree.root()));
+    send_instr(&mut svm, instr, &authority).expect("set allowlist root should succeed");
+
+    let (reg_pda, _) = registration_pda(&invitee.pubkey());
+    let proof = stale_tree.proof(&invitee.pubkey().to_bytes()).unwrap();
+    let instr = build_register_with_proof_instr(&invitee.pubkey(), &state_pda, &reg_pda, &proof);
+    let result = send_instr(&mut svm, instr, &invitee);
+    assert!(result.is_err(), "register with stale proof should fail");
+    assert_err_logs_contain(&result, "NotOnAllowlist");
+}
+
+#[test]
+fn registers_without_a_proof_once_allowlist_is_removed() {
+    let authority = Keypair::new();
+    let mut svm = setup(&authority.pubkey());
+    let invitee = Keypair::new();
+    setup_allowlist(&mut svm, &authority, &[&invitee]);
+    let (state_pda, _) = registry_state_pda();
+
+    let instr = build_set_allowlist_root_instr(&authority.pubkey(), &state_pda, None);
+    send_instr(&mut svm, instr, &authority).expect("remove allowlist root should succeed");
+
+    let user = Keypair::new();
+    svm.airdrop(&user.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    let (reg_pda, _) = registration_pda(&user.pubkey());
+
+    let instr = build_register_instr(&user.pubkey(), &state_pda, &reg_pda);
+    send_instr(&mut svm, instr, &user).expect("register should succeed");
+}
+
+// Vouchers
+
+fn setup_voucher_registry(svm: &mut LiteSVM, authority: &Keypair) -> Pubkey {
+    svm.airdrop(&authority.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    let (state_pda, _) = registry_state_pda();
+
+    let instr = build_initialise_registry_instr(&authority.pubkey(), &state_pda, false);
+    send_instr(svm, instr, authority).expect("initialise should succeed");
+
+    state_pda
+}
+
+#[test]
+fn registers_a_user_with_a_voucher() {
+    let authority = Keypair::new();
+    let mut svm = setup(&authority.pubkey());
+    let state_pda = setup_voucher_registry(&mut svm, &authority);
+
+    let user = Keypair::new();
+    svm.airdrop(&user.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    let (reg_pda, _) = registration_pda(&user.pubkey());
+    let expiry_slot = svm.get_sysvar::<Clock>().slot + 100;
+
+    let instrs = [
+        build_voucher_instr(&authority, &user.pubkey(), &state_pda, expiry_slot, 1),
+        build_register_with_voucher_instr(
+            &user.pubkey(),
+            &user.pubkey(),
+            &state_pda,
+            &reg_pda,
+            expiry_slot,
+            1,
+        ),
+    ];
+    send_instrs(&mut svm, &instrs, &user).expect("register with voucher should succeed");
+
+    let registration = fetch_registration(&svm, &reg_pda).expect("registration should exist");
+    assert_eq!(registration.registrant, user.pubkey());
+    assert_eq!(registration.registration_index, 0);
+    assert!(svm.get_account(&voucher_nonce_pda(1).0).is_some());
+}
+
+#[test]
+fn registers_a_user_with_a_voucher_when_allowlist_is_set() {
+    let authority = Keypair::new();
+    let mut svm = setup(&authority.pubkey());
+    setup_allowlist(&mut svm, &authority, &[]);
+    let (state_pda, _) = registry_state_pda();
+
+    let user = Keypair::new();
+    svm.airdrop(&user.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    let (reg_pda, _) = registration_pda(&user.pubkey());
+    let expiry_slot = svm.get_sysvar::<Clock>().slot + 100;
+
+    let instrs = [
+        build_voucher_instr(&authority, &user.pubkey(), &state_pda, expiry_slot, 1),
+        build_register_with_voucher_instr(
+            &user.pubkey(),
+            &user.pubkey(),
+            &state_pda,
+            &reg_pda,
+            expiry_slot,
+            1,
+        ),
+    ];
+    send_instrs(&mut svm, &instrs, &user).expect("register with voucher should succeed");
+}
+
+#[test]
+fn fails_to_register_with_a_voucher_not_signed_by_authority() {
+    let authority = Keypair::new();
+    let mut svm = setup(&authority.pubkey());
+    let state_pda = setup_voucher_registry(&mut svm, &authority);
+
+    let user = Keypair::new();
+    svm.airdrop(&user.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    let (reg_pda, _) = registration_pda(&user.pubkey());
+    let expiry_slot = svm.get_sysvar::<Clock>().slot + 100;
+
+    let instrs = [
+        build_voucher_instr(&user, &user.pubkey(), &state_pda, expiry_slot, 1),
+        build_register_with_voucher_instr(
+            &user.pubkey(),
+            &user.pubkey(),
+            &state_pda,
+            &reg_pda,
+            expiry_slot,
+            1,
+        ),
+    ];
+    let result = send_instrs(&mut svm, &instrs, &user);
+    assert!(result.is_err(), "self-signed voucher should fail");
+    assert_err_logs_contain(&result, "InvalidVoucher");
+}
+
+#[test]
+fn fails_to_register_with_a_voucher_for_another_registrant() {
+    let authority = Keypair::new();
+    let mut svm = setup(&authority.pubkey());
+    let state_pda = setup_voucher_registry(&mut svm, &authority);
+
+    let user = Keypair::new();
+    svm.airdrop(&user.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    let (reg_pda, _) = registration_pda(&user.pubkey());
+    let expiry_slot = svm.get_sysvar::<Clock>().slot + 100;
+
+    let other_user = Pubkey::new_unique();
+    let instrs = [
+        build_voucher_instr(&authority, &other_user, &state_pda, expiry_slot, 1),
+        build_register_with_voucher_instr(
+            &user.pubkey(),
+            &user.pubkey(),
+            &state_pda,
+            &reg_pda,
+            expiry_slot,
+            1,
+        ),
+    ];
+    let result = send_instrs(&mut svm, &instrs, &user);
+    assert!(
+        result.is_err(),
+        "voucher for another registrant should fail"
+    );
+    assert_err_logs_contain(&result, "InvalidVoucher");
+}
+
+#[test]
+fn fails_to_register_with_a_voucher_without_an_ed25519_instruction() {
+    let authority = Keypair::new();
+    let mut svm = setup(&authority.pubkey());
+    let state_pda = setup_voucher_registry(&mut svm, &authority);
+
+    let user = Keypair::new();
+    svm.airdrop(&user.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    let (reg_pda, _) = registration_pda(&user.pubkey());
+    let expiry_slot = svm.get_sysvar::<Clock>().slot + 100;
+
+    let instr = build_register_with_voucher_instr(
+        &user.pubkey(),
+        &user.pubkey(),
+        &state_pda,
+        &reg_pda,
+        expiry_slot,
+        1,
+    );
+    let result = send_instr(&mut svm, instr, &user);
+    assert!(result.is_err(), "register without voucher should fail");
+    assert_err_logs_contain(&result, "InvalidVoucher");
+}
+
+#[test]
+fn fails_to_register_with_an_expired_voucher() {
+    let authority = Keypair::new();
+    let mut svm = setup(&authority.pubkey());
+    let state_pda = setup_voucher_registry(&mut svm, &authority);
+
+    let user = Keypair::new();
+    svm.airdrop(&user.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    let (reg_pda, _) = registration_pda(&user.pubkey());
+    let expiry_slot = svm.get_sysvar::<Clock>().slot + 10;
+
+    svm.warp_to_slot(expiry_slot + 1);
+
+    let instrs = [
+        build_voucher_instr(&authority, &user.pubkey(), &state_pda, expiry_slot, 1),
+        build_register_with_voucher_instr(
+            &user.pubkey(),
+            &user.pubkey(),
+            &state_pda,
+            &reg_pda,
+            expiry_slot,
+            1,
+        ),
+    ];
+    let result = send_instrs(&mut svm, &instrs, &user);
+    assert!(result.is_err(), "expired voucher should fail");
+    assert_err_logs_contain(&result, "VoucherExpired");
+}
+
+#[test]
+fn fails_to_register_with_a_reused_voucher_nonce() {
+    let authority = Keypair::new();
+    let mut svm = setup(&authority.pubkey());
+    let state_pda = setup_voucher_registry(&mut svm, &authority);
+    let expiry_slot = svm.get_sysvar::<Clock>().slot + 100;
+
+    let user = Keypair::new();
+    svm.airdrop(&user.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    let (reg_pda, _) = registration_pda(&user.pubkey());
+
+    let instrs = [
+        build_voucher_instr(&authority, &user.pubkey(), &state_pda, expiry_slot, 1),
+        build_register_with_voucher_instr(
+            &user.pubkey(),
+            &user.pubkey(),
+            &state_pda,
+            &reg_pda,
+            expiry_slot,
+            1,
+        ),
+    ];
+    send_instrs(&mut svm, &instrs, &user).expect("register with voucher should succeed");
+
+    let other_user = Keypair::new();
+    svm.airdrop(&other_user.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    let (other_reg_pda, _) = registration_pda(&other_user.pubkey());
+
+    let instrs = [
+        build_voucher_instr(&authority, &other_user.pubkey(), &state_pda, expiry_slot, 1),
+        build_register_with_voucher_instr(
+            &other_user.pubkey(),
+            &other_user.pubkey(),
+            &state_pda,
+            &other_reg_pda,
+            expiry_slot,
+            1,
+        ),
+    ];
+    let result = send_instrs(&mut svm, &instrs, &other_user);
+    assert!(result.is_err(), "reused nonce should fail");
+    assert_err_logs_contain(&result, "already in use");
+}
+
+// Sponsored registration
+
+#[test]
+fn sponsor_pays_for_a_registration() {
+    let authority = Keypair::new();
+    let mut svm = setup(&authority.pubkey());
+    svm.airdrop(&authority.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    let (state_pda, _) = registry_state_pda();
+
+    let instr = build_initialise_registry_instr(&authority.pubkey(), &state_pda, false);
+    send_instr(&mut svm, instr, &authority).expect("initialise should succeed");
+
+    let sponsor = Keypair::new();
+    svm.airdrop(&sponsor.pubkey(), LAMPORTS_PER_SOL).unwrap();
+
+    // The registrant holds no SOL at all
+    let user = Keypair::new();
+    let (reg_pda, _) = registration_pda(&user.pubkey());
+
+    let instr = build_sponsored_register_instr(
+        &sponsor.pubkey(),
+        &user.pubkey(),
+        &state_pda,
+        &reg_pda,
+        &[],
+    );
+    send_instrs_with_signers(&mut svm, &[instr], &sponsor, &[&user])
+        .expect("sponsored register should succeed");
+
+    let registration = fetch_registration(&svm, &reg_pda).expect("registration should exist");
+    assert_eq!(registration.registrant, user.pubkey());
+    assert_eq!(svm.get_balance(&user.pubkey()).unwrap_or(0), 0);
+    assert!(svm.get_balance(&sponsor.pubkey()).unwrap() < LAMPORTS_PER_SOL);
+}
+
+#[test]
+fn fails_to_sponsor_a_registration_without_the_registrant_signature() {
+    let authority = Keypair::new();
+    let mut svm = setup(&authority.pubkey());
+    svm.airdrop(&authority.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    let (state_pda, _) = registry_state_pda();
+
+    let instr = build_initialise_registry_instr(&authority.pubkey(), &state_pda, false);
+    send_instr(&mut svm, instr, &authority).expect("initialise should succeed");
+
+    let sponsor = Keypair::new();
+    svm.airdrop(&sponsor.pubkey(), LAMPORTS_PER_SOL).unwrap();
+
+    let user = Keypair::new();
+    let (reg_pda, _) = registration_pda(&user.pubkey());
+
+    let mut instr = build_sponsored_register_instr(
+        &sponsor.pubkey(),
+        &user.pubkey(),
+        &state_pda,
+        &reg_pda,
+        &[],
+    );
+    instr.accounts[1].is_signer = false;
+    let result = send_instr(&mut svm, instr, &sponsor);
+    assert!(
+        result.is_err(),
+        "register without registrant signature should fail"
+    );
+    assert_err_logs_contain(&result, "AccountNotSigner");
+}
+
+#[test]
+fn sponsor_pays_for_a_voucher_registration() {
+    let authority = Keypair::new();
+    let mut svm = setup(&authority.pubkey());
+    let state_pda = setup_voucher_registry(&mut svm, &authority);
+
+    let sponsor = Keypair::new();
+    svm.airdrop(&sponsor.pubkey(), LAMPORTS_PER_SOL).unwrap();
+
+    let user = Keypair::new();
+    let (reg_pda, _) = registration_pda(&user.pubkey());
+    let expiry_slot = svm.get_sysvar::<Clock>().slot + 100;
+
+    let instrs = [
+        build_voucher_instr(&authority, &user.pubkey(), &state_pda, expiry_slot, 1),
+        build_register_with_voucher_instr(
+            &sponsor.pubkey(),
+            &user.pubkey(),
+            &state_pda,
+            &reg_pda,
+            expiry_slot,
+            1,
+        ),
+    ];
+    send_instrs_with_signers(&mut svm, &instrs, &sponsor, &[&user])
+        .expect("sponsored register with voucher should succeed");
+
+    let registration = fetch_registration(&svm, &reg_pda).expect("registration should exist");
+    assert_eq!(registration.registrant, user.pubkey());
+    assert_eq!(svm.get_balance(&user.pubkey()).unwrap_or(0), 0);
+}
+
+// Layout migration
+
+// Writes a program owned account holding `fields` behind `T`'s discriminator, padded to `space`
+fn set_legacy_account<T: Discriminator>(
+    svm: &mut LiteSVM,
+    pda: &Pubkey,
+    fields: &[u8],
+    space: usize,
+) {
+    let mut data = T::DISCRIMINATOR.to_vec();
+    data.extend_from_slice(fields);
+    data.resize(space, 0);
+    svm.set_account(
+        *pda,
+        Account {
+            lamports: svm.minimum_balance_for_rent_exemption(space),
+            data,
+            owner: register::ID,
+            executable: false,
+            rent_epoch: 0,
+        },
+    )
+    .unwrap();
+}
+
+// The `RegistryState` layout before versioning: `authority`, `registration_count`
+fn set_legacy_registry_state(svm: &mut LiteSVM, authority: &Pubkey, registration_count: u64) {
+    let (pda, _) = registry_state_pda();
+    let fields = [authority.as_ref(), &registration_count.to_le_bytes()].concat();
+    set_legacy_account::<register::RegistryState>(svm, &pda, &fields, 8 + 32 + 8);
+}
+
+// The `Registration` layout before versioning:
+// `registrant`, `registration_index`, `registered_at`, `confirmed_at`
+fn set_legacy_registration(
+    svm: &mut LiteSVM,
+    registrant: &Pubkey,
+    registration_index: u64,
+    registered_at: u64,
+    confirmed_at: Option<u64>,
+) {
+    let (pda, _) = registration_pda(registrant);
+    let confirmed_at = match confirmed_at {
+        Some(slot) => [&[1], slot.to_le_bytes().as_slice()].concat(),
+        None => vec![0],
+    };
+    let fields = [
+        registrant.as_ref(),
+        &registration_index.to_le_bytes(),
+        &registered_at.to_le_bytes(),
+        &confirmed_at,
+    ]
+    .concat();
+    set_legacy_account::<register::Registration>(svm, &pda, &fields, 8 + 32 + 8 + 8 + 9);
+}
+
+#[test]
+fn migrates_a_legacy_registry_state() {
+    let authority = Keypair::new();
+    let mut svm = setup(&authority.pubkey());
+    svm.airdrop(&authority.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    set_legacy_registry_state(&mut svm, &authority.pubkey(), 3);
+    let (state_pda, _) = registry_state_pda();
+
+    let instr = build_migrate_registry_state_instr(&authority.pubkey(), &state_pda, 1);
+    send_instr(&mut svm, instr, &authority).expect("migrate should succeed");
+
+    let state = fetch_registry_state(&svm, &state_pda).expect("registry state should exist");
+    assert_eq!(state.authority, authority.pubkey());
+    assert_eq!(state.registration_count, 3);
+    assert!(!state.fifo_only);
+    assert!(state.approvers.is_empty());
+    assert_eq!(state.approval_threshold, 0);
+    assert_eq!(state.allowlist_root, None);
+    assert_eq!(state.version, 3);
+    assert_eq!(state.confirmed_count, 1);
+    assert_eq!(state.pending_count, 2);
+
+    let account = svm.get_account(&state_pda).unwrap();
+    assert_eq!(account.data.len(), 8 + register::RegistryState::INIT_SPACE);
+    assert_eq!(
+        account.lamports,
+        svm.minimum_balance_for_rent_exemption(account.data.len())
+    );
+}
+
+#[test]
+fn migrates_a_registry_state_whose_approvers_shrank() {
+    let authority = Keypair::new();
+    let mut svm = setup(&authority.pubkey());
+    svm.airdrop(&authority.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    let (state_pda, _) = registry_state_pda();
+
+    // A version 2 registry that had two approvers and then dropped one. Shrinking `approvers`
+    // leaves the end of the old data behind, where `approver_epoch` is read from once grown.
+    let approver = Keypair::new();
+    let removed_approver = Keypair::new();
+    let fields = [
+        authority.pubkey().as_ref(),
+        &5u64.to_le_bytes(),
+        &[0],
+        &0u64.to_le_bytes(),
+        &1u32.to_le_bytes(),
+        approver.pubkey().as_ref(),
+        &[1],
+        &[0],
+        &[2],
+        &3u64.to_le_bytes(),
+        &2u64.to_le_bytes(),
+    ]
+    .concat();
+    let stale = [fields.as_slice(), removed_approver.pubkey().as_ref()].concat();
+    let v2_space = 8 + register::RegistryState::INIT_SPACE - 8;
+    set_legacy_account::<register::RegistryState>(&mut svm, &state_pda, &stale, v2_space);
+
+    let instr = build_migrate_registry_state_instr(&authority.pubkey(), &state_pda, 0);
+    send_instr(&mut svm, instr, &authority).expect("migrate should succeed");
+
+    let state = fetch_registry_state(&svm, &state_pda).expect("registry state should exist");
+    assert_eq!(state.approvers, vec![approver.pubkey()]);
+    assert_eq!(state.approval_threshold, 1);
+    assert_eq!(state.version, 3);
+    assert_eq!(state.confirmed_count, 3);
+    assert_eq!(state.pending_count, 2);
+    assert_eq!(state.approver_epoch, 0);
+
+    let account = svm.get_account(&state_pda).unwrap();
+    let serialised_len = 8 + fields.len() + 8;
+    assert!(
+        account.data[serialised_len..].iter().all(|&byte| byte == 0),
+        "bytes past the migrated registry state should be zeroed"
+    );
+}
+
+#[test]
+fn legacy_registry_state_is_usable_once_migrated() {
+    let authority = Keypair::new();
+    let mut svm = setup(&authority.pubkey());
+    svm.airdrop(&authority.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    set_legacy_registry_state(&mut svm, &authority.pubkey(), 3);
+    let (state_pda, _) = registry_state_pda();
+
+    let user = Keypair::new();
+    svm.airdrop(&user.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    let (reg_pda, _) = registration_pda(&user.pubkey());
+
+    let instr = build_register_instr(&user.pubkey(), &state_pda, &reg_pda);
+    let result = send_instr(&mut svm, instr, &user);
+    assert!(
+        result.is_err(),
+        "register against a legacy registry should fail"
+    );
+    assert_err_logs_contain(&result, "AccountDidNotDeserialize");
+
+    let instr = build_migrate_registry_state_instr(&authority.pubkey(), &state_pda, 0);
+    send_instr(&mut svm, instr, &authority).expect("migrate should succeed");
+
+    // LiteSVM rejects identical txns as `AlreadyProcessed`.
+    // Expiring the blockhash forces a new one, making the txns distinct.
+    svm.expire_blockhash();
+
+    let instr = build_register_instr(&user.pubkey(), &state_pda, &reg_pda);
+    send_instr(&mut svm, instr, &user).expect("register should succeed");
+
+    let registration = fetch_registration(&svm, &reg_pda).expect("registration should exist");
+    assert_eq!(registration.registration_index, 3);
+    assert_eq!(registration.version, 2);
+}
+
+#[test]
+fn fails_to_migrate_registry_state_if_signer_is_not_authority() {
+    let authority = Keypair::new();
+    let mut svm = setup(&authority.pubkey());
+    set_legacy_registry_state(&mut svm, &authority.pubkey(), 0);
+    let (state_pda, _) = registry_state_pda();
+
+    let non_authority = Keypair::new();
+    svm.airdrop(&non_authority.pubkey(), LAMPORTS_PER_SOL)
+        .unwrap();
+
+    let instr = build_migrate_registry_state_instr(&non_authority.pubkey(), &state_pda, 0);
+    let result = send_instr(&mut svm, instr, &non_authority);
+    assert!(result.is_err(), "migrate by non-authority should fail");
+    assert_err_logs_contain(&result, "Unauthorised");
+}
+
+#[test]
+fn fails_to_migrate_a_current_registry_state() {
+    let authority = Keypair::new();
+    let mut svm = setup(&authority.pubkey());
+    svm.airdrop(&authority.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    let (state_pda, _) = registry_state_pda();
+
+    let instr = build_initialise_registry_instr(&authority.pubkey(), &state_pda, false);
+    send_instr(&mut svm, instr, &authority).expect("initialise should succeed");
+
+    let instr = build_migrate_registry_state_instr(&authority.pubkey(), &state_pda, 0);
+    let result = send_instr(&mut svm, instr, &authority);
+    assert!(result.is_err(), "migrating a current registry should fail");
+    assert_err_logs_contain(&result, "AccountAlreadyMigrated");
+}
+
+#[test]
+fn anyone_can_migrate_legacy_registrations() {
+    let authority = Keypair::new();
+    let mut svm = setup(&authority.pubkey());
+    let (state_pda, _) = registry_state_pda();
+
+    let confirmed_user = Keypair::new();
+    let pending_user = Keypair::new();
+    set_legacy_registry_state(&mut svm, &authority.pubkey(), 2);
+    set_legacy_registration(&mut svm, &confirmed_user.pubkey(), 0, 10, Some(20));
+    set_legacy_registration(&mut svm, &pending_user.pubkey(), 1, 15, None);
+
+    let payer = Keypair::new();
+    svm.airdrop(&payer.pubkey(), LAMPORTS_PER_SOL).unwrap();
+
+    for user in [&confirmed_user, &pending_user] {
+        let (reg_pda, _) = registration_pda(&user.pubkey());
+        let instr = build_migrate_registration_instr(&payer.pubkey(), &user.pubkey(), &reg_pda);
+        send_instr(&mut svm, instr, &payer).expect("migrate should succeed");
+    }
+
+    let (confirmed_pda, _) = registration_pda(&confirmed_user.pubkey());
+    let registration = fetch_registration(&svm, &confirmed_pda).expect("registration should exist");
+    assert_eq!(registration.registrant, confirmed_user.pubkey());
+    assert_eq!(registration.registration_index, 0);
+    assert_eq!(registration.registered_at, 10);
+    assert_eq!(registration.confirmed_at, Some(20));
+    assert_eq!(registration.approvals, 0);
+    assert_eq!(registration.version, 2);
+
+    let (pending_pda, _) = registration_pda(&pending_user.pubkey());
+    let registration = fetch_registration(&svm, &pending_pda).expect("registration should exist");
+    assert_eq!(registration.registration_index, 1);
+    assert_eq!(registration.confirmed_at, None);
+    assert_eq!(registration.version, 2);
+
+    // Once both accounts are migrated the pending registration can be confirmed
+    svm.airdrop(&authority.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    let instr = build_migrate_registry_state_instr(&authority.pubkey(), &state_pda, 1);
+    send_instr(&mut svm, instr, &authority).expect("migrate should succeed");
+
+    let instr = build_confirm_registration_instr(&authority.pubkey(), &state_pda, &pending_pda);
+    send_instr(&mut svm, instr, &authority).expect("confirm should succeed");
+
+    let registration = fetch_registration(&svm, &pending_pda).expect("registration should exist");
+    assert!(registration.confirmed_at.is_some());
+
+    let state = fetch_registry_state(&svm, &state_pda).expect("registry state should exist");
+    assert_eq!(state.confirmed_count, 2);
+    assert_eq!(state.pending_count, 0);
+}
+
+#[test]
+fn fails_to_migrate_registry_state_with_more_confirmations_than_registrations() {
+    let authority = Keypair::new();
+    let mut svm = setup(&authority.pubkey());
+    svm.airdrop(&authority.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    set_legacy_registry_state(&mut svm, &authority.pubkey(), 2);
+    let (state_pda, _) = registry_state_pda();
+
+    let instr = build_migrate_registry_state_instr(&authority.pubkey(), &state_pda, 3);
+    let result = send_instr(&mut svm, instr, &authority);
+    assert!(
+        result.is_err(),
+        "migrate with too many confirmations should fail"
+    );
+    assert_err_logs_contain(&result, "InvalidConfirmedCount");
+}
+
+// Migrated with one confirmation too many, the registry counts its pending registration as confirmed
+#[test]
+fn fails_to_confirm_once_the_registry_counts_no_pending_registrations() {
+    let authority = Keypair::new();
+    let mut svm = setup(&authority.pubkey());
+    svm.airdrop(&authority.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    let (state_pda, _) = registry_state_pda();
+
+    let user = Keypair::new();
+    set_legacy_registry_state(&mut svm, &authority.pubkey(), 1);
+    set_legacy_registration(&mut svm, &user.pubkey(), 0, 10, None);
+    let (reg_pda, _) = registration_pda(&user.pubkey());
+
+    let instr = build_migrate_registration_instr(&authority.pubkey(), &user.pubkey(), &reg_pda);
+    send_instr(&mut svm, instr, &authority).expect("migrate should succeed");
+    let instr = build_migrate_registry_state_instr(&authority.pubkey(), &state_pda, 1);
+    send_instr(&mut svm, instr, &authority).expect("migrate should succeed");
+
+    let instr = build_confirm_registration_instr(&authority.pubkey(), &state_pda, &reg_pda);
+    let result = send_instr(&mut svm, instr, &authority);
+    assert!(
+        result.is_err(),
+        "confirm with no pending registrations should fail"
+    );
+    assert_err_logs_contain(&result, "NoPendingRegistrations");
+
+    let registration = fetch_registration(&svm, &reg_pda).expect("registration should exist");
+    assert_eq!(registration.confirmed_at, None);
+}
+
+#[test]
+fn fails_to_migrate_a_current_registration() {
+    let authority = Keypair::new();
+    let mut svm = setup(&authority.pubkey());
+    svm.airdrop(&authority.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    let (state_pda, _) = registry_state_pda();
+
+    let instr = build_initialise_registry_instr(&authority.pubkey(), &state_pda, false);
+    send_instr(&mut svm, instr, &authority).expect("initialise should succeed");
+
+    let user = Keypair::new();
+    svm.airdrop(&user.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    let (reg_pda, _) = registration_pda(&user.pubkey());
+
+    let instr = build_register_instr(&user.pubkey(), &state_pda, &reg_pda);
+    send_instr(&mut svm, instr, &user).expect("register should succeed");
+
+    let instr = build_migrate_registration_instr(&user.pubkey(), &user.pubkey(), &reg_pda);
+    let result = send_instr(&mut svm, instr, &user);
+    assert!(
+        result.is_err(),
+        "migrating a current registration should fail"
+    );
+    assert_err_logs_contain(&result, "AccountAlreadyMigrated");
+}
+
+// Registry counts
+
+// xorshift64, enough to vary the operation sequences without pulling in a rand crate
+struct Rng(u64);
+
+impl Rng {
+    fn below(&mut self, n: usize) -> usize {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        (self.0 % n as u64) as usize
+    }
+}
+
+fn assert_counts_match_registrations(svm: &LiteSVM, state_pda: &Pubkey, registrants: &[Keypair]) {
+    let confirmed = registrants
+        .iter()
+        .filter(|registrant| {
+            let (reg_pda, _) = registration_pda(&registrant.pubkey());
+            let registration =
+                fetch_registration(svm, &reg_pda).expect("registration should exist");
+            registration.confirmed_at.is_some()
+        })
+        .count() as u64;
+
+    let state = fetch_registry_state(svm, state_pda).expect("registry state should exist");
+    assert_eq!(state.registration_count, registrants.len() as u64);
+    assert_eq!(state.confirmed_count, confirmed);
+    assert_eq!(state.pending_count, registrants.len() as u64 - confirmed);
+}
+
+#[test]
+fn registry_counts_match_registrations_over_random_operations() {
+    for seed in 1..=12u64 {
+        let authority = Keypair::new();
+        let mut svm = setup(&authority.pubkey());
+        svm.airdrop(&authority.pubkey(), LAMPORTS_PER_SOL).unwrap();
+        let (state_pda, _) = registry_state_pda();
+
+        // Cycle through the confirmation modes: open, FIFO-only and 2-of-3 approval
+        let fifo_only = seed % 3 == 1;
+        let approvers: Vec<Keypair> = if seed % 3 == 2 {
+            (0..3).map(|_| Keypair::new()).collect()
+        } else {
+            Vec::new()
+        };
+
+        let instr = build_initialise_registry_instr(&authority.pubkey(), &state_pda, fifo_only);
+        send_instr(&mut svm, instr, &authority).expect("initialise should succeed");
+
+        if !approvers.is_empty() {
+            let approver_keys: Vec<Pubkey> = approvers.iter().map(|a| a.pubkey()).collect();
+            let instr =
+                build_configure_approvers_instr(&authority.pubkey(), &state_pda, &approver_keys, 2);
+            send_instr(&mut svm, instr, &authority).expect("configure should succeed");
+            for approver in &approvers {
+                svm.airdrop(&approver.pubkey(), LAMPORTS_PER_SOL).unwrap();
+            }
+        }
+
+        let mut rng = Rng(seed);
+        let mut registrants: Vec<Keypair> = Vec::new();
+
+        for _ in 0..30 {
+            // Register a new user, or try to confirm / approve an existing registration.
+            // Attempts are allowed to fail (already confirmed, out of order, wrong mode),
+            // the counts must hold either way.
+            match rng.below(3) {
+                0 => {
+                    let user = Keypair::new();
+                    svm.airdrop(&user.pubkey(), LAMPORTS_PER_SOL).unwrap();
+                    let (reg_pda, _) = registration_pda(&user.pubkey());
+                    let instr = build_register_instr(&user.pubkey(), &state_pda, &reg_pda);
+                    send_instr(&mut svm, instr, &user).expect("register should succeed");
+                    registrants.push(user);
+                }
+                _ if registrants.is_empty() => continue,
+                1 => {
+                    let user = &registrants[rng.below(registrants.len())];
+                    let (reg_pda, _) = registration_pda(&user.pubkey());
+                    let instr =
+                        build_confirm_registration_instr(&authority.pubkey(), &state_pda, &reg_pda);
+                    let _ = send_instr(&mut svm, instr, &authority);
+                }
+                _ => {
+                    if approvers.is_empty() {
+                        continue;
+                    }
+                    let user = &registrants[rng.below(registrants.len())];
+                    let approver = &approvers[rng.below(approvers.len())];
+                    let (reg_pda, _) = registration_pda(&user.pubkey());
+                    let instr =
+                        build_approve_registration_instr(&approver.pubkey(), &state_pda, &reg_pda);
+                    let _ = send_instr(&mut svm, instr, approver);
+                }
+            }
+
+            // LiteSVM rejects identical txns as `AlreadyProcessed`.
+            // Expiring the blockhash forces a new one, making the txns distinct.
+            svm.expire_blockhash();
+
+            assert_counts_match_registrations(&svm, &state_pda, &registrants);
+        }
+    }
+}
+
+// Events
+
+#[test]
+fn initialise_registry_emits_an_event() {
+    let authority = Keypair::new();
+    let mut svm = setup(&authority.pubkey());
+    svm.airdrop(&authority.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    let (state_pda, _) = registry_state_pda();
+
+    let instr = build_initialise_registry_instr(&authority.pubkey(), &state_pda, true);
+    let meta = send_instr(&mut svm, instr, &authority).expect("initialise should succeed");
+
+    let events = cpi_events::<register::RegistryInitialisedEvent>(&meta);
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].registry, state_pda);
+    assert_eq!(events[0].authority, authority.pubkey());
+    assert!(events[0].fifo_only);
+    assert_eq!(events[0].initialised_at, svm.get_sysvar::<Clock>().slot);
+}
+
+#[test]
+fn register_and_confirm_emit_events() {
+    let authority = Keypair::new();
+    let mut svm = setup(&authority.pubkey());
+    svm.airdrop(&authority.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    let (state_pda, _) = registry_state_pda();
+
+    let instr = build_initialise_registry_instr(&authority.pubkey(), &state_pda, false);
+    send_instr(&mut svm, instr, &authority).expect("initialise should succeed");
+
+    let user = Keypair::new();
+    svm.airdrop(&user.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    let (reg_pda, _) = registration_pda(&user.pubkey());
+
+    let instr = build_register_instr(&user.pubkey(), &state_pda, &reg_pda);
+    let meta = send_instr(&mut svm, instr, &user).expect("register should succeed");
+
+    let events = cpi_events::<register::RegisteredEvent>(&meta);
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].registry, state_pda);
+    assert_eq!(events[0].authority, authority.pubkey());
+    assert_eq!(events[0].registrant, user.pubkey());
+    assert_eq!(events[0].registration_index, 0);
+
+    let instr = build_confirm_registration_instr(&authority.pubkey(), &state_pda, &reg_pda);
+    let meta = send_instr(&mut svm, instr, &authority).expect("confirm should succeed");
+
+    let events = cpi_events::<register::ConfirmedEvent>(&meta);
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].registry, state_pda);
+    assert_eq!(events[0].authority, authority.pubkey());
+    assert_eq!(events[0].confirmed_by, authority.pubkey());
+    assert_eq!(events[0].registrant, user.pubkey());
+}
+
+#[test]
+fn approvals_emit_events_and_the_final_approver_confirms() {
+    let authority = Keypair::new();
+    let approvers = [Keypair::new(), Keypair::new(), Keypair::new()];
+    let mut svm = setup(&authority.pubkey());
+    let (state_pda, reg_pda) = setup_multisig_registration(&mut svm, &authority, &approvers);
+
+    let instr = build_approve_registration_instr(&approvers[0].pubkey(), &state_pda, &reg_pda);
+    let meta = send_instr(&mut svm, instr, &approvers[0]).expect("first approval should succeed");
+
+    let approved = cpi_events::<register::RegistrationApprovedEvent>(&meta);
+    assert_eq!(approved.len(), 1);
+    assert_eq!(approved[0].registry, state_pda);
+    assert_eq!(approved[0].authority, authority.pubkey());
+    assert_eq!(approved[0].approver, approvers[0].pubkey());
+    assert!(cpi_events::<register::ConfirmedEvent>(&meta).is_empty());
+
+    let instr = build_approve_registration_instr(&approvers[2].pubkey(), &state_pda, &reg_pda);
+    let meta = send_instr(&mut svm, instr, &approvers[2]).expect("second approval should succeed");
+
+    let confirmed = cpi_events::<register::ConfirmedEvent>(&meta);
+    assert_eq!(confirmed.len(), 1);
+    assert_eq!(confirmed[0].authority, authority.pubkey());
+    assert_eq!(confirmed[0].confirmed_by, approvers[2].pubkey());
+}
+
+#[test]
+fn registry_configuration_emits_events() {
+    let authority = Keypair::new();
+    let mut svm = setup(&authority.pubkey());
+    svm.airdrop(&authority.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    let (state_pda, _) = registry_state_pda();
+
+    let instr = build_initialise_registry_instr(&authority.pubkey(), &state_pda, false);
+    send_instr(&mut svm, instr, &authority).expect("initialise should succeed");
+
+    let approvers = [Pubkey::new_unique(), Pubkey::new_unique()];
+    let instr = build_configure_approvers_instr(&authority.pubkey(), &state_pda, &approvers, 1);
+    let meta = send_instr(&mut svm, instr, &authority).expect("configure should succeed");
+
+    let events = cpi_events::<register::ApproversConfiguredEvent>(&meta);
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].registry, state_pda);
+    assert_eq!(events[0].authority, authority.pubkey());
+    assert_eq!(events[0].approvers, approvers.to_vec());
+    assert_eq!(events[0].approval_threshold, 1);
+
+    let root = [7u8; 32];
+    let instr = build_set_allowlist_root_instr(&authority.pubkey(), &state_pda, Some(root));
+    let meta = send_instr(&mut svm, instr, &authority).expect("set allowlist root should succeed");
+
+    let events = cpi_events::<register::AllowlistRootSetEvent>(&meta);
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].registry, state_pda);
+    assert_eq!(events[0].authority, authority.pubkey());
+    assert_eq!(events[0].allowlist_root, Some(root));
+}
+
+#[test]
+fn fails_to_register_with_the_wrong_event_authority() {
+    let authority = Keypair::new();
+    let mut svm = setup(&authority.pubkey());
+    svm.airdrop(&authority.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    let (state_pda, _) = registry_state_pda();
+
+    let instr = build_initialise_registry_instr(&authority.pubkey(), &state_pda, false);
+    send_instr(&mut svm, instr, &authority).expect("initialise should succeed");
+
+    let user = Keypair::new();
+    svm.airdrop(&user.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    let (reg_pda, _) = registration_pda(&user.pubkey());
+
+    let mut instr = build_register_instr(&user.pubkey(), &state_pda, &reg_pda);
+    let event_authority = instr.accounts.len() - 2;
+    instr.accounts[event_authority].pubkey = Pubkey::new_unique();
+    let result = send_instr(&mut svm, instr, &user);
+    assert!(
+        result.is_err(),
+        "register with a spoofed event authority should fail"
+    );
+    assert_err_logs_contain(&result, "ConstraintSeeds");
+}
@@ -1,30 +1,116 @@
-use anchor_lang::AccountDeserialize;
+use anchor_lang::{AccountDeserialize, AnchorSerialize, Discriminator, Space};
 use litesvm::LiteSVM;
-use program_tests::{anchor_discriminator, anchor_instr_data, assert_err_logs_contain, send_instr};
+use program_tests::{
+    anchor_discriminator, anchor_instr_data, assert_err_logs_contain, log_events, send_instr,
+};
+use round::{RoundConfig, RoundStatus, ScheduleMode};
 use solana_sdk::{
+    account::Account,
     clock::Clock,
+    hash::Hash,
     instruction::{AccountMeta, Instruction},
     native_token::LAMPORTS_PER_SOL,
     pubkey::Pubkey,
     signature::Keypair,
     signer::Signer,
+    slot_hashes::SlotHashes,
     system_program::ID as SYSTEM_PROGRAM_ID,
+    sysvar::slot_hashes::ID as SLOT_HASHES_ID,
 };
 
-fn round_pda(authority: &Pubkey) -> (Pubkey, u8) {
+// Default length of test rounds, from start to end slot
+const ROUND_LENGTH: u64 = 100;
+
+fn round_pda(authority: &Pubkey, round_id: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[b"round", authority.as_ref(), &round_id.to_le_bytes()],
+        &round::ID,
+    )
+}
+
+// Where rounds lived before round ids, see `migrate_round`
+fn legacy_round_pda(authority: &Pubkey) -> (Pubkey, u8) {
     Pubkey::find_program_address(&[b"round", authority.as_ref()], &round::ID)
 }
 
+fn round_authority_pda(authority: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"round_authority", authority.as_ref()], &round::ID)
+}
+
+fn participant_pda(round_pda: &Pubkey, user: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[b"participant", round_pda.as_ref(), user.as_ref()],
+        &round::ID,
+    )
+}
+
+fn pot_pda(round_pda: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"pot", round_pda.as_ref()], &round::ID)
+}
+
+fn leaderboard_pda(round_pda: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"leaderboard", round_pda.as_ref()], &round::ID)
+}
+
+fn series_pda(authority: &Pubkey, series_id: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[b"series", authority.as_ref(), &series_id.to_le_bytes()],
+        &round::ID,
+    )
+}
+
+fn interest_pda(round_pda: &Pubkey, user: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[b"interest", round_pda.as_ref(), user.as_ref()],
+        &round::ID,
+    )
+}
+
+fn submission_pda(round_pda: &Pubkey, user: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[b"submission", round_pda.as_ref(), user.as_ref()],
+        &round::ID,
+    )
+}
+
 fn build_initialise_round_instr(
     authority: &Pubkey,
     round_pda: &Pubkey,
+    round_id: u64,
+    start_slot: u64,
+    end_slot: u64,
+) -> Instruction {
+    build_initialise_configured_round_instr(
+        authority,
+        round_pda,
+        round_id,
+        start_slot,
+        end_slot,
+        &RoundConfig::default(),
+    )
+}
+
+fn build_initialise_configured_round_instr(
+    authority: &Pubkey,
+    round_pda: &Pubkey,
+    round_id: u64,
     start_slot: u64,
+    end_slot: u64,
+    config: &RoundConfig,
 ) -> Instruction {
+    let (round_authority_pda, _) = round_authority_pda(authority);
+    let (pot_pda, _) = pot_pda(round_pda);
+    let mut args = round_id.to_le_bytes().to_vec();
+    args.extend_from_slice(&start_slot.to_le_bytes());
+    args.extend_from_slice(&end_slot.to_le_bytes());
+    config.serialize(&mut args).unwrap();
     Instruction::new_with_bytes(
         round::ID,
-        &anchor_instr_data("initialise_round", &start_slot.to_le_bytes()),
+        &anchor_instr_data("initialise_round", &args),
         vec![
+            AccountMeta::new(round_authority_pda, false),
             AccountMeta::new(*round_pda, false),
+            AccountMeta::new(pot_pda, false),
             AccountMeta::new(*authority, true),
             AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
         ],
@@ -37,15 +123,133 @@ fn build_activate_round_instr(user: &Pubkey, round_pda: &Pubkey) -> Instruction
         &anchor_discriminator("activate_round"),
         vec![
             AccountMeta::new(*round_pda, false),
-            AccountMeta::new_readonly(*user, true),
+            AccountMeta::new(*user, true),
+            // No registration, test rounds aren't gated
+            AccountMeta::new_readonly(round::ID, false),
         ],
     )
 }
 
-fn build_complete_round_instr(authority: &Pubkey, round_pda: &Pubkey) -> Instruction {
+fn build_complete_round_instr(user: &Pubkey, round_pda: &Pubkey) -> Instruction {
     Instruction::new_with_bytes(
         round::ID,
         &anchor_discriminator("complete_round"),
+        vec![
+            AccountMeta::new(*round_pda, false),
+            AccountMeta::new_readonly(*user, true),
+            AccountMeta::new(leaderboard_pda(round_pda).0, false),
+        ],
+    )
+}
+
+fn build_settle_round_instr(
+    authority: &Pubkey,
+    round_pda: &Pubkey,
+    winner: &Pubkey,
+) -> Instruction {
+    Instruction::new_with_bytes(
+        round::ID,
+        &anchor_discriminator("settle_round"),
+        vec![
+            AccountMeta::new(*round_pda, false),
+            AccountMeta::new(*authority, true),
+            AccountMeta::new(pot_pda(round_pda).0, false),
+            AccountMeta::new(*winner, false),
+            AccountMeta::new_readonly(participant_pda(round_pda, winner).0, false),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+        ],
+    )
+}
+
+fn build_cancel_round_instr(authority: &Pubkey, round_pda: &Pubkey) -> Instruction {
+    Instruction::new_with_bytes(
+        round::ID,
+        &anchor_discriminator("cancel_round"),
+        vec![
+            AccountMeta::new(*round_pda, false),
+            AccountMeta::new(*authority, true),
+        ],
+    )
+}
+
+fn build_migrate_round_instr(
+    authority: &Pubkey,
+    legacy_pda: &Pubkey,
+    round_id: u64,
+    end_slot: u64,
+) -> Instruction {
+    let (round_authority_pda, _) = round_authority_pda(authority);
+    let (round_pda, _) = round_pda(authority, round_id);
+    let args = [round_id.to_le_bytes(), end_slot.to_le_bytes()].concat();
+    Instruction::new_with_bytes(
+        round::ID,
+        &anchor_instr_data("migrate_round", &args),
+        vec![
+            AccountMeta::new(*legacy_pda, false),
+            AccountMeta::new(round_authority_pda, false),
+            AccountMeta::new(round_pda, false),
+            AccountMeta::new(*authority, true),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+        ],
+    )
+}
+
+fn build_join_round_instr(user: &Pubkey, round_pda: &Pubkey) -> Instruction {
+    let (participant_pda, _) = participant_pda(round_pda, user);
+    Instruction::new_with_bytes(
+        round::ID,
+        &anchor_discriminator("join_round"),
+        vec![
+            AccountMeta::new(*round_pda, false),
+            AccountMeta::new(participant_pda, false),
+            AccountMeta::new(*user, true),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+            AccountMeta::new_readonly(round::ID, false),
+        ],
+    )
+}
+
+fn build_enter_round_instr(user: &Pubkey, round_pda: &Pubkey) -> Instruction {
+    let (participant_pda, _) = participant_pda(round_pda, user);
+    let (pot_pda, _) = pot_pda(round_pda);
+    Instruction::new_with_bytes(
+        round::ID,
+        &anchor_discriminator("enter_round"),
+        vec![
+            AccountMeta::new(*round_pda, false),
+            AccountMeta::new(participant_pda, false),
+            AccountMeta::new(pot_pda, false),
+            AccountMeta::new(*user, true),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+            AccountMeta::new_readonly(round::ID, false),
+        ],
+    )
+}
+
+fn build_refund_entry_instr(user: &Pubkey, round_pda: &Pubkey) -> Instruction {
+    let (participant_pda, _) = participant_pda(round_pda, user);
+    let (pot_pda, _) = pot_pda(round_pda);
+    Instruction::new_with_bytes(
+        round::ID,
+        &anchor_discriminator("refund_entry"),
+        vec![
+            AccountMeta::new(*round_pda, false),
+            AccountMeta::new(participant_pda, false),
+            AccountMeta::new(pot_pda, false),
+            AccountMeta::new(*user, true),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+        ],
+    )
+}
+
+fn build_reschedule_round_instr(
+    authority: &Pubkey,
+    round_pda: &Pubkey,
+    new_start_slot: u64,
+) -> Instruction {
+    Instruction::new_with_bytes(
+        round::ID,
+        &anchor_instr_data("reschedule_round", &new_start_slot.to_le_bytes()),
         vec![
             AccountMeta::new(*round_pda, false),
             AccountMeta::new_readonly(*authority, true),
@@ -53,6 +257,190 @@ fn build_complete_round_instr(authority: &Pubkey, round_pda: &Pubkey) -> Instruc
     )
 }
 
+fn build_close_round_instr(authority: &Pubkey, round_pda: &Pubkey) -> Instruction {
+    let (pot_pda, _) = pot_pda(round_pda);
+    Instruction::new_with_bytes(
+        round::ID,
+        &anchor_discriminator("close_round"),
+        vec![
+            AccountMeta::new(*round_pda, false),
+            AccountMeta::new(pot_pda, false),
+            AccountMeta::new(*authority, true),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+            AccountMeta::new(leaderboard_pda(round_pda).0, false),
+        ],
+    )
+}
+
+// `close_participant`, `close_submission` and `close_interest` share their accounts
+fn build_close_user_account_instr(
+    instruction: &str,
+    user: &Pubkey,
+    round_pda: &Pubkey,
+    account_pda: &Pubkey,
+) -> Instruction {
+    Instruction::new_with_bytes(
+        round::ID,
+        &anchor_discriminator(instruction),
+        vec![
+            AccountMeta::new_readonly(*round_pda, false),
+            AccountMeta::new(*account_pda, false),
+            AccountMeta::new(*user, true),
+        ],
+    )
+}
+
+fn build_draw_winner_instr(user: &Pubkey, round_pda: &Pubkey, candidates: u32) -> Instruction {
+    Instruction::new_with_bytes(
+        round::ID,
+        &anchor_instr_data("draw_winner", &candidates.to_le_bytes()),
+        vec![
+            AccountMeta::new(*round_pda, false),
+            AccountMeta::new_readonly(*user, true),
+            AccountMeta::new_readonly(SLOT_HASHES_ID, false),
+        ],
+    )
+}
+
+// `round_id` is the authority's next round id, which the series' first round takes
+fn build_initialise_series_instr(
+    authority: &Pubkey,
+    series_id: u64,
+    round_id: u64,
+    start_slot: u64,
+    interval_slots: u64,
+    duration_slots: u64,
+) -> Instruction {
+    let (series_pda, _) = series_pda(authority, series_id);
+    let (round_authority_pda, _) = round_authority_pda(authority);
+    let (first_round_pda, _) = round_pda(authority, round_id);
+    let args = [
+        series_id.to_le_bytes(),
+        start_slot.to_le_bytes(),
+        interval_slots.to_le_bytes(),
+        duration_slots.to_le_bytes(),
+    ]
+    .concat();
+    Instruction::new_with_bytes(
+        round::ID,
+        &anchor_instr_data("initialise_series", &args),
+        vec![
+            AccountMeta::new(series_pda, false),
+            AccountMeta::new(round_authority_pda, false),
+            AccountMeta::new(first_round_pda, false),
+            AccountMeta::new(*authority, true),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+        ],
+    )
+}
+
+// `round_id` is the series' current round id, the next round takes `next_round_id`
+fn build_roll_series_instr(
+    user: &Pubkey,
+    authority: &Pubkey,
+    round_id: u64,
+    next_round_id: u64,
+) -> Instruction {
+    let (current_round_pda, _) = round_pda(authority, round_id);
+    Instruction::new_with_bytes(
+        round::ID,
+        &anchor_discriminator("roll_series"),
+        vec![
+            AccountMeta::new(series_pda(authority, 0).0, false),
+            AccountMeta::new(round_authority_pda(authority).0, false),
+            AccountMeta::new(current_round_pda, false),
+            AccountMeta::new(round_pda(authority, next_round_id).0, false),
+            AccountMeta::new(*user, true),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+            AccountMeta::new(leaderboard_pda(&current_round_pda).0, false),
+        ],
+    )
+}
+
+fn build_close_series_instr(authority: &Pubkey, series_id: u64) -> Instruction {
+    Instruction::new_with_bytes(
+        round::ID,
+        &anchor_discriminator("close_series"),
+        vec![
+            AccountMeta::new(series_pda(authority, series_id).0, false),
+            AccountMeta::new(*authority, true),
+        ],
+    )
+}
+
+fn build_signal_interest_instr(user: &Pubkey, round_pda: &Pubkey) -> Instruction {
+    let (interest_pda, _) = interest_pda(round_pda, user);
+    Instruction::new_with_bytes(
+        round::ID,
+        &anchor_discriminator("signal_interest"),
+        vec![
+            AccountMeta::new(*round_pda, false),
+            AccountMeta::new(interest_pda, false),
+            AccountMeta::new(*user, true),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+            AccountMeta::new_readonly(round::ID, false),
+        ],
+    )
+}
+
+fn build_commit_instr(user: &Pubkey, round_pda: &Pubkey, commitment: [u8; 32]) -> Instruction {
+    let (participant_pda, _) = participant_pda(round_pda, user);
+    let (submission_pda, _) = submission_pda(round_pda, user);
+    Instruction::new_with_bytes(
+        round::ID,
+        &anchor_instr_data("commit", &commitment),
+        vec![
+            AccountMeta::new_readonly(*round_pda, false),
+            AccountMeta::new_readonly(participant_pda, false),
+            AccountMeta::new(submission_pda, false),
+            AccountMeta::new(*user, true),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+        ],
+    )
+}
+
+fn build_reveal_instr(
+    user: &Pubkey,
+    round_pda: &Pubkey,
+    value: u64,
+    salt: [u8; 32],
+) -> Instruction {
+    let (submission_pda, _) = submission_pda(round_pda, user);
+    let mut args = value.to_le_bytes().to_vec();
+    args.extend_from_slice(&salt);
+    Instruction::new_with_bytes(
+        round::ID,
+        &anchor_instr_data("reveal", &args),
+        vec![
+            AccountMeta::new(*round_pda, false),
+            AccountMeta::new(submission_pda, false),
+            AccountMeta::new_readonly(*user, true),
+        ],
+    )
+}
+
+fn build_submit_score_instr(
+    scorer: &Pubkey,
+    round_pda: &Pubkey,
+    user: &Pubkey,
+    score: u64,
+) -> Instruction {
+    let (participant_pda, _) = participant_pda(round_pda, user);
+    let (leaderboard_pda, _) = leaderboard_pda(round_pda);
+    let args = [user.as_ref(), &score.to_le_bytes()].concat();
+    Instruction::new_with_bytes(
+        round::ID,
+        &anchor_instr_data("submit_score", &args),
+        vec![
+            AccountMeta::new_readonly(*round_pda, false),
+            AccountMeta::new_readonly(participant_pda, false),
+            AccountMeta::new(leaderboard_pda, false),
+            AccountMeta::new(*scorer, true),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+        ],
+    )
+}
+
 fn setup() -> LiteSVM {
     let mut svm = LiteSVM::new();
     svm.add_program_from_file(round::ID, "../target/deploy/round.so")
@@ -66,300 +454,3839 @@ fn fetch_round(svm: &LiteSVM, pda: &Pubkey) -> Option<round::Round> {
     round::Round::try_deserialize(&mut data).ok()
 }
 
-fn current_slot(svm: &LiteSVM) -> u64 {
-    svm.get_sysvar::<Clock>().slot
+fn fetch_round_authority(svm: &LiteSVM, pda: &Pubkey) -> Option<round::RoundAuthority> {
+    let account = svm.get_account(pda)?;
+    let mut data = account.data.as_slice();
+    round::RoundAuthority::try_deserialize(&mut data).ok()
+}
+
+fn fetch_participant(svm: &LiteSVM, pda: &Pubkey) -> Option<round::Participant> {
+    let account = svm.get_account(pda)?;
+    let mut data = account.data.as_slice();
+    round::Participant::try_deserialize(&mut data).ok()
+}
+
+fn fetch_submission(svm: &LiteSVM, pda: &Pubkey) -> Option<round::Submission> {
+    let account = svm.get_account(pda)?;
+    let mut data = account.data.as_slice();
+    round::Submission::try_deserialize(&mut data).ok()
+}
+
+fn fetch_series(svm: &LiteSVM, pda: &Pubkey) -> Option<round::RoundSeries> {
+    let account = svm.get_account(pda)?;
+    let mut data = account.data.as_slice();
+    round::RoundSeries::try_deserialize(&mut data).ok()
+}
+
+fn fetch_interest(svm: &LiteSVM, pda: &Pubkey) -> Option<round::Interest> {
+    let account = svm.get_account(pda)?;
+    let mut data = account.data.as_slice();
+    round::Interest::try_deserialize(&mut data).ok()
+}
+
+fn fetch_leaderboard(svm: &LiteSVM, pda: &Pubkey) -> Option<round::Leaderboard> {
+    let account = svm.get_account(pda)?;
+    let mut data = account.data.as_slice();
+    round::Leaderboard::try_deserialize(&mut data).ok()
+}
+
+fn current_slot(svm: &LiteSVM) -> u64 {
+    svm.get_sysvar::<Clock>().slot
+}
+
+// Round initialisation
+
+#[test]
+fn initialises_a_round() {
+    let mut svm = setup();
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    let (pda, _) = round_pda(&authority.pubkey(), 0);
+
+    let start_slot = current_slot(&svm) + 10;
+    let instr = build_initialise_round_instr(
+        &authority.pubkey(),
+        &pda,
+        0,
+        start_slot,
+        start_slot + ROUND_LENGTH,
+    );
+    send_instr(&mut svm, instr, &authority).expect("initialise should succeed");
+
+    let round = fetch_round(&svm, &pda).expect("round account should exist");
+    assert_eq!(round.authority, authority.pubkey());
+    assert_eq!(round.start_at, start_slot);
+    assert_eq!(round.round_id, 0);
+    assert_eq!(round.end_at, start_slot + ROUND_LENGTH);
+    assert_eq!(round.version, 1);
+    assert_eq!(round.status, RoundStatus::Scheduled);
+    assert_eq!(round.participant_count, 0);
+    assert_eq!(round.max_participants, None);
+    assert_eq!(round.schedule_mode, ScheduleMode::Slot);
+
+    let (round_authority_pda, _) = round_authority_pda(&authority.pubkey());
+    let round_authority =
+        fetch_round_authority(&svm, &round_authority_pda).expect("round authority should exist");
+    assert_eq!(round_authority.authority, authority.pubkey());
+    assert_eq!(round_authority.next_round_id, 1);
+}
+
+#[test]
+fn initialises_rounds_with_incrementing_ids() {
+    let mut svm = setup();
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), LAMPORTS_PER_SOL).unwrap();
+
+    let start_slot = current_slot(&svm) + 10;
+    for round_id in 0..3 {
+        let (pda, _) = round_pda(&authority.pubkey(), round_id);
+        let instr = build_initialise_round_instr(
+            &authority.pubkey(),
+            &pda,
+            round_id,
+            start_slot,
+            start_slot + ROUND_LENGTH,
+        );
+        send_instr(&mut svm, instr, &authority).expect("initialise should succeed");
+
+        let round = fetch_round(&svm, &pda).expect("round account should exist");
+        assert_eq!(round.round_id, round_id);
+    }
+
+    let (round_authority_pda, _) = round_authority_pda(&authority.pubkey());
+    let round_authority =
+        fetch_round_authority(&svm, &round_authority_pda).expect("round authority should exist");
+    assert_eq!(round_authority.next_round_id, 3);
+}
+
+#[test]
+fn authorities_have_independent_round_ids() {
+    let mut svm = setup();
+    let start_slot = current_slot(&svm) + 10;
+
+    for _ in 0..2 {
+        let authority = Keypair::new();
+        svm.airdrop(&authority.pubkey(), LAMPORTS_PER_SOL).unwrap();
+        let (pda, _) = round_pda(&authority.pubkey(), 0);
+        let instr = build_initialise_round_instr(
+            &authority.pubkey(),
+            &pda,
+            0,
+            start_slot,
+            start_slot + ROUND_LENGTH,
+        );
+        send_instr(&mut svm, instr, &authority).expect("initialise should succeed");
+    }
+}
+
+#[test]
+fn initialises_a_new_round_after_the_previous_one_completes() {
+    let mut svm = setup();
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    let (first_pda, _) = round_pda(&authority.pubkey(), 0);
+
+    let start_slot = current_slot(&svm) + 10;
+    let instr = build_initialise_round_instr(
+        &authority.pubkey(),
+        &first_pda,
+        0,
+        start_slot,
+        start_slot + ROUND_LENGTH,
+    );
+    send_instr(&mut svm, instr, &authority).expect("initialise should succeed");
+
+    svm.warp_to_slot(start_slot);
+    let instr = build_activate_round_instr(&authority.pubkey(), &first_pda);
+    send_instr(&mut svm, instr, &authority).expect("activate should succeed");
+
+    svm.warp_to_slot(start_slot + 10);
+    let instr = build_complete_round_instr(&authority.pubkey(), &first_pda);
+    send_instr(&mut svm, instr, &authority).expect("complete should succeed");
+
+    let (second_pda, _) = round_pda(&authority.pubkey(), 1);
+    let second_start_slot = current_slot(&svm) + 10;
+    let instr = build_initialise_round_instr(
+        &authority.pubkey(),
+        &second_pda,
+        1,
+        second_start_slot,
+        second_start_slot + ROUND_LENGTH,
+    );
+    send_instr(&mut svm, instr, &authority).expect("second initialise should succeed");
+
+    svm.warp_to_slot(second_start_slot);
+    let instr = build_activate_round_instr(&authority.pubkey(), &second_pda);
+    send_instr(&mut svm, instr, &authority).expect("second activate should succeed");
+
+    let first = fetch_round(&svm, &first_pda).expect("first round should exist");
+    let second = fetch_round(&svm, &second_pda).expect("second round should exist");
+    assert_eq!(
+        first.status,
+        RoundStatus::Completed {
+            activated_at: start_slot,
+            activated_by: authority.pubkey(),
+            completed_at: start_slot + 10,
+        }
+    );
+    assert_eq!(
+        second.status,
+        RoundStatus::Active {
+            activated_at: second_start_slot,
+            activated_by: authority.pubkey(),
+        }
+    );
+}
+
+#[test]
+fn fails_to_initialise_a_round_if_round_id_is_not_next() {
+    let mut svm = setup();
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    let (pda, _) = round_pda(&authority.pubkey(), 1);
+
+    let start_slot = current_slot(&svm) + 10;
+    let instr = build_initialise_round_instr(
+        &authority.pubkey(),
+        &pda,
+        1,
+        start_slot,
+        start_slot + ROUND_LENGTH,
+    );
+    let result = send_instr(&mut svm, instr, &authority);
+    assert!(result.is_err(), "initialise with a skipped id should fail");
+    assert_err_logs_contain(&result, "InvalidRoundId");
+}
+
+#[test]
+fn fails_to_initialise_a_round_if_pda_does_not_match_round_id() {
+    let mut svm = setup();
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    let (pda, _) = round_pda(&authority.pubkey(), 1);
+
+    let start_slot = current_slot(&svm) + 10;
+    let instr = build_initialise_round_instr(
+        &authority.pubkey(),
+        &pda,
+        0,
+        start_slot,
+        start_slot + ROUND_LENGTH,
+    );
+    let result = send_instr(&mut svm, instr, &authority);
+    assert!(
+        result.is_err(),
+        "initialise with a mismatched pda should fail"
+    );
+    assert_err_logs_contain(&result, "ConstraintSeeds");
+}
+
+#[test]
+fn fails_to_initialise_a_round_if_already_initialised() {
+    let mut svm = setup();
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    let (pda, _) = round_pda(&authority.pubkey(), 0);
+
+    let start_slot = current_slot(&svm) + 10;
+    let instr = build_initialise_round_instr(
+        &authority.pubkey(),
+        &pda,
+        0,
+        start_slot,
+        start_slot + ROUND_LENGTH,
+    );
+    send_instr(&mut svm, instr, &authority).expect("first initialise should succeed");
+
+    let start_slot = current_slot(&svm) + 20;
+    let instr = build_initialise_round_instr(
+        &authority.pubkey(),
+        &pda,
+        0,
+        start_slot,
+        start_slot + ROUND_LENGTH,
+    );
+    let result = send_instr(&mut svm, instr, &authority);
+    assert!(result.is_err(), "second initialise should fail");
+    assert_err_logs_contain(&result, "already in use");
+}
+
+#[test]
+fn fails_to_initialise_a_round_if_not_after_current_slot() {
+    let mut svm = setup();
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    let (pda, _) = round_pda(&authority.pubkey(), 0);
+
+    svm.warp_to_slot(20);
+
+    let instr = build_initialise_round_instr(&authority.pubkey(), &pda, 0, 10, 10 + ROUND_LENGTH);
+    let result = send_instr(&mut svm, instr, &authority);
+    assert!(result.is_err(), "initialise with past slot should fail");
+    assert_err_logs_contain(&result, "round must start in the future");
+}
+
+#[test]
+fn fails_to_initialise_a_round_if_end_slot_not_after_start_slot() {
+    let mut svm = setup();
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    let (pda, _) = round_pda(&authority.pubkey(), 0);
+
+    let start_slot = current_slot(&svm) + 10;
+    let instr = build_initialise_round_instr(&authority.pubkey(), &pda, 0, start_slot, start_slot);
+    let result = send_instr(&mut svm, instr, &authority);
+    assert!(
+        result.is_err(),
+        "initialise with end slot at start slot should fail"
+    );
+    assert_err_logs_contain(&result, "InvalidEndSlot");
+}
+
+// Round activation
+
+#[test]
+fn authority_user_activates_a_round_at_start_slot() {
+    let mut svm = setup();
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    let (pda, _) = round_pda(&authority.pubkey(), 0);
+
+    let start_slot = current_slot(&svm) + 10;
+    let instr = build_initialise_round_instr(
+        &authority.pubkey(),
+        &pda,
+        0,
+        start_slot,
+        start_slot + ROUND_LENGTH,
+    );
+    send_instr(&mut svm, instr, &authority).expect("initialise should succeed");
+
+    svm.warp_to_slot(start_slot);
+
+    let instr = build_activate_round_instr(&authority.pubkey(), &pda);
+    send_instr(&mut svm, instr, &authority).expect("activate should succeed");
+
+    let round = fetch_round(&svm, &pda).expect("round account should exist");
+    assert_eq!(
+        round.status,
+        RoundStatus::Active {
+            activated_at: start_slot,
+            activated_by: authority.pubkey(),
+        }
+    );
+}
+
+#[test]
+fn non_authority_user_activates_a_round_at_start_slot() {
+    let mut svm = setup();
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    let (pda, _) = round_pda(&authority.pubkey(), 0);
+
+    let start_slot = current_slot(&svm) + 10;
+    let instr = build_initialise_round_instr(
+        &authority.pubkey(),
+        &pda,
+        0,
+        start_slot,
+        start_slot + ROUND_LENGTH,
+    );
+    send_instr(&mut svm, instr, &authority).expect("initialise should succeed");
+
+    svm.warp_to_slot(start_slot);
+
+    let activator = Keypair::new();
+    svm.airdrop(&activator.pubkey(), LAMPORTS_PER_SOL).unwrap();
+
+    let instr = build_activate_round_instr(&activator.pubkey(), &pda);
+    send_instr(&mut svm, instr, &activator).expect("activate should succeed");
+
+    let round = fetch_round(&svm, &pda).expect("round account should exist");
+    assert_eq!(
+        round.status,
+        RoundStatus::Active {
+            activated_at: start_slot,
+            activated_by: activator.pubkey(),
+        }
+    );
+}
+
+#[test]
+fn round_activated_after_start_slot() {
+    let mut svm = setup();
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    let (pda, _) = round_pda(&authority.pubkey(), 0);
+
+    let start_slot = current_slot(&svm) + 10;
+    let activate_at = start_slot + 10;
+    let instr = build_initialise_round_instr(
+        &authority.pubkey(),
+        &pda,
+        0,
+        start_slot,
+        start_slot + ROUND_LENGTH,
+    );
+    send_instr(&mut svm, instr, &authority).expect("initialise should succeed");
+
+    svm.warp_to_slot(activate_at);
+
+    let instr = build_activate_round_instr(&authority.pubkey(), &pda);
+    send_instr(&mut svm, instr, &authority).expect("activate should succeed");
+
+    let round = fetch_round(&svm, &pda).expect("round account should exist");
+    assert_eq!(
+        round.status,
+        RoundStatus::Active {
+            activated_at: activate_at,
+            activated_by: authority.pubkey(),
+        }
+    );
+}
+
+#[test]
+fn fails_to_activate_a_round_if_not_initialised() {
+    let mut svm = setup();
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    let (pda, _) = round_pda(&authority.pubkey(), 0);
+
+    let instr = build_activate_round_instr(&authority.pubkey(), &pda);
+    let result = send_instr(&mut svm, instr, &authority);
+    assert!(result.is_err(), "activate without initialise should fail");
+    assert_err_logs_contain(&result, "AccountNotInitialized");
+}
+
+#[test]
+fn fails_to_activate_a_round_if_already_active() {
+    let mut svm = setup();
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    let (pda, _) = round_pda(&authority.pubkey(), 0);
+
+    let start_slot = current_slot(&svm) + 10;
+    let instr = build_initialise_round_instr(
+        &authority.pubkey(),
+        &pda,
+        0,
+        start_slot,
+        start_slot + ROUND_LENGTH,
+    );
+    send_instr(&mut svm, instr, &authority).expect("initialise should succeed");
+
+    svm.warp_to_slot(start_slot);
+
+    let instr = build_activate_round_instr(&authority.pubkey(), &pda);
+    send_instr(&mut svm, instr, &authority).expect("first activate should succeed");
+
+    // LiteSVM was rejecting the second identical txn as `AlreadyProcessed`,
+    // as the same instr w/ the same signer and same blockhash produces an identical txn hash.
+    // Expiring the blockhash forces a new one, making the txns distinct.
+    svm.expire_blockhash();
+
+    let instr = build_activate_round_instr(&authority.pubkey(), &pda);
+    let result = send_instr(&mut svm, instr, &authority);
+    assert!(result.is_err(), "second activate should fail");
+    assert_err_logs_contain(&result, "RoundAlreadyActive");
+}
+
+#[test]
+fn fails_to_activate_a_round_if_not_after_current_slot() {
+    let mut svm = setup();
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    let (pda, _) = round_pda(&authority.pubkey(), 0);
+
+    let start_slot = current_slot(&svm) + 10;
+    let instr = build_initialise_round_instr(
+        &authority.pubkey(),
+        &pda,
+        0,
+        start_slot,
+        start_slot + ROUND_LENGTH,
+    );
+    send_instr(&mut svm, instr, &authority).expect("initialise should succeed");
+
+    svm.warp_to_slot(current_slot(&svm) + 5);
+
+    let instr = build_activate_round_instr(&authority.pubkey(), &pda);
+    let result = send_instr(&mut svm, instr, &authority);
+    assert!(result.is_err(), "activate before start slot should fail");
+    assert_err_logs_contain(&result, "round can't be activated before its start");
+}
+
+#[test]
+fn round_activated_at_end_slot() {
+    let mut svm = setup();
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    let (pda, _) = round_pda(&authority.pubkey(), 0);
+
+    let start_slot = current_slot(&svm) + 10;
+    let end_slot = start_slot + ROUND_LENGTH;
+    let instr = build_initialise_round_instr(&authority.pubkey(), &pda, 0, start_slot, end_slot);
+    send_instr(&mut svm, instr, &authority).expect("initialise should succeed");
+
+    svm.warp_to_slot(end_slot);
+
+    let instr = build_activate_round_instr(&authority.pubkey(), &pda);
+    send_instr(&mut svm, instr, &authority).expect("activate should succeed");
+
+    let round = fetch_round(&svm, &pda).expect("round account should exist");
+    assert_eq!(
+        round.status,
+        RoundStatus::Active {
+            activated_at: end_slot,
+            activated_by: authority.pubkey(),
+        }
+    );
+}
+
+#[test]
+fn fails_to_activate_a_round_after_end_slot() {
+    let mut svm = setup();
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    let (pda, _) = round_pda(&authority.pubkey(), 0);
+
+    let start_slot = current_slot(&svm) + 10;
+    let end_slot = start_slot + ROUND_LENGTH;
+    let instr = build_initialise_round_instr(&authority.pubkey(), &pda, 0, start_slot, end_slot);
+    send_instr(&mut svm, instr, &authority).expect("initialise should succeed");
+
+    svm.warp_to_slot(end_slot + 1);
+
+    let instr = build_activate_round_instr(&authority.pubkey(), &pda);
+    let result = send_instr(&mut svm, instr, &authority);
+    assert!(result.is_err(), "activate after end slot should fail");
+    assert_err_logs_contain(&result, "RoundExpired");
+}
+
+// Round completion
+
+#[test]
+fn completes_a_round() {
+    let mut svm = setup();
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    let (pda, _) = round_pda(&authority.pubkey(), 0);
+
+    let start_slot = current_slot(&svm) + 10;
+    let complete_slot = start_slot + 10;
+    let instr = build_initialise_round_instr(
+        &authority.pubkey(),
+        &pda,
+        0,
+        start_slot,
+        start_slot + ROUND_LENGTH,
+    );
+    send_instr(&mut svm, instr, &authority).expect("initialise should succeed");
+
+    svm.warp_to_slot(start_slot);
+    let instr = build_activate_round_instr(&authority.pubkey(), &pda);
+    send_instr(&mut svm, instr, &authority).expect("activate should succeed");
+
+    svm.warp_to_slot(complete_slot);
+    let instr = build_complete_round_instr(&authority.pubkey(), &pda);
+    send_instr(&mut svm, instr, &authority).expect("complete should succeed");
+
+    let round = fetch_round(&svm, &pda).expect("round account should exist");
+    assert_eq!(
+        round.status,
+        RoundStatus::Completed {
+            activated_at: start_slot,
+            activated_by: authority.pubkey(),
+            completed_at: complete_slot,
+        }
+    );
+}
+
+#[test]
+fn fails_to_complete_a_round_before_end_slot_if_signer_is_not_authority() {
+    let mut svm = setup();
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    let (pda, _) = round_pda(&authority.pubkey(), 0);
+
+    let start_slot = current_slot(&svm) + 10;
+    let complete_slot = start_slot + 10;
+    let instr = build_initialise_round_instr(
+        &authority.pubkey(),
+        &pda,
+        0,
+        start_slot,
+        start_slot + ROUND_LENGTH,
+    );
+    send_instr(&mut svm, instr, &authority).expect("initialise should succeed");
+
+    svm.warp_to_slot(start_slot);
+    let instr = build_activate_round_instr(&authority.pubkey(), &pda);
+    send_instr(&mut svm, instr, &authority).expect("activate should succeed");
+
+    svm.warp_to_slot(complete_slot);
+    let non_authority = Keypair::new();
+    svm.airdrop(&non_authority.pubkey(), LAMPORTS_PER_SOL)
+        .unwrap();
+    let instr = build_complete_round_instr(&non_authority.pubkey(), &pda);
+    let result = send_instr(&mut svm, instr, &non_authority);
+    assert!(result.is_err(), "complete by non-authority should fail");
+    assert_err_logs_contain(&result, "RoundNotYetEnded");
+}
+
+#[test]
+fn non_authority_user_completes_a_round_after_end_slot() {
+    let mut svm = setup();
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    let (pda, _) = round_pda(&authority.pubkey(), 0);
+
+    let start_slot = current_slot(&svm) + 10;
+    let end_slot = start_slot + ROUND_LENGTH;
+    let instr = build_initialise_round_instr(&authority.pubkey(), &pda, 0, start_slot, end_slot);
+    send_instr(&mut svm, instr, &authority).expect("initialise should succeed");
+
+    svm.warp_to_slot(start_slot);
+    let instr = build_activate_round_instr(&authority.pubkey(), &pda);
+    send_instr(&mut svm, instr, &authority).expect("activate should succeed");
+
+    let non_authority = Keypair::new();
+    svm.airdrop(&non_authority.pubkey(), LAMPORTS_PER_SOL)
+        .unwrap();
+
+    svm.warp_to_slot(end_slot);
+    let instr = build_complete_round_instr(&non_authority.pubkey(), &pda);
+    let result = send_instr(&mut svm, instr, &non_authority);
+    assert!(
+        result.is_err(),
+        "complete by non-authority at end slot should fail"
+    );
+    assert_err_logs_contain(&result, "RoundNotYetEnded");
+
+    // LiteSVM rejects identical txns as `AlreadyProcessed`.
+    // Expiring the blockhash forces a new one, making the txns distinct.
+    svm.expire_blockhash();
+
+    svm.warp_to_slot(end_slot + 1);
+    let instr = build_complete_round_instr(&non_authority.pubkey(), &pda);
+    send_instr(&mut svm, instr, &non_authority).expect("complete should succeed");
+
+    let round = fetch_round(&svm, &pda).expect("round account should exist");
+    assert_eq!(
+        round.status,
+        RoundStatus::Completed {
+            activated_at: start_slot,
+            activated_by: authority.pubkey(),
+            completed_at: end_slot + 1,
+        }
+    );
+}
+
+#[test]
+fn fails_to_complete_a_round_if_not_active() {
+    let mut svm = setup();
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    let (pda, _) = round_pda(&authority.pubkey(), 0);
+
+    let start_slot = current_slot(&svm) + 10;
+    let instr = build_initialise_round_instr(
+        &authority.pubkey(),
+        &pda,
+        0,
+        start_slot,
+        start_slot + ROUND_LENGTH,
+    );
+    send_instr(&mut svm, instr, &authority).expect("initialise should succeed");
+
+    let instr = build_complete_round_instr(&authority.pubkey(), &pda);
+    let result = send_instr(&mut svm, instr, &authority);
+    assert!(result.is_err(), "complete without activate should fail");
+    assert_err_logs_contain(&result, "round has not yet been activated");
+}
+
+#[test]
+fn fails_to_complete_a_round_if_already_completed() {
+    let mut svm = setup();
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    let (pda, _) = round_pda(&authority.pubkey(), 0);
+
+    let start_slot = current_slot(&svm) + 10;
+    let complete_slot = start_slot + 10;
+    let instr = build_initialise_round_instr(
+        &authority.pubkey(),
+        &pda,
+        0,
+        start_slot,
+        start_slot + ROUND_LENGTH,
+    );
+    send_instr(&mut svm, instr, &authority).expect("initialise should succeed");
+
+    svm.warp_to_slot(start_slot);
+    let instr = build_activate_round_instr(&authority.pubkey(), &pda);
+    send_instr(&mut svm, instr, &authority).expect("activate should succeed");
+
+    svm.warp_to_slot(complete_slot);
+    let instr = build_complete_round_instr(&authority.pubkey(), &pda);
+    send_instr(&mut svm, instr, &authority).expect("first complete should succeed");
+
+    // LiteSVM was rejecting the second identical txn as `AlreadyProcessed`,
+    // as the same instr w/ the same signer and same blockhash produces an identical txn hash.
+    // Expiring the blockhash forces a new one, making the txns distinct.
+    svm.expire_blockhash();
+
+    let instr = build_complete_round_instr(&authority.pubkey(), &pda);
+    let result = send_instr(&mut svm, instr, &authority);
+    assert!(result.is_err(), "second complete should fail");
+    assert_err_logs_contain(&result, "RoundAlreadyComplete");
+}
+
+#[test]
+fn fails_to_complete_a_round_if_not_initialised() {
+    let mut svm = setup();
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    let (pda, _) = round_pda(&authority.pubkey(), 0);
+
+    let instr = build_complete_round_instr(&authority.pubkey(), &pda);
+    let result = send_instr(&mut svm, instr, &authority);
+    assert!(result.is_err(), "complete without initialise should fail");
+    assert_err_logs_contain(&result, "AccountNotInitialized");
+}
+
+// Round cancellation
+
+#[test]
+fn cancels_a_scheduled_round() {
+    let mut svm = setup();
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    let (pda, _) = round_pda(&authority.pubkey(), 0);
+
+    let start_slot = current_slot(&svm) + 10;
+    let instr = build_initialise_round_instr(
+        &authority.pubkey(),
+        &pda,
+        0,
+        start_slot,
+        start_slot + ROUND_LENGTH,
+    );
+    send_instr(&mut svm, instr, &authority).expect("initialise should succeed");
+
+    let cancel_slot = current_slot(&svm) + 5;
+    svm.warp_to_slot(cancel_slot);
+    let instr = build_cancel_round_instr(&authority.pubkey(), &pda);
+    send_instr(&mut svm, instr, &authority).expect("cancel should succeed");
+
+    let round = fetch_round(&svm, &pda).expect("round account should exist");
+    assert_eq!(
+        round.status,
+        RoundStatus::Cancelled {
+            cancelled_at: cancel_slot
+        }
+    );
+}
+
+#[test]
+fn cancels_an_expired_round_that_was_never_activated() {
+    let mut svm = setup();
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    let (pda, _) = round_pda(&authority.pubkey(), 0);
+
+    let start_slot = current_slot(&svm) + 10;
+    let end_slot = start_slot + ROUND_LENGTH;
+    let instr = build_initialise_round_instr(&authority.pubkey(), &pda, 0, start_slot, end_slot);
+    send_instr(&mut svm, instr, &authority).expect("initialise should succeed");
+
+    svm.warp_to_slot(end_slot + 1);
+    let instr = build_cancel_round_instr(&authority.pubkey(), &pda);
+    send_instr(&mut svm, instr, &authority).expect("cancel should succeed");
+
+    let round = fetch_round(&svm, &pda).expect("round account should exist");
+    assert_eq!(
+        round.status,
+        RoundStatus::Cancelled {
+            cancelled_at: end_slot + 1
+        }
+    );
+}
+
+#[test]
+fn fails_to_cancel_a_round_if_signer_is_not_authority() {
+    let mut svm = setup();
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    let (pda, _) = round_pda(&authority.pubkey(), 0);
+
+    let start_slot = current_slot(&svm) + 10;
+    let instr = build_initialise_round_instr(
+        &authority.pubkey(),
+        &pda,
+        0,
+        start_slot,
+        start_slot + ROUND_LENGTH,
+    );
+    send_instr(&mut svm, instr, &authority).expect("initialise should succeed");
+
+    let non_authority = Keypair::new();
+    svm.airdrop(&non_authority.pubkey(), LAMPORTS_PER_SOL)
+        .unwrap();
+    let instr = build_cancel_round_instr(&non_authority.pubkey(), &pda);
+    let result = send_instr(&mut svm, instr, &non_authority);
+    assert!(result.is_err(), "cancel by non-authority should fail");
+    assert_err_logs_contain(&result, "ConstraintHasOne");
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Stage {
+    Scheduled,
+    Active,
+    Completed,
+    Cancelled,
+}
+
+#[derive(Clone, Copy, Debug)]
+enum Transition {
+    Activate,
+    Complete,
+    Cancel,
+}
+
+fn stage_of(status: &RoundStatus) -> Stage {
+    match status {
+        RoundStatus::Scheduled => Stage::Scheduled,
+        RoundStatus::Active { .. } => Stage::Active,
+        RoundStatus::Completed { .. } => Stage::Completed,
+        RoundStatus::Cancelled { .. } => Stage::Cancelled,
+    }
+}
+
+// Initialises a round and drives it to `stage`, leaving the clock at its start slot
+fn round_at_stage(svm: &mut LiteSVM, authority: &Keypair, stage: Stage) -> Pubkey {
+    let (pda, _) = round_pda(&authority.pubkey(), 0);
+    let start_slot = current_slot(svm) + 10;
+    let instr = build_initialise_round_instr(
+        &authority.pubkey(),
+        &pda,
+        0,
+        start_slot,
+        start_slot + ROUND_LENGTH,
+    );
+    send_instr(svm, instr, authority).expect("initialise should succeed");
+    svm.warp_to_slot(start_slot);
+
+    if matches!(stage, Stage::Active | Stage::Completed) {
+        let instr = build_activate_round_instr(&authority.pubkey(), &pda);
+        send_instr(svm, instr, authority).expect("activate should succeed");
+    }
+    match stage {
+        Stage::Completed => {
+            let instr = build_complete_round_instr(&authority.pubkey(), &pda);
+            send_instr(svm, instr, authority).expect("complete should succeed");
+        }
+        Stage::Cancelled => {
+            let instr = build_cancel_round_instr(&authority.pubkey(), &pda);
+            send_instr(svm, instr, authority).expect("cancel should succeed");
+        }
+        _ => {}
+    }
+
+    // LiteSVM rejects identical txns as `AlreadyProcessed`.
+    // Expiring the blockhash forces a new one, making the txns distinct.
+    svm.expire_blockhash();
+
+    pda
+}
+
+#[test]
+fn round_status_transitions() {
+    // Every transition from every stage, with the resulting stage or the expected error
+    let cases: [(Stage, Transition, Result<Stage, &str>); 12] = [
+        (Stage::Scheduled, Transition::Activate, Ok(Stage::Active)),
+        (
+            Stage::Scheduled,
+            Transition::Complete,
+            Err("RoundNotYetActive"),
+        ),
+        (Stage::Scheduled, Transition::Cancel, Ok(Stage::Cancelled)),
+        (
+            Stage::Active,
+            Transition::Activate,
+            Err("RoundAlreadyActive"),
+        ),
+        (Stage::Active, Transition::Complete, Ok(Stage::Completed)),
+        (Stage::Active, Transition::Cancel, Ok(Stage::Cancelled)),
+        (
+            Stage::Completed,
+            Transition::Activate,
+            Err("RoundAlreadyComplete"),
+        ),
+        (
+            Stage::Completed,
+            Transition::Complete,
+            Err("RoundAlreadyComplete"),
+        ),
+        (
+            Stage::Completed,
+            Transition::Cancel,
+            Err("RoundAlreadyComplete"),
+        ),
+        (
+            Stage::Cancelled,
+            Transition::Activate,
+            Err("RoundCancelled"),
+        ),
+        (
+            Stage::Cancelled,
+            Transition::Complete,
+            Err("RoundCancelled"),
+        ),
+        (Stage::Cancelled, Transition::Cancel, Err("RoundCancelled")),
+    ];
+
+    for (from, transition, expected) in cases {
+        let mut svm = setup();
+        let authority = Keypair::new();
+        svm.airdrop(&authority.pubkey(), LAMPORTS_PER_SOL).unwrap();
+        let pda = round_at_stage(&mut svm, &authority, from);
+
+        let instr = match transition {
+            Transition::Activate => build_activate_round_instr(&authority.pubkey(), &pda),
+            Transition::Complete => build_complete_round_instr(&authority.pubkey(), &pda),
+            Transition::Cancel => build_cancel_round_instr(&authority.pubkey(), &pda),
+        };
+        let result = send_instr(&mut svm, instr, &authority);
+        let round = fetch_round(&svm, &pda).expect("round account should exist");
+
+        match expected {
+            Ok(to) => {
+                assert!(
+                    result.is_ok(),
+                    "{transition:?} from {from:?} should succeed"
+                );
+                assert_eq!(stage_of(&round.status), to, "{transition:?} from {from:?}");
+            }
+            Err(error) => {
+                assert!(result.is_err(), "{transition:?} from {from:?} should fail");
+                assert_err_logs_contain(&result, error);
+                assert_eq!(
+                    stage_of(&round.status),
+                    from,
+                    "{transition:?} from {from:?}"
+                );
+            }
+        }
+    }
+}
+
+// Layout migration
+
+// Writes a round as created before round ids, keyed by authority alone:
+// `start_slot`, `authority`, `activated_at`, `activated_by`, `completed_at`
+fn set_legacy_round(
+    svm: &mut LiteSVM,
+    authority: &Pubkey,
+    start_slot: u64,
+    activated: Option<(u64, Pubkey)>,
+    completed_at: Option<u64>,
+) -> Pubkey {
+    let (pda, _) = legacy_round_pda(authority);
+    let space = 8 + 8 + 32 + 9 + 33 + 9;
+
+    let mut data = round::Round::DISCRIMINATOR.to_vec();
+    data.extend_from_slice(&start_slot.to_le_bytes());
+    data.extend_from_slice(authority.as_ref());
+    let (activated_at, activated_by) = activated.unzip();
+    activated_at.serialize(&mut data).unwrap();
+    activated_by.serialize(&mut data).unwrap();
+    completed_at.serialize(&mut data).unwrap();
+    data.resize(space, 0);
+
+    svm.set_account(
+        pda,
+        Account {
+            lamports: svm.minimum_balance_for_rent_exemption(space),
+            data,
+            owner: round::ID,
+            executable: false,
+            rent_epoch: 0,
+        },
+    )
+    .unwrap();
+    pda
+}
+
+#[test]
+fn migrates_legacy_rounds_to_a_status() {
+    let authority = Keypair::new();
+    let activator = Keypair::new();
+    let legacy_rounds = [
+        (None, None, RoundStatus::Scheduled),
+        (
+            Some((20, activator.pubkey())),
+            None,
+            RoundStatus::Active {
+                activated_at: 20,
+                activated_by: activator.pubkey(),
+            },
+        ),
+        (
+            Some((20, activator.pubkey())),
+            Some(30),
+            RoundStatus::Completed {
+                activated_at: 20,
+                activated_by: activator.pubkey(),
+                completed_at: 30,
+            },
+        ),
+    ];
+
+    for (activated, completed_at, status) in legacy_rounds {
+        let mut svm = setup();
+        svm.airdrop(&authority.pubkey(), LAMPORTS_PER_SOL).unwrap();
+        let legacy_pda =
+            set_legacy_round(&mut svm, &authority.pubkey(), 10, activated, completed_at);
+
+        let instr =
+            build_migrate_round_instr(&authority.pubkey(), &legacy_pda, 0, 10 + ROUND_LENGTH);
+        send_instr(&mut svm, instr, &authority).expect("migrate should succeed");
+
+        let (pda, _) = round_pda(&authority.pubkey(), 0);
+        let round = fetch_round(&svm, &pda).expect("round account should exist");
+        assert_eq!(round.start_at, 10);
+        assert_eq!(round.authority, authority.pubkey());
+        assert_eq!(round.version, 1);
+        assert_eq!(round.round_id, 0);
+        assert_eq!(round.end_at, 10 + ROUND_LENGTH);
+        assert_eq!(round.status, status);
+        assert_eq!(round.participant_count, 0);
+        assert_eq!(round.entry_fee, 0);
+
+        let (round_authority_pda, _) = round_authority_pda(&authority.pubkey());
+        let round_authority = fetch_round_authority(&svm, &round_authority_pda)
+            .expect("round authority should exist");
+        assert_eq!(round_authority.next_round_id, 1);
+        assert!(svm
+            .get_account(&legacy_pda)
+            .is_none_or(|account| account.lamports == 0));
+    }
+}
+
+#[test]
+fn legacy_round_is_usable_once_migrated() {
+    let mut svm = setup();
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    let start_slot = current_slot(&svm) + 10;
+    let legacy_pda = set_legacy_round(&mut svm, &authority.pubkey(), start_slot, None, None);
+
+    let instr = build_migrate_round_instr(
+        &authority.pubkey(),
+        &legacy_pda,
+        0,
+        start_slot + ROUND_LENGTH,
+    );
+    send_instr(&mut svm, instr, &authority).expect("migrate should succeed");
+    svm.warp_to_slot(start_slot);
+
+    let (pda, _) = round_pda(&authority.pubkey(), 0);
+    let instr = build_activate_round_instr(&authority.pubkey(), &pda);
+    send_instr(&mut svm, instr, &authority).expect("activate should succeed");
+
+    let round = fetch_round(&svm, &pda).expect("round account should exist");
+    assert_eq!(
+        round.status,
+        RoundStatus::Active {
+            activated_at: start_slot,
+            activated_by: authority.pubkey(),
+        }
+    );
+}
+
+#[test]
+fn migrates_a_legacy_round_to_the_authority_next_round_id() {
+    let mut svm = setup();
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    let start_slot = current_slot(&svm) + 10;
+    let (pda, _) = round_pda(&authority.pubkey(), 0);
+    let instr = build_initialise_round_instr(
+        &authority.pubkey(),
+        &pda,
+        0,
+        start_slot,
+        start_slot + ROUND_LENGTH,
+    );
+    send_instr(&mut svm, instr, &authority).expect("initialise should succeed");
+    let legacy_pda = set_legacy_round(&mut svm, &authority.pubkey(), 10, None, None);
+
+    let instr = build_migrate_round_instr(&authority.pubkey(), &legacy_pda, 0, 10 + ROUND_LENGTH);
+    let result = send_instr(&mut svm, instr, &authority);
+    assert!(result.is_err(), "migrate to a taken round id should fail");
+    assert_err_logs_contain(&result, "already in use");
+
+    let instr = build_migrate_round_instr(&authority.pubkey(), &legacy_pda, 1, 10 + ROUND_LENGTH);
+    send_instr(&mut svm, instr, &authority).expect("migrate should succeed");
+
+    let round = fetch_round(&svm, &round_pda(&authority.pubkey(), 1).0)
+        .expect("round account should exist");
+    assert_eq!(round.round_id, 1);
+    assert_eq!(round.start_at, 10);
+}
+
+#[test]
+fn migrating_a_legacy_round_returns_its_rent_to_the_authority() {
+    let mut svm = setup();
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    let legacy_pda = set_legacy_round(&mut svm, &authority.pubkey(), 10, None, None);
+    let legacy_rent = svm.get_balance(&legacy_pda).unwrap();
+    let round_authority_rent =
+        svm.minimum_balance_for_rent_exemption(8 + round::RoundAuthority::INIT_SPACE);
+
+    let instr = build_migrate_round_instr(&authority.pubkey(), &legacy_pda, 0, 10 + ROUND_LENGTH);
+    send_instr(&mut svm, instr, &authority).expect("migrate should succeed");
+
+    // Less the fee for the authority's signature
+    assert_eq!(
+        svm.get_balance(&authority.pubkey()).unwrap(),
+        LAMPORTS_PER_SOL + legacy_rent - round_rent(&svm) - round_authority_rent - 5_000
+    );
+}
+
+#[test]
+fn fails_to_migrate_a_legacy_round_ending_before_its_start() {
+    let mut svm = setup();
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    let legacy_pda = set_legacy_round(&mut svm, &authority.pubkey(), 10, None, None);
+
+    let instr = build_migrate_round_instr(&authority.pubkey(), &legacy_pda, 0, 10);
+    let result = send_instr(&mut svm, instr, &authority);
+    assert!(result.is_err(), "migrate with end before start should fail");
+    assert_err_logs_contain(&result, "InvalidEndSlot");
+}
+
+#[test]
+fn fails_to_migrate_another_authority_legacy_round() {
+    let mut svm = setup();
+    let authority = Keypair::new();
+    let other = Keypair::new();
+    svm.airdrop(&other.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    let legacy_pda = set_legacy_round(&mut svm, &authority.pubkey(), 10, None, None);
+
+    let instr = build_migrate_round_instr(&other.pubkey(), &legacy_pda, 0, 10 + ROUND_LENGTH);
+    let result = send_instr(&mut svm, instr, &other);
+    assert!(
+        result.is_err(),
+        "migrate of another authority's round should fail"
+    );
+    assert_err_logs_contain(&result, "ConstraintSeeds");
+}
+
+#[test]
+fn rejects_a_round_on_another_layout() {
+    let mut svm = setup();
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    let (pda, start_slot) = scheduled_round(&mut svm, &authority, RoundConfig::default());
+    let mut account = svm.get_account(&pda).unwrap();
+    account.data[8 + 8 + 32] = 0;
+    svm.set_account(pda, account).unwrap();
+    svm.warp_to_slot(start_slot);
+
+    let instr = build_activate_round_instr(&authority.pubkey(), &pda);
+    let result = send_instr(&mut svm, instr, &authority);
+    assert!(
+        result.is_err(),
+        "activate of a round on another layout should fail"
+    );
+    assert_err_logs_contain(&result, "AccountNotMigrated");
+}
+
+// Round participation
+
+// Initialises round 0 with `config`, starting 10 slots from now, returning its PDA and start slot
+fn scheduled_round(svm: &mut LiteSVM, authority: &Keypair, config: RoundConfig) -> (Pubkey, u64) {
+    let (pda, _) = round_pda(&authority.pubkey(), 0);
+    let start_slot = current_slot(svm) + 10;
+    let instr = build_initialise_configured_round_instr(
+        &authority.pubkey(),
+        &pda,
+        0,
+        start_slot,
+        start_slot + ROUND_LENGTH,
+        &config,
+    );
+    send_instr(svm, instr, authority).expect("initialise should succeed");
+    (pda, start_slot)
+}
+
+// Initialises a round with `config` and activates it at its start slot
+fn active_round(svm: &mut LiteSVM, authority: &Keypair, config: RoundConfig) -> Pubkey {
+    let (pda, start_slot) = scheduled_round(svm, authority, config);
+
+    svm.warp_to_slot(start_slot);
+    let instr = build_activate_round_instr(&authority.pubkey(), &pda);
+    send_instr(svm, instr, authority).expect("activate should succeed");
+    pda
+}
+
+// Funds a new user and sends the instruction `build_instr` makes for them in the round,
+// e.g. `build_join_round_instr`
+fn funded_user(
+    svm: &mut LiteSVM,
+    round_pda: &Pubkey,
+    build_instr: fn(&Pubkey, &Pubkey) -> Instruction,
+) -> Keypair {
+    let user = Keypair::new();
+    svm.airdrop(&user.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    let instr = build_instr(&user.pubkey(), round_pda);
+    send_instr(svm, instr, &user).expect("instruction should succeed");
+    user
+}
+
+#[test]
+fn users_join_an_active_round() {
+    let mut svm = setup();
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    let pda = active_round(&mut svm, &authority, RoundConfig::default());
+
+    for entry_index in 0..3 {
+        let user = funded_user(&mut svm, &pda, build_join_round_instr);
+
+        let (participant_pda, _) = participant_pda(&pda, &user.pubkey());
+        let participant =
+            fetch_participant(&svm, &participant_pda).expect("participant should exist");
+        assert_eq!(participant.round, pda);
+        assert_eq!(participant.user, user.pubkey());
+        assert_eq!(participant.joined_at, current_slot(&svm));
+        assert_eq!(participant.entry_index, entry_index);
+    }
+
+    let round = fetch_round(&svm, &pda).expect("round account should exist");
+    assert_eq!(round.participant_count, 3);
+}
+
+#[test]
+fn fails_to_join_a_round_twice() {
+    let mut svm = setup();
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    let pda = active_round(&mut svm, &authority, RoundConfig::default());
+
+    let user = Keypair::new();
+    svm.airdrop(&user.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    let instr = build_join_round_instr(&user.pubkey(), &pda);
+    send_instr(&mut svm, instr, &user).expect("first join should succeed");
+
+    // LiteSVM rejects identical txns as `AlreadyProcessed`.
+    // Expiring the blockhash forces a new one, making the txns distinct.
+    svm.expire_blockhash();
+
+    let instr = build_join_round_instr(&user.pubkey(), &pda);
+    let result = send_instr(&mut svm, instr, &user);
+    assert!(result.is_err(), "second join should fail");
+    assert_err_logs_contain(&result, "already in use");
+
+    let round = fetch_round(&svm, &pda).expect("round account should exist");
+    assert_eq!(round.participant_count, 1);
+}
+
+#[test]
+fn fails_to_join_a_round_if_not_active() {
+    let mut svm = setup();
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    let (pda, _) = round_pda(&authority.pubkey(), 0);
+
+    let start_slot = current_slot(&svm) + 10;
+    let instr = build_initialise_round_instr(
+        &authority.pubkey(),
+        &pda,
+        0,
+        start_slot,
+        start_slot + ROUND_LENGTH,
+    );
+    send_instr(&mut svm, instr, &authority).expect("initialise should succeed");
+
+    let user = Keypair::new();
+    svm.airdrop(&user.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    let instr = build_join_round_instr(&user.pubkey(), &pda);
+    let result = send_instr(&mut svm, instr, &user);
+    assert!(result.is_err(), "join before activation should fail");
+    assert_err_logs_contain(&result, "RoundNotYetActive");
+}
+
+#[test]
+fn fails_to_join_a_round_once_completed() {
+    let mut svm = setup();
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    let pda = active_round(&mut svm, &authority, RoundConfig::default());
+
+    let instr = build_complete_round_instr(&authority.pubkey(), &pda);
+    send_instr(&mut svm, instr, &authority).expect("complete should succeed");
+
+    let user = Keypair::new();
+    svm.airdrop(&user.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    let instr = build_join_round_instr(&user.pubkey(), &pda);
+    let result = send_instr(&mut svm, instr, &user);
+    assert!(result.is_err(), "join after completion should fail");
+    assert_err_logs_contain(&result, "RoundAlreadyComplete");
+}
+
+#[test]
+fn fails_to_join_a_round_after_end_slot() {
+    let mut svm = setup();
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    let pda = active_round(&mut svm, &authority, RoundConfig::default());
+    let round = fetch_round(&svm, &pda).expect("round account should exist");
+
+    svm.warp_to_slot(round.end_at + 1);
+
+    let user = Keypair::new();
+    svm.airdrop(&user.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    let instr = build_join_round_instr(&user.pubkey(), &pda);
+    let result = send_instr(&mut svm, instr, &user);
+    assert!(result.is_err(), "join after end slot should fail");
+    assert_err_logs_contain(&result, "RoundExpired");
+}
+
+#[test]
+fn fails_to_join_a_round_at_its_participant_cap() {
+    let mut svm = setup();
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    let pda = active_round(
+        &mut svm,
+        &authority,
+        RoundConfig {
+            max_participants: Some(2),
+            ..Default::default()
+        },
+    );
+
+    for _ in 0..2 {
+        funded_user(&mut svm, &pda, build_join_round_instr);
+    }
+
+    let user = Keypair::new();
+    svm.airdrop(&user.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    let instr = build_join_round_instr(&user.pubkey(), &pda);
+    let result = send_instr(&mut svm, instr, &user);
+    assert!(result.is_err(), "join beyond the cap should fail");
+    assert_err_logs_contain(&result, "RoundFull");
+
+    let round = fetch_round(&svm, &pda).expect("round account should exist");
+    assert_eq!(round.participant_count, 2);
+    assert_eq!(round.max_participants, Some(2));
+}
+
+#[test]
+fn fails_to_initialise_a_round_with_a_zero_participant_cap() {
+    let mut svm = setup();
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    let (pda, _) = round_pda(&authority.pubkey(), 0);
+
+    let start_slot = current_slot(&svm) + 10;
+    let instr = build_initialise_configured_round_instr(
+        &authority.pubkey(),
+        &pda,
+        0,
+        start_slot,
+        start_slot + ROUND_LENGTH,
+        &RoundConfig {
+            max_participants: Some(0),
+            ..Default::default()
+        },
+    );
+    let result = send_instr(&mut svm, instr, &authority);
+    assert!(result.is_err(), "initialise with a zero cap should fail");
+    assert_err_logs_contain(&result, "InvalidMaxParticipants");
+}
+
+// Entry fees
+
+const ENTRY_FEE: u64 = LAMPORTS_PER_SOL / 10;
+
+fn paid_round(svm: &mut LiteSVM, authority: &Keypair, commission_bps: u16) -> Pubkey {
+    active_round(
+        svm,
+        authority,
+        RoundConfig {
+            entry_fee: ENTRY_FEE,
+            commission_bps,
+            ..Default::default()
+        },
+    )
+}
+
+fn pot_balance(svm: &LiteSVM, round_pda: &Pubkey) -> u64 {
+    svm.get_balance(&pot_pda(round_pda).0).unwrap_or(0)
+}
+
+#[test]
+fn users_enter_a_paid_round() {
+    let mut svm = setup();
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    let pda = paid_round(&mut svm, &authority, 0);
+    let pot_reserve = svm.minimum_balance_for_rent_exemption(0);
+    assert_eq!(pot_balance(&svm, &pda), pot_reserve);
+
+    let first = funded_user(&mut svm, &pda, build_enter_round_instr);
+    let second = funded_user(&mut svm, &pda, build_enter_round_instr);
+
+    for user in [&first, &second] {
+        let (participant_pda, _) = participant_pda(&pda, &user.pubkey());
+        let participant =
+            fetch_participant(&svm, &participant_pda).expect("participant should exist");
+        assert_eq!(participant.user, user.pubkey());
+    }
+
+    let round = fetch_round(&svm, &pda).expect("round account should exist");
+    assert_eq!(round.entry_fee, ENTRY_FEE);
+    assert_eq!(round.participant_count, 2);
+    assert_eq!(pot_balance(&svm, &pda), pot_reserve + 2 * ENTRY_FEE);
+}
+
+#[test]
+fn fails_to_join_a_paid_round_without_paying() {
+    let mut svm = setup();
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    let pda = paid_round(&mut svm, &authority, 0);
+
+    let user = Keypair::new();
+    svm.airdrop(&user.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    let instr = build_join_round_instr(&user.pubkey(), &pda);
+    let result = send_instr(&mut svm, instr, &user);
+    assert!(result.is_err(), "join of a paid round should fail");
+    assert_err_logs_contain(&result, "EntryFeeRequired");
+}
+
+#[test]
+fn fails_to_enter_a_free_round() {
+    let mut svm = setup();
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    let pda = active_round(&mut svm, &authority, RoundConfig::default());
+
+    let user = Keypair::new();
+    svm.airdrop(&user.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    let instr = build_enter_round_instr(&user.pubkey(), &pda);
+    let result = send_instr(&mut svm, instr, &user);
+    assert!(result.is_err(), "enter of a free round should fail");
+    assert_err_logs_contain(&result, "RoundHasNoEntryFee");
+}
+
+// Lets the round run to its end and completes it from `user`, as anyone may
+fn complete_ended_round(svm: &mut LiteSVM, round_pda: &Pubkey, user: &Keypair) {
+    let round = fetch_round(svm, round_pda).expect("round account should exist");
+    svm.warp_to_slot(round.end_at + 1);
+    let instr = build_complete_round_instr(&user.pubkey(), round_pda);
+    send_instr(svm, instr, user).expect("complete should succeed");
+}
+
+#[test]
+fn pays_the_pot_to_the_winner_minus_commission() {
+    let mut svm = setup();
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    // 10% commission
+    let pda = paid_round(&mut svm, &authority, 1_000);
+    let winner = funded_user(&mut svm, &pda, build_enter_round_instr);
+    let user = funded_user(&mut svm, &pda, build_enter_round_instr);
+
+    // Completed by someone other than the authority, who still names the winner afterwards
+    complete_ended_round(&mut svm, &pda, &user);
+
+    let winner_balance = svm.get_balance(&winner.pubkey()).unwrap();
+    let authority_balance = svm.get_balance(&authority.pubkey()).unwrap();
+
+    let instr = build_settle_round_instr(&authority.pubkey(), &pda, &winner.pubkey());
+    send_instr(&mut svm, instr, &authority).expect("settle should succeed");
+
+    let prize = 2 * ENTRY_FEE;
+    let commission = prize / 10;
+    assert_eq!(
+        svm.get_balance(&winner.pubkey()).unwrap(),
+        winner_balance + prize - commission
+    );
+    // Less the fee for the authority's signature
+    assert_eq!(
+        svm.get_balance(&authority.pubkey()).unwrap(),
+        authority_balance + commission - 5_000
+    );
+    assert_eq!(
+        pot_balance(&svm, &pda),
+        svm.minimum_balance_for_rent_exemption(0)
+    );
+
+    let round = fetch_round(&svm, &pda).expect("round account should exist");
+    assert_eq!(round.winner, Some(winner.pubkey()));
+    assert!(matches!(round.status, RoundStatus::Completed { .. }));
+}
+
+#[test]
+fn fails_to_pay_a_winner_who_did_not_enter() {
+    let mut svm = setup();
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    let pda = paid_round(&mut svm, &authority, 0);
+    let user = funded_user(&mut svm, &pda, build_enter_round_instr);
+    let outsider = Keypair::new();
+    complete_ended_round(&mut svm, &pda, &user);
+
+    // Pairs the outsider's wallet with the entrant's participant account
+    let mut instr = build_settle_round_instr(&authority.pubkey(), &pda, &user.pubkey());
+    instr.accounts[3].pubkey = outsider.pubkey();
+    let result = send_instr(&mut svm, instr, &authority);
+    assert!(
+        result.is_err(),
+        "settle with an outsider as winner should fail"
+    );
+    assert_err_logs_contain(&result, "WinnerNotEntered");
+}
+
+#[test]
+fn fails_to_settle_if_signer_is_not_authority() {
+    let mut svm = setup();
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    let pda = paid_round(&mut svm, &authority, 0);
+    let user = funded_user(&mut svm, &pda, build_enter_round_instr);
+    complete_ended_round(&mut svm, &pda, &user);
+
+    let instr = build_settle_round_instr(&user.pubkey(), &pda, &user.pubkey());
+    let result = send_instr(&mut svm, instr, &user);
+    assert!(result.is_err(), "settle by non-authority should fail");
+    assert_err_logs_contain(&result, "ConstraintHasOne");
+}
+
+#[test]
+fn fails_to_settle_an_active_round() {
+    let mut svm = setup();
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    let pda = paid_round(&mut svm, &authority, 0);
+    let user = funded_user(&mut svm, &pda, build_enter_round_instr);
+
+    let instr = build_settle_round_instr(&authority.pubkey(), &pda, &user.pubkey());
+    let result = send_instr(&mut svm, instr, &authority);
+    assert!(result.is_err(), "settle of an active round should fail");
+    assert_err_logs_contain(&result, "RoundNotYetComplete");
+}
+
+#[test]
+fn fails_to_settle_a_round_twice() {
+    let mut svm = setup();
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    let pda = paid_round(&mut svm, &authority, 0);
+    let winner = funded_user(&mut svm, &pda, build_enter_round_instr);
+    let user = funded_user(&mut svm, &pda, build_enter_round_instr);
+    complete_ended_round(&mut svm, &pda, &user);
+
+    let instr = build_settle_round_instr(&authority.pubkey(), &pda, &winner.pubkey());
+    send_instr(&mut svm, instr, &authority).expect("settle should succeed");
+
+    let instr = build_settle_round_instr(&authority.pubkey(), &pda, &user.pubkey());
+    let result = send_instr(&mut svm, instr, &authority);
+    assert!(result.is_err(), "second settle should fail");
+    assert_err_logs_contain(&result, "RoundAlreadySettled");
+}
+
+#[test]
+fn fails_to_settle_once_the_settlement_window_has_closed() {
+    let mut svm = setup();
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    let pda = paid_round(&mut svm, &authority, 0);
+    let user = funded_user(&mut svm, &pda, build_enter_round_instr);
+    complete_ended_round(&mut svm, &pda, &user);
+
+    let completed_at = current_slot(&svm);
+    svm.warp_to_slot(completed_at + round::SETTLEMENT_WINDOW_SLOTS + 1);
+
+    let instr = build_settle_round_instr(&authority.pubkey(), &pda, &user.pubkey());
+    let result = send_instr(&mut svm, instr, &authority);
+    assert!(result.is_err(), "settle after the window should fail");
+    assert_err_logs_contain(&result, "SettlementWindowClosed");
+}
+
+#[test]
+fn refunds_entries_of_a_cancelled_round() {
+    let mut svm = setup();
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    let pda = paid_round(&mut svm, &authority, 0);
+    let user = funded_user(&mut svm, &pda, build_enter_round_instr);
+    funded_user(&mut svm, &pda, build_enter_round_instr);
+
+    let instr = build_cancel_round_instr(&authority.pubkey(), &pda);
+    send_instr(&mut svm, instr, &authority).expect("cancel should succeed");
+
+    let pot_before = pot_balance(&svm, &pda);
+    let instr = build_refund_entry_instr(&user.pubkey(), &pda);
+    send_instr(&mut svm, instr, &user).expect("refund should succeed");
+
+    assert_eq!(pot_balance(&svm, &pda), pot_before - ENTRY_FEE);
+    let (participant_pda, _) = participant_pda(&pda, &user.pubkey());
+    assert!(fetch_participant(&svm, &participant_pda).is_none());
+
+    // LiteSVM rejects identical txns as `AlreadyProcessed`.
+    // Expiring the blockhash forces a new one, making the txns distinct.
+    svm.expire_blockhash();
+
+    let instr = build_refund_entry_instr(&user.pubkey(), &pda);
+    let result = send_instr(&mut svm, instr, &user);
+    assert!(result.is_err(), "second refund should fail");
+    assert_err_logs_contain(&result, "AccountNotInitialized");
+}
+
+#[test]
+fn refunds_entries_of_a_round_left_unsettled() {
+    let mut svm = setup();
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    let pda = paid_round(&mut svm, &authority, 0);
+    let user = funded_user(&mut svm, &pda, build_enter_round_instr);
+    complete_ended_round(&mut svm, &pda, &user);
+    let completed_at = current_slot(&svm);
+
+    // The authority can still settle, so the entries stay in the pot
+    let instr = build_refund_entry_instr(&user.pubkey(), &pda);
+    let result = send_instr(&mut svm, instr, &user);
+    assert!(result.is_err(), "refund within the window should fail");
+    assert_err_logs_contain(&result, "RefundUnavailable");
+
+    svm.warp_to_slot(completed_at + round::SETTLEMENT_WINDOW_SLOTS + 1);
+    // LiteSVM rejects identical txns as `AlreadyProcessed`.
+    // Expiring the blockhash forces a new one, making the txns distinct.
+    svm.expire_blockhash();
+
+    let pot_before = pot_balance(&svm, &pda);
+    let instr = build_refund_entry_instr(&user.pubkey(), &pda);
+    send_instr(&mut svm, instr, &user).expect("refund should succeed");
+
+    assert_eq!(pot_balance(&svm, &pda), pot_before - ENTRY_FEE);
+}
+
+#[test]
+fn fails_to_refund_an_entry_of_an_active_round() {
+    let mut svm = setup();
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    let pda = paid_round(&mut svm, &authority, 0);
+    let user = funded_user(&mut svm, &pda, build_enter_round_instr);
+
+    let instr = build_refund_entry_instr(&user.pubkey(), &pda);
+    let result = send_instr(&mut svm, instr, &user);
+    assert!(result.is_err(), "refund of an active round should fail");
+    assert_err_logs_contain(&result, "RefundUnavailable");
+}
+
+#[test]
+fn fails_to_refund_an_entry_once_a_winner_is_paid() {
+    let mut svm = setup();
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    let pda = paid_round(&mut svm, &authority, 0);
+    let winner = funded_user(&mut svm, &pda, build_enter_round_instr);
+    let user = funded_user(&mut svm, &pda, build_enter_round_instr);
+    complete_ended_round(&mut svm, &pda, &user);
+    let completed_at = current_slot(&svm);
+
+    let instr = build_settle_round_instr(&authority.pubkey(), &pda, &winner.pubkey());
+    send_instr(&mut svm, instr, &authority).expect("settle should succeed");
+
+    svm.warp_to_slot(completed_at + round::SETTLEMENT_WINDOW_SLOTS + 1);
+    let instr = build_refund_entry_instr(&user.pubkey(), &pda);
+    let result = send_instr(&mut svm, instr, &user);
+    assert!(result.is_err(), "refund once a winner is paid should fail");
+    assert_err_logs_contain(&result, "RefundUnavailable");
+}
+
+#[test]
+fn fails_to_initialise_a_round_with_a_commission_over_the_whole_pot() {
+    let mut svm = setup();
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    let (pda, _) = round_pda(&authority.pubkey(), 0);
+
+    let start_slot = current_slot(&svm) + 10;
+    let instr = build_initialise_configured_round_instr(
+        &authority.pubkey(),
+        &pda,
+        0,
+        start_slot,
+        start_slot + ROUND_LENGTH,
+        &RoundConfig {
+            entry_fee: ENTRY_FEE,
+            commission_bps: 10_001,
+            ..Default::default()
+        },
+    );
+    let result = send_instr(&mut svm, instr, &authority);
+    assert!(
+        result.is_err(),
+        "initialise with a commission over 100% should fail"
+    );
+    assert_err_logs_contain(&result, "InvalidCommission");
+}
+
+// Winner draw
+
+// Activates a free round, joins `participants` users to it and completes it
+fn completed_round(svm: &mut LiteSVM, authority: &Keypair, participants: usize) -> Pubkey {
+    let pda = active_round(svm, authority, RoundConfig::default());
+    for _ in 0..participants {
+        funded_user(svm, &pda, build_join_round_instr);
+    }
+
+    let instr = build_complete_round_instr(&authority.pubkey(), &pda);
+    send_instr(svm, instr, authority).expect("complete should succeed");
+
+    // LiteSVM rejects identical txns as `AlreadyProcessed`.
+    // Expiring the blockhash forces a new one, making the txns distinct.
+    svm.expire_blockhash();
+
+    pda
+}
+
+// Replaces SlotHashes with `slots`, newest first, each hashed to a distinct filler
+fn set_slot_hashes(svm: &mut LiteSVM, slots: &[u64]) -> Vec<Hash> {
+    let hashes: Vec<Hash> = slots
+        .iter()
+        .map(|slot| Hash::new_from_array([*slot as u8 ^ 0x5a; 32]))
+        .collect();
+    let entries: Vec<_> = slots.iter().copied().zip(hashes.iter().copied()).collect();
+    svm.set_sysvar(&SlotHashes::new(&entries));
+    hashes
+}
+
+#[test]
+fn draws_a_winner_from_the_draw_slot_hash() {
+    let mut svm = setup();
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    let pda = completed_round(&mut svm, &authority, 3);
+
+    let round = fetch_round(&svm, &pda).expect("round account should exist");
+    assert_eq!(round.draw_slot, round.end_at + 1);
+    let draw_slot = round.draw_slot;
+    let hashes = set_slot_hashes(&mut svm, &[draw_slot + 2, draw_slot, draw_slot - 1]);
+
+    let instr = build_draw_winner_instr(&authority.pubkey(), &pda, 3);
+    send_instr(&mut svm, instr, &authority).expect("draw should succeed");
+
+    let slot_hash = hashes[1].to_bytes();
+    let index = u64::from_le_bytes(slot_hash[..8].try_into().unwrap()) % 3;
+    let round = fetch_round(&svm, &pda).expect("round account should exist");
+    assert_eq!(
+        round.draw,
+        Some(round::Draw {
+            candidates: 3,
+            index: index as u32,
+            slot: draw_slot,
+            slot_hash,
+        })
+    );
+}
+
+#[test]
+fn anyone_can_draw_a_winner() {
+    let mut svm = setup();
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    let pda = completed_round(&mut svm, &authority, 2);
+
+    let draw_slot = fetch_round(&svm, &pda).unwrap().draw_slot;
+    set_slot_hashes(&mut svm, &[draw_slot]);
+
+    let keeper = Keypair::new();
+    svm.airdrop(&keeper.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    let instr = build_draw_winner_instr(&keeper.pubkey(), &pda, 2);
+    send_instr(&mut svm, instr, &keeper).expect("draw should succeed");
+
+    let round = fetch_round(&svm, &pda).expect("round account should exist");
+    assert!(round.draw.is_some(), "draw should be recorded");
+}
+
+#[test]
+fn fails_to_draw_over_an_off_chain_list_if_signer_is_not_authority() {
+    let mut svm = setup();
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    let pda = completed_round(&mut svm, &authority, 0);
+
+    let draw_slot = fetch_round(&svm, &pda).unwrap().draw_slot;
+    set_slot_hashes(&mut svm, &[draw_slot]);
+
+    // With no on-chain participants the count is unchecked, so a keeper could grind it
+    let keeper = Keypair::new();
+    svm.airdrop(&keeper.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    let instr = build_draw_winner_instr(&keeper.pubkey(), &pda, 7);
+    let result = send_instr(&mut svm, instr, &keeper);
+    assert!(result.is_err(), "draw by non-authority should fail");
+    assert_err_logs_contain(&result, "DrawNotAllowed");
+
+    let instr = build_draw_winner_instr(&authority.pubkey(), &pda, 7);
+    send_instr(&mut svm, instr, &authority).expect("draw should succeed");
+
+    let round = fetch_round(&svm, &pda).expect("round account should exist");
+    assert_eq!(round.draw.map(|draw| draw.candidates), Some(7));
+}
+
+#[test]
+fn settles_a_paid_round_with_the_drawn_entrant() {
+    let mut svm = setup();
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    let pda = paid_round(&mut svm, &authority, 0);
+    // The entry order is the candidate list the draw indexes into
+    let entrants: Vec<Keypair> = (0..3)
+        .map(|_| funded_user(&mut svm, &pda, build_enter_round_instr))
+        .collect();
+    complete_ended_round(&mut svm, &pda, &entrants[0]);
+
+    let draw_slot = fetch_round(&svm, &pda).unwrap().draw_slot;
+    set_slot_hashes(&mut svm, &[draw_slot]);
+    let instr = build_draw_winner_instr(&authority.pubkey(), &pda, 3);
+    send_instr(&mut svm, instr, &authority).expect("draw should succeed");
+
+    let draw = fetch_round(&svm, &pda)
+        .unwrap()
+        .draw
+        .expect("draw should be recorded");
+    let winner = &entrants[draw.index as usize];
+    let instr = build_settle_round_instr(&authority.pubkey(), &pda, &winner.pubkey());
+    send_instr(&mut svm, instr, &authority).expect("settle should succeed");
+
+    let round = fetch_round(&svm, &pda).expect("round account should exist");
+    assert_eq!(round.winner, Some(winner.pubkey()));
+    assert_eq!(
+        pot_balance(&svm, &pda),
+        svm.minimum_balance_for_rent_exemption(0)
+    );
+}
+
+#[test]
+fn fails_to_settle_a_drawn_round_with_another_entrant() {
+    let mut svm = setup();
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    let pda = paid_round(&mut svm, &authority, 0);
+    let entrants: Vec<Keypair> = (0..3)
+        .map(|_| funded_user(&mut svm, &pda, build_enter_round_instr))
+        .collect();
+    complete_ended_round(&mut svm, &pda, &entrants[0]);
+
+    let draw_slot = fetch_round(&svm, &pda).unwrap().draw_slot;
+    set_slot_hashes(&mut svm, &[draw_slot]);
+    let instr = build_draw_winner_instr(&authority.pubkey(), &pda, 3);
+    send_instr(&mut svm, instr, &authority).expect("draw should succeed");
+
+    let draw = fetch_round(&svm, &pda)
+        .unwrap()
+        .draw
+        .expect("draw should be recorded");
+    let other = &entrants[(draw.index as usize + 1) % 3];
+    let instr = build_settle_round_instr(&authority.pubkey(), &pda, &other.pubkey());
+    let result = send_instr(&mut svm, instr, &authority);
+    assert!(
+        result.is_err(),
+        "settle with an entrant that was not drawn should fail"
+    );
+    assert_err_logs_contain(&result, "WinnerNotDrawn");
+}
+
+#[test]
+fn draws_from_the_next_slot_if_the_draw_slot_was_skipped() {
+    let mut svm = setup();
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    let pda = completed_round(&mut svm, &authority, 2);
+
+    let draw_slot = fetch_round(&svm, &pda).unwrap().draw_slot;
+    let hashes = set_slot_hashes(&mut svm, &[draw_slot + 3, draw_slot + 1, draw_slot - 1]);
+
+    let instr = build_draw_winner_instr(&authority.pubkey(), &pda, 2);
+    send_instr(&mut svm, instr, &authority).expect("draw should succeed");
+
+    let draw = fetch_round(&svm, &pda)
+        .unwrap()
+        .draw
+        .expect("draw should be recorded");
+    assert_eq!(draw.slot, draw_slot + 1);
+    assert_eq!(draw.slot_hash, hashes[1].to_bytes());
+}
+
+#[test]
+fn fails_to_draw_a_winner_before_the_draw_slot() {
+    let mut svm = setup();
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    let pda = completed_round(&mut svm, &authority, 1);
+
+    let draw_slot = fetch_round(&svm, &pda).unwrap().draw_slot;
+    set_slot_hashes(&mut svm, &[draw_slot - 1, draw_slot - 2]);
+
+    let instr = build_draw_winner_instr(&authority.pubkey(), &pda, 1);
+    let result = send_instr(&mut svm, instr, &authority);
+    assert!(result.is_err(), "draw before the draw slot should fail");
+    assert_err_logs_contain(&result, "DrawSlotNotReached");
+}
+
+#[test]
+fn fails_to_draw_a_winner_once_the_draw_slot_hash_expired() {
+    let mut svm = setup();
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    let pda = completed_round(&mut svm, &authority, 1);
+
+    // The draw slot has dropped out of the window, only later slots are left
+    let draw_slot = fetch_round(&svm, &pda).unwrap().draw_slot;
+    set_slot_hashes(&mut svm, &[draw_slot + 600, draw_slot + 599]);
+
+    let instr = build_draw_winner_instr(&authority.pubkey(), &pda, 1);
+    let result = send_instr(&mut svm, instr, &authority);
+    assert!(
+        result.is_err(),
+        "draw after the draw slot expired should fail"
+    );
+    assert_err_logs_contain(&result, "DrawSlotHashExpired");
+}
+
+#[test]
+fn fails_to_draw_a_winner_if_not_completed() {
+    let mut svm = setup();
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    let pda = active_round(&mut svm, &authority, RoundConfig::default());
+
+    let instr = build_draw_winner_instr(&authority.pubkey(), &pda, 1);
+    let result = send_instr(&mut svm, instr, &authority);
+    assert!(result.is_err(), "draw of an active round should fail");
+    assert_err_logs_contain(&result, "RoundNotYetComplete");
+}
+
+#[test]
+fn fails_to_draw_a_winner_twice() {
+    let mut svm = setup();
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    let pda = completed_round(&mut svm, &authority, 2);
+
+    let draw_slot = fetch_round(&svm, &pda).unwrap().draw_slot;
+    set_slot_hashes(&mut svm, &[draw_slot, draw_slot - 1]);
+
+    let instr = build_draw_winner_instr(&authority.pubkey(), &pda, 2);
+    send_instr(&mut svm, instr, &authority).expect("draw should succeed");
+
+    // LiteSVM rejects identical txns as `AlreadyProcessed`.
+    // Expiring the blockhash forces a new one, making the txns distinct.
+    svm.expire_blockhash();
+
+    let instr = build_draw_winner_instr(&authority.pubkey(), &pda, 2);
+    let result = send_instr(&mut svm, instr, &authority);
+    assert!(result.is_err(), "second draw should fail");
+    assert_err_logs_contain(&result, "WinnerAlreadyDrawn");
+}
+
+#[test]
+fn fails_to_draw_a_winner_with_invalid_candidates() {
+    // Candidates must be non-zero, and match the participant count when there are participants
+    for (participants, candidates, error) in
+        [(0, 0, "InvalidCandidates"), (2, 3, "CandidatesMismatch")]
+    {
+        let mut svm = setup();
+        let authority = Keypair::new();
+        svm.airdrop(&authority.pubkey(), LAMPORTS_PER_SOL).unwrap();
+        let pda = completed_round(&mut svm, &authority, participants);
+
+        let draw_slot = fetch_round(&svm, &pda).unwrap().draw_slot;
+        set_slot_hashes(&mut svm, &[draw_slot, draw_slot - 1]);
+
+        let instr = build_draw_winner_instr(&authority.pubkey(), &pda, candidates);
+        let result = send_instr(&mut svm, instr, &authority);
+        assert!(
+            result.is_err(),
+            "draw with {candidates} candidates should fail"
+        );
+        assert_err_logs_contain(&result, error);
+    }
+}
+
+// Activation bounty
+
+const ACTIVATION_BOUNTY: u64 = LAMPORTS_PER_SOL / 100;
+
+fn round_rent(svm: &LiteSVM) -> u64 {
+    svm.minimum_balance_for_rent_exemption(8 + round::Round::INIT_SPACE)
+}
+
+#[test]
+fn holds_the_activation_bounty_in_the_round_account() {
+    let mut svm = setup();
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    let (pda, _) = scheduled_round(
+        &mut svm,
+        &authority,
+        RoundConfig {
+            activation_bounty: ACTIVATION_BOUNTY,
+            ..Default::default()
+        },
+    );
+
+    let round = fetch_round(&svm, &pda).expect("round account should exist");
+    assert_eq!(round.activation_bounty, ACTIVATION_BOUNTY);
+    assert_eq!(
+        svm.get_balance(&pda).unwrap(),
+        round_rent(&svm) + ACTIVATION_BOUNTY
+    );
+}
+
+#[test]
+fn pays_the_activation_bounty_to_the_activator() {
+    let mut svm = setup();
+    let authority = Keypair::new();
+    let keeper = Keypair::new();
+    svm.airdrop(&authority.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    svm.airdrop(&keeper.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    let (pda, start_slot) = scheduled_round(
+        &mut svm,
+        &authority,
+        RoundConfig {
+            activation_bounty: ACTIVATION_BOUNTY,
+            ..Default::default()
+        },
+    );
+    svm.warp_to_slot(start_slot);
+
+    let instr = build_activate_round_instr(&keeper.pubkey(), &pda);
+    send_instr(&mut svm, instr, &keeper).expect("activate should succeed");
+
+    // Less the fee for the keeper's signature
+    assert_eq!(
+        svm.get_balance(&keeper.pubkey()).unwrap(),
+        LAMPORTS_PER_SOL + ACTIVATION_BOUNTY - 5_000
+    );
+    assert_eq!(svm.get_balance(&pda).unwrap(), round_rent(&svm));
+}
+
+#[test]
+fn returns_the_activation_bounty_when_a_scheduled_round_is_cancelled() {
+    let mut svm = setup();
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    let (pda, _) = scheduled_round(
+        &mut svm,
+        &authority,
+        RoundConfig {
+            activation_bounty: ACTIVATION_BOUNTY,
+            ..Default::default()
+        },
+    );
+    let authority_balance = svm.get_balance(&authority.pubkey()).unwrap();
+
+    let instr = build_cancel_round_instr(&authority.pubkey(), &pda);
+    send_instr(&mut svm, instr, &authority).expect("cancel should succeed");
+
+    assert_eq!(
+        svm.get_balance(&authority.pubkey()).unwrap(),
+        authority_balance + ACTIVATION_BOUNTY - 5_000
+    );
+    assert_eq!(svm.get_balance(&pda).unwrap(), round_rent(&svm));
+}
+
+// Round rescheduling
+
+#[test]
+fn reschedules_a_scheduled_round() {
+    let mut svm = setup();
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    let (pda, start_slot) = scheduled_round(&mut svm, &authority, RoundConfig::default());
+
+    for (reschedule_count, new_start_slot) in [(1, start_slot + 50), (2, start_slot + 5)] {
+        let instr = build_reschedule_round_instr(&authority.pubkey(), &pda, new_start_slot);
+        send_instr(&mut svm, instr, &authority).expect("reschedule should succeed");
+
+        let round = fetch_round(&svm, &pda).expect("round account should exist");
+        assert_eq!(round.start_at, new_start_slot);
+        assert_eq!(round.end_at, new_start_slot + ROUND_LENGTH);
+        assert_eq!(round.reschedule_count, reschedule_count);
+        assert_eq!(round.status, RoundStatus::Scheduled);
+    }
+}
+
+#[test]
+fn activates_a_rescheduled_round_only_from_its_new_start_slot() {
+    let mut svm = setup();
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    let (pda, start_slot) = scheduled_round(&mut svm, &authority, RoundConfig::default());
+
+    let instr = build_reschedule_round_instr(&authority.pubkey(), &pda, start_slot + 50);
+    send_instr(&mut svm, instr, &authority).expect("reschedule should succeed");
+
+    svm.warp_to_slot(start_slot);
+    let instr = build_activate_round_instr(&authority.pubkey(), &pda);
+    let result = send_instr(&mut svm, instr, &authority);
+    assert!(
+        result.is_err(),
+        "activate at the old start slot should fail"
+    );
+    assert_err_logs_contain(&result, "InvalidRoundActivationSlot");
+
+    // LiteSVM rejects identical txns as `AlreadyProcessed`.
+    // Expiring the blockhash forces a new one, making the txns distinct.
+    svm.expire_blockhash();
+
+    svm.warp_to_slot(start_slot + 50);
+    let instr = build_activate_round_instr(&authority.pubkey(), &pda);
+    send_instr(&mut svm, instr, &authority).expect("activate should succeed");
+}
+
+#[test]
+fn fails_to_reschedule_a_round_once_active() {
+    let mut svm = setup();
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    let pda = active_round(&mut svm, &authority, RoundConfig::default());
+
+    let new_start_slot = current_slot(&svm) + 10;
+    let instr = build_reschedule_round_instr(&authority.pubkey(), &pda, new_start_slot);
+    let result = send_instr(&mut svm, instr, &authority);
+    assert!(result.is_err(), "reschedule of an active round should fail");
+    assert_err_logs_contain(&result, "RoundAlreadyActive");
+}
+
+#[test]
+fn fails_to_reschedule_a_round_if_not_after_current_slot() {
+    let mut svm = setup();
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    let (pda, _) = scheduled_round(&mut svm, &authority, RoundConfig::default());
+
+    let instr = build_reschedule_round_instr(&authority.pubkey(), &pda, current_slot(&svm));
+    let result = send_instr(&mut svm, instr, &authority);
+    assert!(
+        result.is_err(),
+        "reschedule to the current slot should fail"
+    );
+    assert_err_logs_contain(&result, "InvalidStartSlot");
+}
+
+#[test]
+fn fails_to_reschedule_a_round_if_signer_is_not_authority() {
+    let mut svm = setup();
+    let authority = Keypair::new();
+    let non_authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    svm.airdrop(&non_authority.pubkey(), LAMPORTS_PER_SOL)
+        .unwrap();
+    let (pda, start_slot) = scheduled_round(&mut svm, &authority, RoundConfig::default());
+
+    let instr = build_reschedule_round_instr(&non_authority.pubkey(), &pda, start_slot + 50);
+    let result = send_instr(&mut svm, instr, &non_authority);
+    assert!(result.is_err(), "reschedule by a non-authority should fail");
+    assert_err_logs_contain(&result, "ConstraintHasOne");
+}
+
+#[test]
+fn reschedule_emits_an_event() {
+    let mut svm = setup();
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    let (pda, start_slot) = scheduled_round(&mut svm, &authority, RoundConfig::default());
+
+    let instr = build_reschedule_round_instr(&authority.pubkey(), &pda, start_slot + 50);
+    let meta = send_instr(&mut svm, instr, &authority).expect("reschedule should succeed");
+
+    let events = log_events::<round::RoundRescheduled>(&meta);
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].round, pda);
+    assert_eq!(events[0].authority, authority.pubkey());
+    assert_eq!(events[0].actor, authority.pubkey());
+    assert_eq!(events[0].slot, current_slot(&svm));
+    assert_eq!(events[0].old_start_at, start_slot);
+    assert_eq!(events[0].new_start_at, start_slot + 50);
+    assert_eq!(events[0].end_at, start_slot + 50 + ROUND_LENGTH);
+    assert_eq!(events[0].reschedule_count, 1);
+}
+
+// Timestamp scheduling
+
+// An arbitrary wall clock time for the tests to start from
+const NOW: i64 = 1_700_000_000;
+
+// Default length of timestamp scheduled test rounds, in seconds
+const ROUND_DURATION: u64 = 3_600;
+
+fn set_unix_timestamp(svm: &mut LiteSVM, unix_timestamp: i64) {
+    let mut clock = svm.get_sysvar::<Clock>();
+    clock.unix_timestamp = unix_timestamp;
+    svm.set_sysvar(&clock);
+}
+
+// Sets the clock to `NOW` and initialises a round starting a minute later, returning its PDA
+// and start time
+fn timed_round(svm: &mut LiteSVM, authority: &Keypair) -> (Pubkey, u64) {
+    set_unix_timestamp(svm, NOW);
+    let (pda, _) = round_pda(&authority.pubkey(), 0);
+    let start_time = NOW as u64 + 60;
+    let instr = build_initialise_configured_round_instr(
+        &authority.pubkey(),
+        &pda,
+        0,
+        start_time,
+        start_time + ROUND_DURATION,
+        &RoundConfig {
+            schedule_mode: ScheduleMode::UnixTimestamp,
+            ..Default::default()
+        },
+    );
+    send_instr(svm, instr, authority).expect("initialise should succeed");
+    (pda, start_time)
+}
+
+// Initialises a timestamp scheduled round and activates it at its start time
+fn active_timed_round(svm: &mut LiteSVM, authority: &Keypair) -> (Pubkey, u64) {
+    let (pda, start_time) = timed_round(svm, authority);
+    set_unix_timestamp(svm, start_time as i64);
+    let instr = build_activate_round_instr(&authority.pubkey(), &pda);
+    send_instr(svm, instr, authority).expect("activate should succeed");
+    (pda, start_time)
+}
+
+#[test]
+fn initialises_a_round_scheduled_by_timestamp() {
+    let mut svm = setup();
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    let (pda, start_time) = timed_round(&mut svm, &authority);
+
+    let round = fetch_round(&svm, &pda).expect("round account should exist");
+    assert_eq!(round.schedule_mode, ScheduleMode::UnixTimestamp);
+    assert_eq!(round.start_at, start_time);
+    assert_eq!(round.end_at, start_time + ROUND_DURATION);
+}
+
+#[test]
+fn fails_to_initialise_a_timestamp_round_if_not_after_current_time() {
+    let mut svm = setup();
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    set_unix_timestamp(&mut svm, NOW);
+    let (pda, _) = round_pda(&authority.pubkey(), 0);
+
+    // A start that is still ahead as a slot is long past as a timestamp
+    let start_slot = current_slot(&svm) + 10;
+    let instr = build_initialise_configured_round_instr(
+        &authority.pubkey(),
+        &pda,
+        0,
+        start_slot,
+        start_slot + ROUND_LENGTH,
+        &RoundConfig {
+            schedule_mode: ScheduleMode::UnixTimestamp,
+            ..Default::default()
+        },
+    );
+    let result = send_instr(&mut svm, instr, &authority);
+    assert!(result.is_err(), "initialise in the past should fail");
+    assert_err_logs_contain(&result, "InvalidStartSlot");
+}
+
+#[test]
+fn activates_a_timestamp_round_at_its_start_time_whatever_the_slot() {
+    let mut svm = setup();
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    let (pda, start_time) = timed_round(&mut svm, &authority);
+
+    // Slots passing alone don't open the round
+    svm.warp_to_slot(current_slot(&svm) + 1_000);
+    set_unix_timestamp(&mut svm, start_time as i64 - 1);
+    let instr = build_activate_round_instr(&authority.pubkey(), &pda);
+    let result = send_instr(&mut svm, instr, &authority);
+    assert!(
+        result.is_err(),
+        "activate before the start time should fail"
+    );
+    assert_err_logs_contain(&result, "InvalidRoundActivationSlot");
+
+    // LiteSVM rejects identical txns as `AlreadyProcessed`.
+    // Expiring the blockhash forces a new one, making the txns distinct.
+    svm.expire_blockhash();
+
+    set_unix_timestamp(&mut svm, start_time as i64);
+    let instr = build_activate_round_instr(&authority.pubkey(), &pda);
+    send_instr(&mut svm, instr, &authority).expect("activate should succeed");
+
+    let round = fetch_round(&svm, &pda).expect("round account should exist");
+    assert_eq!(
+        round.status,
+        RoundStatus::Active {
+            activated_at: current_slot(&svm),
+            activated_by: authority.pubkey(),
+        }
+    );
+}
+
+#[test]
+fn fails_to_activate_a_timestamp_round_after_its_end_time() {
+    let mut svm = setup();
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    let (pda, start_time) = timed_round(&mut svm, &authority);
+
+    set_unix_timestamp(&mut svm, (start_time + ROUND_DURATION + 1) as i64);
+    let instr = build_activate_round_instr(&authority.pubkey(), &pda);
+    let result = send_instr(&mut svm, instr, &authority);
+    assert!(result.is_err(), "activate after the end time should fail");
+    assert_err_logs_contain(&result, "RoundExpired");
+}
+
+#[test]
+fn fails_to_join_a_timestamp_round_after_its_end_time() {
+    let mut svm = setup();
+    let authority = Keypair::new();
+    let user = Keypair::new();
+    svm.airdrop(&authority.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    svm.airdrop(&user.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    let (pda, start_time) = active_timed_round(&mut svm, &authority);
+
+    set_unix_timestamp(&mut svm, (start_time + ROUND_DURATION + 1) as i64);
+    let instr = build_join_round_instr(&user.pubkey(), &pda);
+    let result = send_instr(&mut svm, instr, &user);
+    assert!(result.is_err(), "join after the end time should fail");
+    assert_err_logs_contain(&result, "RoundExpired");
+}
+
+#[test]
+fn non_authority_user_completes_a_timestamp_round_after_its_end_time() {
+    let mut svm = setup();
+    let authority = Keypair::new();
+    let user = Keypair::new();
+    svm.airdrop(&authority.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    svm.airdrop(&user.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    let (pda, start_time) = active_timed_round(&mut svm, &authority);
+
+    set_unix_timestamp(&mut svm, (start_time + ROUND_DURATION) as i64);
+    let instr = build_complete_round_instr(&user.pubkey(), &pda);
+    let result = send_instr(&mut svm, instr, &user);
+    assert!(result.is_err(), "complete at the end time should fail");
+    assert_err_logs_contain(&result, "RoundNotYetEnded");
+
+    // LiteSVM rejects identical txns as `AlreadyProcessed`.
+    // Expiring the blockhash forces a new one, making the txns distinct.
+    svm.expire_blockhash();
+
+    set_unix_timestamp(&mut svm, (start_time + ROUND_DURATION + 1) as i64);
+    let instr = build_complete_round_instr(&user.pubkey(), &pda);
+    send_instr(&mut svm, instr, &user).expect("complete should succeed");
+
+    // The draw uses the slot after completion, as the end time maps to no slot
+    let round = fetch_round(&svm, &pda).expect("round account should exist");
+    assert_eq!(stage_of(&round.status), Stage::Completed);
+    assert_eq!(round.draw_slot, current_slot(&svm) + 1);
+}
+
+#[test]
+fn reschedules_a_timestamp_round_to_a_later_time() {
+    let mut svm = setup();
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    let (pda, start_time) = timed_round(&mut svm, &authority);
+
+    let instr = build_reschedule_round_instr(&authority.pubkey(), &pda, NOW as u64);
+    let result = send_instr(&mut svm, instr, &authority);
+    assert!(
+        result.is_err(),
+        "reschedule to the current time should fail"
+    );
+    assert_err_logs_contain(&result, "InvalidStartSlot");
+
+    let instr = build_reschedule_round_instr(&authority.pubkey(), &pda, start_time + 600);
+    send_instr(&mut svm, instr, &authority).expect("reschedule should succeed");
+
+    let round = fetch_round(&svm, &pda).expect("round account should exist");
+    assert_eq!(round.start_at, start_time + 600);
+    assert_eq!(round.end_at, start_time + 600 + ROUND_DURATION);
+}
+
+// Round closing
+
+#[test]
+fn closes_finished_rounds() {
+    for stage in [Stage::Completed, Stage::Cancelled] {
+        let mut svm = setup();
+        let authority = Keypair::new();
+        svm.airdrop(&authority.pubkey(), LAMPORTS_PER_SOL).unwrap();
+        let pda = round_at_stage(&mut svm, &authority, stage);
+        let authority_balance = svm.get_balance(&authority.pubkey()).unwrap();
+
+        let instr = build_close_round_instr(&authority.pubkey(), &pda);
+        send_instr(&mut svm, instr, &authority).expect("close should succeed");
+
+        assert!(fetch_round(&svm, &pda).is_none());
+        // Less the fee for the authority's signature
+        assert_eq!(
+            svm.get_balance(&authority.pubkey()).unwrap(),
+            authority_balance + round_rent(&svm) - 5_000
+        );
+    }
+}
+
+#[test]
+fn initialises_the_next_round_after_closing_one() {
+    let mut svm = setup();
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    let first_pda = round_at_stage(&mut svm, &authority, Stage::Completed);
+
+    let instr = build_close_round_instr(&authority.pubkey(), &first_pda);
+    send_instr(&mut svm, instr, &authority).expect("close should succeed");
+
+    // The closed round's id is spent, reinitialising it fails even though its PDA is free
+    let start_slot = current_slot(&svm) + 10;
+    let instr = build_initialise_round_instr(
+        &authority.pubkey(),
+        &first_pda,
+        0,
+        start_slot,
+        start_slot + ROUND_LENGTH,
+    );
+    let result = send_instr(&mut svm, instr, &authority);
+    assert!(
+        result.is_err(),
+        "initialise of a closed round id should fail"
+    );
+    assert_err_logs_contain(&result, "InvalidRoundId");
+
+    let (second_pda, _) = round_pda(&authority.pubkey(), 1);
+    let instr = build_initialise_round_instr(
+        &authority.pubkey(),
+        &second_pda,
+        1,
+        start_slot,
+        start_slot + ROUND_LENGTH,
+    );
+    send_instr(&mut svm, instr, &authority).expect("initialise should succeed");
+
+    let round = fetch_round(&svm, &second_pda).expect("round account should exist");
+    assert_eq!(round.round_id, 1);
+    assert_eq!(round.status, RoundStatus::Scheduled);
+}
+
+#[test]
+fn fails_to_close_a_round_before_it_finishes() {
+    for stage in [Stage::Scheduled, Stage::Active] {
+        let mut svm = setup();
+        let authority = Keypair::new();
+        svm.airdrop(&authority.pubkey(), LAMPORTS_PER_SOL).unwrap();
+        let pda = round_at_stage(&mut svm, &authority, stage);
+
+        let instr = build_close_round_instr(&authority.pubkey(), &pda);
+        let result = send_instr(&mut svm, instr, &authority);
+        assert!(result.is_err(), "close of an unfinished round should fail");
+        assert_err_logs_contain(&result, "RoundNotYetComplete");
+    }
+}
+
+#[test]
+fn fails_to_close_a_round_if_signer_is_not_authority() {
+    let mut svm = setup();
+    let authority = Keypair::new();
+    let non_authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    svm.airdrop(&non_authority.pubkey(), LAMPORTS_PER_SOL)
+        .unwrap();
+    let pda = round_at_stage(&mut svm, &authority, Stage::Completed);
+
+    let instr = build_close_round_instr(&non_authority.pubkey(), &pda);
+    let result = send_instr(&mut svm, instr, &non_authority);
+    assert!(result.is_err(), "close by a non-authority should fail");
+    assert_err_logs_contain(&result, "ConstraintHasOne");
+}
+
+#[test]
+fn closes_a_paid_round_only_once_its_entries_are_refunded() {
+    let mut svm = setup();
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    let pda = paid_round(&mut svm, &authority, 0);
+    let user = funded_user(&mut svm, &pda, build_enter_round_instr);
+
+    let instr = build_cancel_round_instr(&authority.pubkey(), &pda);
+    send_instr(&mut svm, instr, &authority).expect("cancel should succeed");
+
+    let instr = build_close_round_instr(&authority.pubkey(), &pda);
+    let result = send_instr(&mut svm, instr, &authority);
+    assert!(
+        result.is_err(),
+        "close with entries outstanding should fail"
+    );
+    assert_err_logs_contain(&result, "PotNotEmpty");
+
+    let instr = build_refund_entry_instr(&user.pubkey(), &pda);
+    send_instr(&mut svm, instr, &user).expect("refund should succeed");
+
+    // LiteSVM rejects identical txns as `AlreadyProcessed`.
+    // Expiring the blockhash forces a new one, making the txns distinct.
+    svm.expire_blockhash();
+
+    let instr = build_close_round_instr(&authority.pubkey(), &pda);
+    send_instr(&mut svm, instr, &authority).expect("close should succeed");
+
+    assert_eq!(pot_balance(&svm, &pda), 0);
+}
+
+#[test]
+fn closes_a_refunded_round_whatever_is_sent_to_its_pot() {
+    let mut svm = setup();
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    let pda = paid_round(&mut svm, &authority, 0);
+    let user = funded_user(&mut svm, &pda, build_enter_round_instr);
+
+    let instr = build_cancel_round_instr(&authority.pubkey(), &pda);
+    send_instr(&mut svm, instr, &authority).expect("cancel should succeed");
+    let instr = build_refund_entry_instr(&user.pubkey(), &pda);
+    send_instr(&mut svm, instr, &user).expect("refund should succeed");
+
+    // Anyone can send lamports to the pot, which mustn't hold up closing the round
+    let (pot_pda, _) = pot_pda(&pda);
+    svm.airdrop(&pot_pda, 1).unwrap();
+    let pot_lamports = pot_balance(&svm, &pda);
+    let authority_balance = svm.get_balance(&authority.pubkey()).unwrap();
+
+    let instr = build_close_round_instr(&authority.pubkey(), &pda);
+    send_instr(&mut svm, instr, &authority).expect("close should succeed");
+
+    assert_eq!(pot_balance(&svm, &pda), 0);
+    // Less the fee for the authority's signature
+    assert_eq!(
+        svm.get_balance(&authority.pubkey()).unwrap(),
+        authority_balance + round_rent(&svm) + pot_lamports - 5_000
+    );
+}
+
+#[test]
+fn closes_a_scored_round_with_its_leaderboard() {
+    let mut svm = setup();
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    let pda = active_round(&mut svm, &authority, RoundConfig::default());
+    let user = funded_user(&mut svm, &pda, build_join_round_instr);
+    submit_score(&mut svm, &authority, &pda, &user.pubkey(), 10);
+
+    let instr = build_complete_round_instr(&authority.pubkey(), &pda);
+    send_instr(&mut svm, instr, &authority).expect("complete should succeed");
+
+    let (leaderboard_pda, _) = leaderboard_pda(&pda);
+    let leaderboard_rent = svm.get_balance(&leaderboard_pda).unwrap();
+    let authority_balance = svm.get_balance(&authority.pubkey()).unwrap();
+
+    let instr = build_close_round_instr(&authority.pubkey(), &pda);
+    send_instr(&mut svm, instr, &authority).expect("close should succeed");
+
+    assert!(fetch_leaderboard(&svm, &leaderboard_pda).is_none());
+    // Less the fee for the authority's signature
+    assert_eq!(
+        svm.get_balance(&authority.pubkey()).unwrap(),
+        authority_balance + round_rent(&svm) + leaderboard_rent - 5_000
+    );
+}
+
+#[test]
+fn users_close_their_accounts_once_the_round_is_closed() {
+    let mut svm = setup();
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    let (pda, _) = sealed_round(&mut svm, &authority);
+    let user = committed_user(&mut svm, &pda, 1);
+    let (participant_pda, _) = participant_pda(&pda, &user.pubkey());
+    let (submission_pda, _) = submission_pda(&pda, &user.pubkey());
+
+    let instr = build_complete_round_instr(&authority.pubkey(), &pda);
+    send_instr(&mut svm, instr, &authority).expect("complete should succeed");
+
+    let instr =
+        build_close_user_account_instr("close_participant", &user.pubkey(), &pda, &participant_pda);
+    let result = send_instr(&mut svm, instr, &user);
+    assert!(
+        result.is_err(),
+        "close before the round is closed should fail"
+    );
+    assert_err_logs_contain(&result, "RoundNotClosed");
+
+    let instr = build_close_round_instr(&authority.pubkey(), &pda);
+    send_instr(&mut svm, instr, &authority).expect("close should succeed");
+
+    let accounts_rent =
+        svm.get_balance(&participant_pda).unwrap() + svm.get_balance(&submission_pda).unwrap();
+    let user_balance = svm.get_balance(&user.pubkey()).unwrap();
+
+    // LiteSVM rejects identical txns as `AlreadyProcessed`.
+    // Expiring the blockhash forces a new one, making the txns distinct.
+    svm.expire_blockhash();
+
+    let instr =
+        build_close_user_account_instr("close_participant", &user.pubkey(), &pda, &participant_pda);
+    send_instr(&mut svm, instr, &user).expect("close participant should succeed");
+    let instr =
+        build_close_user_account_instr("close_submission", &user.pubkey(), &pda, &submission_pda);
+    send_instr(&mut svm, instr, &user).expect("close submission should succeed");
+
+    assert!(fetch_participant(&svm, &participant_pda).is_none());
+    assert!(fetch_submission(&svm, &submission_pda).is_none());
+    // Less the fees for the user's two signatures
+    assert_eq!(
+        svm.get_balance(&user.pubkey()).unwrap(),
+        user_balance + accounts_rent - 10_000
+    );
+}
+
+#[test]
+fn users_close_their_interest_once_the_round_is_closed() {
+    let mut svm = setup();
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    let (pda, _) = scheduled_round(
+        &mut svm,
+        &authority,
+        RoundConfig {
+            min_participants: 2,
+            ..Default::default()
+        },
+    );
+    let user = funded_user(&mut svm, &pda, build_signal_interest_instr);
+    let (interest_pda, _) = interest_pda(&pda, &user.pubkey());
+
+    let instr = build_cancel_round_instr(&authority.pubkey(), &pda);
+    send_instr(&mut svm, instr, &authority).expect("cancel should succeed");
+    let instr = build_close_round_instr(&authority.pubkey(), &pda);
+    send_instr(&mut svm, instr, &authority).expect("close should succeed");
+
+    let instr =
+        build_close_user_account_instr("close_interest", &user.pubkey(), &pda, &interest_pda);
+    send_instr(&mut svm, instr, &user).expect("close interest should succeed");
+
+    assert!(fetch_interest(&svm, &interest_pda).is_none());
+}
+
+// Commit-reveal submissions
+
+// Slots from a sealed round's start to its reveal start
+const COMMIT_PHASE: u64 = 50;
+
+const SALT: [u8; 32] = [7; 32];
+
+// Initialises a round taking submissions and activates it, returning its PDA and reveal start slot
+fn sealed_round(svm: &mut LiteSVM, authority: &Keypair) -> (Pubkey, u64) {
+    let start_slot = current_slot(svm) + 10;
+    let reveal_start_slot = start_slot + COMMIT_PHASE;
+    let pda = active_round(
+        svm,
+        authority,
+        RoundConfig {
+            reveal_start_at: Some(reveal_start_slot),
+            ..Default::default()
+        },
+    );
+    (pda, reveal_start_slot)
+}
+
+// Funds a new user, joins them to the round and commits `value`
+fn committed_user(svm: &mut LiteSVM, round_pda: &Pubkey, value: u64) -> Keypair {
+    let user = funded_user(svm, round_pda, build_join_round_instr);
+    let commitment = round::submission_commitment(round_pda, &user.pubkey(), value, &SALT);
+    let instr = build_commit_instr(&user.pubkey(), round_pda, commitment);
+    send_instr(svm, instr, &user).expect("commit should succeed");
+    user
+}
+
+#[test]
+fn commits_and_reveals_submissions() {
+    let mut svm = setup();
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    let (pda, reveal_start_slot) = sealed_round(&mut svm, &authority);
+    let users: Vec<Keypair> = (0..2)
+        .map(|value| committed_user(&mut svm, &pda, value))
+        .collect();
+
+    let (submission_pda, _) = submission_pda(&pda, &users[0].pubkey());
+    let submission = fetch_submission(&svm, &submission_pda).expect("submission should exist");
+    assert_eq!(submission.round, pda);
+    assert_eq!(submission.user, users[0].pubkey());
+    assert_eq!(submission.committed_at, current_slot(&svm));
+    assert_eq!(submission.value, None);
+
+    svm.warp_to_slot(reveal_start_slot);
+    for (value, user) in users.iter().enumerate() {
+        let instr = build_reveal_instr(&user.pubkey(), &pda, value as u64, SALT);
+        send_instr(&mut svm, instr, user).expect("reveal should succeed");
+    }
+
+    let submission = fetch_submission(&svm, &submission_pda).expect("submission should exist");
+    assert_eq!(submission.value, Some(0));
+    assert_eq!(submission.revealed_at, Some(reveal_start_slot));
+    let round = fetch_round(&svm, &pda).expect("round account should exist");
+    assert_eq!(round.reveal_count, 2);
+}
+
+#[test]
+fn fails_to_reveal_a_value_that_does_not_match_the_commitment() {
+    let mut svm = setup();
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    let (pda, reveal_start_slot) = sealed_round(&mut svm, &authority);
+    let user = committed_user(&mut svm, &pda, 42);
+    svm.warp_to_slot(reveal_start_slot);
+
+    for (value, salt) in [(41, SALT), (42, [8; 32])] {
+        let instr = build_reveal_instr(&user.pubkey(), &pda, value, salt);
+        let result = send_instr(&mut svm, instr, &user);
+        assert!(result.is_err(), "mismatched reveal should fail");
+        assert_err_logs_contain(&result, "CommitmentMismatch");
+    }
+
+    let round = fetch_round(&svm, &pda).expect("round account should exist");
+    assert_eq!(round.reveal_count, 0);
+}
+
+#[test]
+fn fails_to_reveal_a_copied_commitment() {
+    let mut svm = setup();
+    let authority = Keypair::new();
+    let copier = Keypair::new();
+    svm.airdrop(&authority.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    svm.airdrop(&copier.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    let (pda, reveal_start_slot) = sealed_round(&mut svm, &authority);
+    let user = committed_user(&mut svm, &pda, 42);
+
+    // The copier commits the user's commitment, then reveals the user's value and salt
+    let instr = build_join_round_instr(&copier.pubkey(), &pda);
+    send_instr(&mut svm, instr, &copier).expect("join should succeed");
+    let (submission_pda, _) = submission_pda(&pda, &user.pubkey());
+    let commitment = fetch_submission(&svm, &submission_pda).unwrap().commitment;
+    let instr = build_commit_instr(&copier.pubkey(), &pda, commitment);
+    send_instr(&mut svm, instr, &copier).expect("commit should succeed");
+
+    svm.warp_to_slot(reveal_start_slot);
+    let instr = build_reveal_instr(&copier.pubkey(), &pda, 42, SALT);
+    let result = send_instr(&mut svm, instr, &copier);
+    assert!(result.is_err(), "reveal of a copied commitment should fail");
+    assert_err_logs_contain(&result, "CommitmentMismatch");
+}
+
+#[test]
+fn fails_to_reveal_twice() {
+    let mut svm = setup();
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    let (pda, reveal_start_slot) = sealed_round(&mut svm, &authority);
+    let user = committed_user(&mut svm, &pda, 42);
+    svm.warp_to_slot(reveal_start_slot);
+
+    let instr = build_reveal_instr(&user.pubkey(), &pda, 42, SALT);
+    send_instr(&mut svm, instr, &user).expect("reveal should succeed");
+
+    // LiteSVM rejects identical txns as `AlreadyProcessed`.
+    // Expiring the blockhash forces a new one, making the txns distinct.
+    svm.expire_blockhash();
+
+    let instr = build_reveal_instr(&user.pubkey(), &pda, 42, SALT);
+    let result = send_instr(&mut svm, instr, &user);
+    assert!(result.is_err(), "second reveal should fail");
+    assert_err_logs_contain(&result, "AlreadyRevealed");
+}
+
+#[test]
+fn fails_to_reveal_outside_the_reveal_phase() {
+    // Just before the reveal start slot, or just after the end slot
+    for (late, error) in [(false, "RevealNotStarted"), (true, "RoundExpired")] {
+        let mut svm = setup();
+        let authority = Keypair::new();
+        svm.airdrop(&authority.pubkey(), LAMPORTS_PER_SOL).unwrap();
+        let (pda, reveal_start_slot) = sealed_round(&mut svm, &authority);
+        let user = committed_user(&mut svm, &pda, 42);
+        let end_slot = fetch_round(&svm, &pda).unwrap().end_at;
+
+        svm.warp_to_slot(if late {
+            end_slot + 1
+        } else {
+            reveal_start_slot - 1
+        });
+        let instr = build_reveal_instr(&user.pubkey(), &pda, 42, SALT);
+        let result = send_instr(&mut svm, instr, &user);
+        assert!(result.is_err(), "reveal outside the phase should fail");
+        assert_err_logs_contain(&result, error);
+    }
+}
+
+#[test]
+fn fails_to_commit_once_reveals_start() {
+    let mut svm = setup();
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    let (pda, reveal_start_slot) = sealed_round(&mut svm, &authority);
+    let user = funded_user(&mut svm, &pda, build_join_round_instr);
+
+    svm.warp_to_slot(reveal_start_slot);
+    let commitment = round::submission_commitment(&pda, &user.pubkey(), 42, &SALT);
+    let instr = build_commit_instr(&user.pubkey(), &pda, commitment);
+    let result = send_instr(&mut svm, instr, &user);
+    assert!(result.is_err(), "commit at the reveal start should fail");
+    assert_err_logs_contain(&result, "CommitPhaseOver");
+}
+
+#[test]
+fn fails_to_commit_without_joining() {
+    let mut svm = setup();
+    let authority = Keypair::new();
+    let user = Keypair::new();
+    svm.airdrop(&authority.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    svm.airdrop(&user.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    let (pda, _) = sealed_round(&mut svm, &authority);
+
+    let commitment = round::submission_commitment(&pda, &user.pubkey(), 42, &SALT);
+    let instr = build_commit_instr(&user.pubkey(), &pda, commitment);
+    let result = send_instr(&mut svm, instr, &user);
+    assert!(result.is_err(), "commit by a non-participant should fail");
+    assert_err_logs_contain(&result, "AccountNotInitialized");
+}
+
+#[test]
+fn fails_to_commit_to_a_round_without_submissions() {
+    let mut svm = setup();
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    let pda = active_round(&mut svm, &authority, RoundConfig::default());
+    let user = funded_user(&mut svm, &pda, build_join_round_instr);
+
+    let commitment = round::submission_commitment(&pda, &user.pubkey(), 42, &SALT);
+    let instr = build_commit_instr(&user.pubkey(), &pda, commitment);
+    let result = send_instr(&mut svm, instr, &user);
+    assert!(
+        result.is_err(),
+        "commit to a round without submissions should fail"
+    );
+    assert_err_logs_contain(&result, "SubmissionsDisabled");
+}
+
+#[test]
+fn fails_to_initialise_a_round_with_the_reveal_start_outside_it() {
+    let start_slot = 10;
+    for reveal_start_slot in [start_slot, start_slot + ROUND_LENGTH + 1] {
+        let mut svm = setup();
+        let authority = Keypair::new();
+        svm.airdrop(&authority.pubkey(), LAMPORTS_PER_SOL).unwrap();
+        let (pda, _) = round_pda(&authority.pubkey(), 0);
+
+        let instr = build_initialise_configured_round_instr(
+            &authority.pubkey(),
+            &pda,
+            0,
+            start_slot,
+            start_slot + ROUND_LENGTH,
+            &RoundConfig {
+                reveal_start_at: Some(reveal_start_slot),
+                ..Default::default()
+            },
+        );
+        let result = send_instr(&mut svm, instr, &authority);
+        assert!(
+            result.is_err(),
+            "reveal start slot {reveal_start_slot} should fail"
+        );
+        assert_err_logs_contain(&result, "InvalidRevealStartSlot");
+    }
+}
+
+#[test]
+fn reschedules_the_reveal_start_with_the_round() {
+    let mut svm = setup();
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    let (pda, _) = round_pda(&authority.pubkey(), 0);
+    let start_slot = current_slot(&svm) + 10;
+    let instr = build_initialise_configured_round_instr(
+        &authority.pubkey(),
+        &pda,
+        0,
+        start_slot,
+        start_slot + ROUND_LENGTH,
+        &RoundConfig {
+            reveal_start_at: Some(start_slot + COMMIT_PHASE),
+            ..Default::default()
+        },
+    );
+    send_instr(&mut svm, instr, &authority).expect("initialise should succeed");
+
+    let instr = build_reschedule_round_instr(&authority.pubkey(), &pda, start_slot + 30);
+    send_instr(&mut svm, instr, &authority).expect("reschedule should succeed");
+
+    let round = fetch_round(&svm, &pda).expect("round account should exist");
+    assert_eq!(round.reveal_start_at, Some(start_slot + 30 + COMMIT_PHASE));
+}
+
+// Scoring
+
+fn submit_score(
+    svm: &mut LiteSVM,
+    scorer: &Keypair,
+    round_pda: &Pubkey,
+    user: &Pubkey,
+    score: u64,
+) {
+    let instr = build_submit_score_instr(&scorer.pubkey(), round_pda, user, score);
+    send_instr(svm, instr, scorer).expect("submit score should succeed");
+    // LiteSVM rejects identical txns as `AlreadyProcessed`.
+    // Expiring the blockhash forces a new one, making the txns distinct.
+    svm.expire_blockhash();
+}
+
+fn standings(svm: &LiteSVM, round_pda: &Pubkey) -> Vec<(Pubkey, u64)> {
+    let leaderboard =
+        fetch_leaderboard(svm, &leaderboard_pda(round_pda).0).expect("leaderboard should exist");
+    leaderboard
+        .entries
+        .iter()
+        .map(|entry| (entry.user, entry.score))
+        .collect()
+}
+
+#[test]
+fn ranks_scores_on_the_leaderboard() {
+    let mut svm = setup();
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    let pda = active_round(&mut svm, &authority, RoundConfig::default());
+    let users: Vec<Keypair> = (0..3)
+        .map(|_| funded_user(&mut svm, &pda, build_join_round_instr))
+        .collect();
+
+    for (user, score) in users.iter().zip([10, 30, 20]) {
+        submit_score(&mut svm, &authority, &pda, &user.pubkey(), score);
+    }
+
+    assert_eq!(
+        standings(&svm, &pda),
+        vec![
+            (users[1].pubkey(), 30),
+            (users[2].pubkey(), 20),
+            (users[0].pubkey(), 10),
+        ]
+    );
+    let leaderboard =
+        fetch_leaderboard(&svm, &leaderboard_pda(&pda).0).expect("leaderboard should exist");
+    assert_eq!(leaderboard.round, pda);
+    assert_eq!(leaderboard.frozen_at, None);
+    assert_eq!(leaderboard.entries[0].scored_at, current_slot(&svm));
+}
+
+#[test]
+fn keeps_each_participants_best_score() {
+    let mut svm = setup();
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    let pda = active_round(&mut svm, &authority, RoundConfig::default());
+    let user = funded_user(&mut svm, &pda, build_join_round_instr);
+    let rival = funded_user(&mut svm, &pda, build_join_round_instr);
+
+    submit_score(&mut svm, &authority, &pda, &user.pubkey(), 10);
+    submit_score(&mut svm, &authority, &pda, &rival.pubkey(), 15);
+    submit_score(&mut svm, &authority, &pda, &user.pubkey(), 5);
+    assert_eq!(
+        standings(&svm, &pda),
+        vec![(rival.pubkey(), 15), (user.pubkey(), 10)]
+    );
+
+    submit_score(&mut svm, &authority, &pda, &user.pubkey(), 20);
+    assert_eq!(
+        standings(&svm, &pda),
+        vec![(user.pubkey(), 20), (rival.pubkey(), 15)]
+    );
+}
+
+#[test]
+fn ties_rank_in_the_order_they_were_reached() {
+    let mut svm = setup();
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    let pda = active_round(&mut svm, &authority, RoundConfig::default());
+    let users: Vec<Keypair> = (0..3)
+        .map(|_| funded_user(&mut svm, &pda, build_join_round_instr))
+        .collect();
+
+    submit_score(&mut svm, &authority, &pda, &users[0].pubkey(), 20);
+    submit_score(&mut svm, &authority, &pda, &users[1].pubkey(), 10);
+    // Raising a score to a tie places it behind the score that got there first
+    submit_score(&mut svm, &authority, &pda, &users[1].pubkey(), 20);
+    submit_score(&mut svm, &authority, &pda, &users[2].pubkey(), 20);
+
+    assert_eq!(
+        standings(&svm, &pda),
+        vec![
+            (users[0].pubkey(), 20),
+            (users[1].pubkey(), 20),
+            (users[2].pubkey(), 20),
+        ]
+    );
+}
+
+#[test]
+fn keeps_only_the_top_scores_once_full() {
+    let mut svm = setup();
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    let pda = active_round(&mut svm, &authority, RoundConfig::default());
+    let users: Vec<Keypair> = (0..round::LEADERBOARD_CAPACITY + 2)
+        .map(|_| funded_user(&mut svm, &pda, build_join_round_instr))
+        .collect();
+    let (ranked, unranked) = users.split_at(round::LEADERBOARD_CAPACITY);
+
+    for (score, user) in (1..).zip(ranked) {
+        submit_score(&mut svm, &authority, &pda, &user.pubkey(), score);
+    }
+    // A tie with the lowest entry of a full leaderboard doesn't displace it
+    submit_score(&mut svm, &authority, &pda, &unranked[0].pubkey(), 1);
+    assert_eq!(standings(&svm, &pda).len(), round::LEADERBOARD_CAPACITY);
+    assert_eq!(standings(&svm, &pda).last(), Some(&(ranked[0].pubkey(), 1)));
+
+    submit_score(&mut svm, &authority, &pda, &unranked[1].pubkey(), 5);
+    let standings = standings(&svm, &pda);
+    assert_eq!(standings.len(), round::LEADERBOARD_CAPACITY);
+    assert!(!standings
+        .iter()
+        .any(|(user, _)| *user == ranked[0].pubkey()));
+    assert!(!standings
+        .iter()
+        .any(|(user, _)| *user == unranked[0].pubkey()));
+    assert_eq!(standings.last(), Some(&(ranked[1].pubkey(), 2)));
+    // Behind the participant who reached 5 first
+    let rank = standings
+        .iter()
+        .position(|(user, _)| *user == unranked[1].pubkey())
+        .expect("new score should rank");
+    assert_eq!(standings[rank - 1], (ranked[4].pubkey(), 5));
+}
+
+#[test]
+fn designated_scorer_submits_scores() {
+    let mut svm = setup();
+    let authority = Keypair::new();
+    let scorer = Keypair::new();
+    svm.airdrop(&authority.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    svm.airdrop(&scorer.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    let pda = active_round(
+        &mut svm,
+        &authority,
+        RoundConfig {
+            scorer: Some(scorer.pubkey()),
+            ..Default::default()
+        },
+    );
+    let user = funded_user(&mut svm, &pda, build_join_round_instr);
+
+    submit_score(&mut svm, &scorer, &pda, &user.pubkey(), 10);
+
+    let round = fetch_round(&svm, &pda).expect("round account should exist");
+    assert_eq!(round.scorer, Some(scorer.pubkey()));
+    assert_eq!(standings(&svm, &pda), vec![(user.pubkey(), 10)]);
+}
+
+#[test]
+fn fails_to_score_if_signer_is_not_authority_or_scorer() {
+    let mut svm = setup();
+    let authority = Keypair::new();
+    let scorer = Keypair::new();
+    let other = Keypair::new();
+    svm.airdrop(&authority.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    svm.airdrop(&other.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    let pda = active_round(
+        &mut svm,
+        &authority,
+        RoundConfig {
+            scorer: Some(scorer.pubkey()),
+            ..Default::default()
+        },
+    );
+    let user = funded_user(&mut svm, &pda, build_join_round_instr);
+
+    let instr = build_submit_score_instr(&other.pubkey(), &pda, &user.pubkey(), 10);
+    let result = send_instr(&mut svm, instr, &other);
+    assert!(result.is_err(), "submit score by a stranger should fail");
+    assert_err_logs_contain(&result, "ScorerNotAllowed");
+}
+
+#[test]
+fn fails_to_score_a_user_who_has_not_joined() {
+    let mut svm = setup();
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    let pda = active_round(&mut svm, &authority, RoundConfig::default());
+
+    let instr = build_submit_score_instr(&authority.pubkey(), &pda, &Pubkey::new_unique(), 10);
+    let result = send_instr(&mut svm, instr, &authority);
+    assert!(
+        result.is_err(),
+        "submit score for a non-participant should fail"
+    );
+    assert_err_logs_contain(&result, "AccountNotInitialized");
+}
+
+#[test]
+fn fails_to_score_a_round_that_is_not_active() {
+    let mut svm = setup();
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    let pda = round_at_stage(&mut svm, &authority, Stage::Scheduled);
+    // Participants can only join active rounds, write one in directly
+    let user = Pubkey::new_unique();
+    let participant = round::Participant {
+        round: pda,
+        user,
+        joined_at: 0,
+        entry_index: 0,
+    };
+    let mut data = round::Participant::DISCRIMINATOR.to_vec();
+    participant.serialize(&mut data).unwrap();
+    svm.set_account(
+        participant_pda(&pda, &user).0,
+        Account {
+            lamports: svm.minimum_balance_for_rent_exemption(data.len()),
+            data,
+            owner: round::ID,
+            executable: false,
+            rent_epoch: 0,
+        },
+    )
+    .unwrap();
+
+    let instr = build_submit_score_instr(&authority.pubkey(), &pda, &user, 10);
+    let result = send_instr(&mut svm, instr, &authority);
+    assert!(
+        result.is_err(),
+        "submit score before activation should fail"
+    );
+    assert_err_logs_contain(&result, "RoundNotYetActive");
+}
+
+#[test]
+fn freezes_the_leaderboard_when_the_round_completes() {
+    let mut svm = setup();
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    let pda = active_round(&mut svm, &authority, RoundConfig::default());
+    let user = funded_user(&mut svm, &pda, build_join_round_instr);
+    submit_score(&mut svm, &authority, &pda, &user.pubkey(), 10);
+
+    let instr = build_complete_round_instr(&authority.pubkey(), &pda);
+    send_instr(&mut svm, instr, &authority).expect("complete should succeed");
+
+    let leaderboard =
+        fetch_leaderboard(&svm, &leaderboard_pda(&pda).0).expect("leaderboard should exist");
+    assert_eq!(leaderboard.frozen_at, Some(current_slot(&svm)));
+    assert_eq!(standings(&svm, &pda), vec![(user.pubkey(), 10)]);
+
+    let instr = build_submit_score_instr(&authority.pubkey(), &pda, &user.pubkey(), 20);
+    let result = send_instr(&mut svm, instr, &authority);
+    assert!(result.is_err(), "submit score after completion should fail");
+    assert_err_logs_contain(&result, "LeaderboardFrozen");
 }
 
-// Round initialisation
+// Participation threshold
 
 #[test]
-fn initialises_a_round() {
+fn users_signal_interest_before_the_start_slot() {
     let mut svm = setup();
     let authority = Keypair::new();
     svm.airdrop(&authority.pubkey(), LAMPORTS_PER_SOL).unwrap();
-    let (pda, _) = round_pda(&authority.pubkey());
+    let (pda, _) = scheduled_round(
+        &mut svm,
+        &authority,
+        RoundConfig {
+            min_participants: 2,
+            ..Default::default()
+        },
+    );
 
-    let start_slot = current_slot(&svm) + 10;
-    let instr = build_initialise_round_instr(&authority.pubkey(), &pda, start_slot);
-    send_instr(&mut svm, instr, &authority).expect("initialise should succeed");
+    let users: Vec<Keypair> = (0..2)
+        .map(|_| funded_user(&mut svm, &pda, build_signal_interest_instr))
+        .collect();
 
     let round = fetch_round(&svm, &pda).expect("round account should exist");
-    assert_eq!(round.authority, authority.pubkey());
-    assert_eq!(round.start_slot, start_slot);
+    assert_eq!(round.min_participants, 2);
+    assert_eq!(round.interest_count, 2);
+    let (interest_pda, _) = interest_pda(&pda, &users[0].pubkey());
+    let interest = fetch_interest(&svm, &interest_pda).expect("interest should exist");
+    assert_eq!(interest.round, pda);
+    assert_eq!(interest.user, users[0].pubkey());
+    assert_eq!(interest.signalled_at, current_slot(&svm));
 }
 
 #[test]
-fn fails_to_initialise_a_round_if_already_initialised() {
+fn fails_to_signal_interest_twice() {
     let mut svm = setup();
     let authority = Keypair::new();
     svm.airdrop(&authority.pubkey(), LAMPORTS_PER_SOL).unwrap();
-    let (pda, _) = round_pda(&authority.pubkey());
+    let (pda, _) = scheduled_round(
+        &mut svm,
+        &authority,
+        RoundConfig {
+            min_participants: 2,
+            ..Default::default()
+        },
+    );
+    let user = funded_user(&mut svm, &pda, build_signal_interest_instr);
 
-    let start_slot = current_slot(&svm) + 10;
-    let instr = build_initialise_round_instr(&authority.pubkey(), &pda, start_slot);
-    send_instr(&mut svm, instr, &authority).expect("first initialise should succeed");
+    // LiteSVM rejects identical txns as `AlreadyProcessed`.
+    // Expiring the blockhash forces a new one, making the txns distinct.
+    svm.expire_blockhash();
 
-    let start_slot = current_slot(&svm) + 20;
-    let instr = build_initialise_round_instr(&authority.pubkey(), &pda, start_slot);
-    let result = send_instr(&mut svm, instr, &authority);
-    assert!(result.is_err(), "second initialise should fail");
+    let instr = build_signal_interest_instr(&user.pubkey(), &pda);
+    let result = send_instr(&mut svm, instr, &user);
+    assert!(result.is_err(), "second signal should fail");
     assert_err_logs_contain(&result, "already in use");
+
+    let round = fetch_round(&svm, &pda).expect("round account should exist");
+    assert_eq!(round.interest_count, 1);
 }
 
 #[test]
-fn fails_to_initialise_a_round_if_not_after_current_slot() {
+fn fails_to_signal_interest_from_the_start_slot() {
     let mut svm = setup();
     let authority = Keypair::new();
+    let user = Keypair::new();
     svm.airdrop(&authority.pubkey(), LAMPORTS_PER_SOL).unwrap();
-    let (pda, _) = round_pda(&authority.pubkey());
-
-    svm.warp_to_slot(20);
+    svm.airdrop(&user.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    let (pda, start_slot) = scheduled_round(
+        &mut svm,
+        &authority,
+        RoundConfig {
+            min_participants: 2,
+            ..Default::default()
+        },
+    );
+    svm.warp_to_slot(start_slot);
 
-    let instr = build_initialise_round_instr(&authority.pubkey(), &pda, 10);
-    let result = send_instr(&mut svm, instr, &authority);
-    assert!(result.is_err(), "initialise with past slot should fail");
-    assert_err_logs_contain(&result, "start slot must be greater than the current slot");
+    let instr = build_signal_interest_instr(&user.pubkey(), &pda);
+    let result = send_instr(&mut svm, instr, &user);
+    assert!(result.is_err(), "signal at the start slot should fail");
+    assert_err_logs_contain(&result, "InterestClosed");
 }
 
-// Round activation
-
 #[test]
-fn authority_user_activates_a_round_at_start_slot() {
+fn activates_a_round_once_enough_users_are_interested() {
     let mut svm = setup();
     let authority = Keypair::new();
     svm.airdrop(&authority.pubkey(), LAMPORTS_PER_SOL).unwrap();
-    let (pda, _) = round_pda(&authority.pubkey());
-
-    let start_slot = current_slot(&svm) + 10;
-    let instr = build_initialise_round_instr(&authority.pubkey(), &pda, start_slot);
-    send_instr(&mut svm, instr, &authority).expect("initialise should succeed");
-
+    let (pda, start_slot) = scheduled_round(
+        &mut svm,
+        &authority,
+        RoundConfig {
+            min_participants: 2,
+            ..Default::default()
+        },
+    );
+    for _ in 0..2 {
+        funded_user(&mut svm, &pda, build_signal_interest_instr);
+    }
     svm.warp_to_slot(start_slot);
 
     let instr = build_activate_round_instr(&authority.pubkey(), &pda);
     send_instr(&mut svm, instr, &authority).expect("activate should succeed");
 
     let round = fetch_round(&svm, &pda).expect("round account should exist");
-    assert_eq!(round.activated_by, Some(authority.pubkey()));
-    assert_eq!(round.activated_at, Some(start_slot));
+    assert!(matches!(round.status, RoundStatus::Active { .. }));
 }
 
 #[test]
-fn non_authority_user_activates_a_round_at_start_slot() {
+fn fails_to_activate_a_round_below_its_minimum_participants() {
     let mut svm = setup();
     let authority = Keypair::new();
     svm.airdrop(&authority.pubkey(), LAMPORTS_PER_SOL).unwrap();
-    let (pda, _) = round_pda(&authority.pubkey());
+    let (pda, start_slot) = scheduled_round(
+        &mut svm,
+        &authority,
+        RoundConfig {
+            min_participants: 2,
+            ..Default::default()
+        },
+    );
+    funded_user(&mut svm, &pda, build_signal_interest_instr);
+    svm.warp_to_slot(start_slot);
 
-    let start_slot = current_slot(&svm) + 10;
-    let instr = build_initialise_round_instr(&authority.pubkey(), &pda, start_slot);
-    send_instr(&mut svm, instr, &authority).expect("initialise should succeed");
+    let instr = build_activate_round_instr(&authority.pubkey(), &pda);
+    let result = send_instr(&mut svm, instr, &authority);
+    assert!(result.is_err(), "activate below the threshold should fail");
+    assert_err_logs_contain(&result, "NotEnoughInterest");
+
+    let round = fetch_round(&svm, &pda).expect("round account should exist");
+    assert_eq!(round.status, RoundStatus::Scheduled);
+}
 
+#[test]
+fn rescheduled_round_keeps_its_interest_until_it_activates() {
+    let mut svm = setup();
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    let (pda, start_slot) = scheduled_round(
+        &mut svm,
+        &authority,
+        RoundConfig {
+            min_participants: 2,
+            ..Default::default()
+        },
+    );
+    funded_user(&mut svm, &pda, build_signal_interest_instr);
     svm.warp_to_slot(start_slot);
 
-    let activator = Keypair::new();
-    svm.airdrop(&activator.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    let new_start_slot = start_slot + 50;
+    let instr = build_reschedule_round_instr(&authority.pubkey(), &pda, new_start_slot);
+    send_instr(&mut svm, instr, &authority).expect("reschedule should succeed");
+    funded_user(&mut svm, &pda, build_signal_interest_instr);
+    svm.warp_to_slot(new_start_slot);
 
-    let instr = build_activate_round_instr(&activator.pubkey(), &pda);
-    send_instr(&mut svm, instr, &activator).expect("activate should succeed");
+    let instr = build_activate_round_instr(&authority.pubkey(), &pda);
+    send_instr(&mut svm, instr, &authority).expect("activate should succeed");
 
     let round = fetch_round(&svm, &pda).expect("round account should exist");
-    assert_eq!(round.activated_by, Some(activator.pubkey()));
-    assert_eq!(round.activated_at, Some(start_slot));
+    assert_eq!(round.interest_count, 2);
+    assert!(matches!(round.status, RoundStatus::Active { .. }));
 }
 
 #[test]
-fn round_activated_after_start_slot() {
+fn cancels_a_round_that_did_not_reach_its_minimum_participants() {
     let mut svm = setup();
     let authority = Keypair::new();
     svm.airdrop(&authority.pubkey(), LAMPORTS_PER_SOL).unwrap();
-    let (pda, _) = round_pda(&authority.pubkey());
+    let (pda, start_slot) = scheduled_round(
+        &mut svm,
+        &authority,
+        RoundConfig {
+            min_participants: 2,
+            ..Default::default()
+        },
+    );
+    svm.warp_to_slot(start_slot);
+
+    let instr = build_cancel_round_instr(&authority.pubkey(), &pda);
+    send_instr(&mut svm, instr, &authority).expect("cancel should succeed");
+
+    let round = fetch_round(&svm, &pda).expect("round account should exist");
+    assert!(matches!(round.status, RoundStatus::Cancelled { .. }));
+}
 
+#[test]
+fn fails_to_initialise_a_round_with_minimum_above_its_cap() {
+    let mut svm = setup();
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    let (pda, _) = round_pda(&authority.pubkey(), 0);
     let start_slot = current_slot(&svm) + 10;
-    let activate_at = start_slot + 10;
-    let instr = build_initialise_round_instr(&authority.pubkey(), &pda, start_slot);
-    send_instr(&mut svm, instr, &authority).expect("initialise should succeed");
 
-    svm.warp_to_slot(activate_at);
+    let instr = build_initialise_configured_round_instr(
+        &authority.pubkey(),
+        &pda,
+        0,
+        start_slot,
+        start_slot + ROUND_LENGTH,
+        &RoundConfig {
+            max_participants: Some(2),
+            min_participants: 3,
+            ..Default::default()
+        },
+    );
+    let result = send_instr(&mut svm, instr, &authority);
+    assert!(result.is_err(), "initialise with min above max should fail");
+    assert_err_logs_contain(&result, "InvalidMinParticipants");
+}
 
-    let instr = build_activate_round_instr(&authority.pubkey(), &pda);
-    send_instr(&mut svm, instr, &authority).expect("activate should succeed");
+// Round series
 
-    let round = fetch_round(&svm, &pda).expect("round account should exist");
-    assert_eq!(round.activated_at, Some(activate_at));
+const SERIES_INTERVAL: u64 = 100;
+const SERIES_DURATION: u64 = 60;
+
+// Initialises a series 0 whose first round starts 10 slots from now, as the authority's round 0.
+// Returns its PDA and that slot.
+fn series(svm: &mut LiteSVM, authority: &Keypair) -> (Pubkey, u64) {
+    let start_slot = current_slot(svm) + 10;
+    let instr = build_initialise_series_instr(
+        &authority.pubkey(),
+        0,
+        0,
+        start_slot,
+        SERIES_INTERVAL,
+        SERIES_DURATION,
+    );
+    send_instr(svm, instr, authority).expect("initialise series should succeed");
+    (series_pda(&authority.pubkey(), 0).0, start_slot)
 }
 
 #[test]
-fn fails_to_activate_a_round_if_not_initialised() {
+fn initialises_a_series_with_its_first_round() {
     let mut svm = setup();
     let authority = Keypair::new();
     svm.airdrop(&authority.pubkey(), LAMPORTS_PER_SOL).unwrap();
-    let (pda, _) = round_pda(&authority.pubkey());
 
-    let instr = build_activate_round_instr(&authority.pubkey(), &pda);
-    let result = send_instr(&mut svm, instr, &authority);
-    assert!(result.is_err(), "activate without initialise should fail");
-    assert_err_logs_contain(&result, "AccountNotInitialized");
+    let (series_pda, start_slot) = series(&mut svm, &authority);
+
+    let series = fetch_series(&svm, &series_pda).expect("series account should exist");
+    assert_eq!(series.authority, authority.pubkey());
+    assert_eq!(series.series_id, 0);
+    assert_eq!(series.interval_slots, SERIES_INTERVAL);
+    assert_eq!(series.duration_slots, SERIES_DURATION);
+    assert_eq!(series.round_id, 0);
+    assert_eq!(series.round_start_at, start_slot);
+
+    let round =
+        fetch_round(&svm, &round_pda(&authority.pubkey(), 0).0).expect("round should exist");
+    assert_eq!(round.authority, authority.pubkey());
+    assert_eq!(round.version, 1);
+    assert_eq!(round.round_id, 0);
+    assert_eq!(round.start_at, start_slot);
+    assert_eq!(round.end_at, start_slot + SERIES_DURATION);
+    assert_eq!(round.status, RoundStatus::Scheduled);
+
+    let (round_authority_pda, _) = round_authority_pda(&authority.pubkey());
+    let round_authority =
+        fetch_round_authority(&svm, &round_authority_pda).expect("round authority should exist");
+    assert_eq!(round_authority.next_round_id, 1);
 }
 
 #[test]
-fn fails_to_activate_a_round_if_already_active() {
+fn rolls_a_series_once_the_interval_elapses() {
     let mut svm = setup();
     let authority = Keypair::new();
+    let keeper = Keypair::new();
     svm.airdrop(&authority.pubkey(), LAMPORTS_PER_SOL).unwrap();
-    let (pda, _) = round_pda(&authority.pubkey());
+    svm.airdrop(&keeper.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    let (series_pda, start_slot) = series(&mut svm, &authority);
+    let (first_round_pda, _) = round_pda(&authority.pubkey(), 0);
 
-    let start_slot = current_slot(&svm) + 10;
-    let instr = build_initialise_round_instr(&authority.pubkey(), &pda, start_slot);
-    send_instr(&mut svm, instr, &authority).expect("initialise should succeed");
+    svm.warp_to_slot(start_slot);
+    let instr = build_activate_round_instr(&keeper.pubkey(), &first_round_pda);
+    send_instr(&mut svm, instr, &keeper).expect("activate should succeed");
+    funded_user(&mut svm, &first_round_pda, build_join_round_instr);
+
+    svm.warp_to_slot(start_slot + SERIES_INTERVAL);
+    let instr = build_roll_series_instr(&keeper.pubkey(), &authority.pubkey(), 0, 1);
+    send_instr(&mut svm, instr, &keeper).expect("roll should succeed");
+
+    let first_round = fetch_round(&svm, &first_round_pda).expect("round should exist");
+    assert_eq!(
+        first_round.status,
+        RoundStatus::Completed {
+            activated_at: start_slot,
+            activated_by: keeper.pubkey(),
+            completed_at: start_slot + SERIES_INTERVAL,
+        }
+    );
+    assert_eq!(first_round.participant_count, 1);
+
+    let series = fetch_series(&svm, &series_pda).expect("series account should exist");
+    assert_eq!(series.round_id, 1);
+    assert_eq!(series.round_start_at, start_slot + SERIES_INTERVAL);
+    let (next_round_pda, _) = round_pda(&authority.pubkey(), 1);
+    let next_round = fetch_round(&svm, &next_round_pda).expect("round should exist");
+    assert_eq!(next_round.authority, authority.pubkey());
+    assert_eq!(next_round.round_id, 1);
+    assert_eq!(next_round.start_at, start_slot + SERIES_INTERVAL);
+    assert_eq!(
+        next_round.end_at,
+        start_slot + SERIES_INTERVAL + SERIES_DURATION
+    );
+    assert_eq!(next_round.status, RoundStatus::Scheduled);
+
+    // The next round is due to start, anyone can open it
+    let instr = build_activate_round_instr(&keeper.pubkey(), &next_round_pda);
+    send_instr(&mut svm, instr, &keeper).expect("activate should succeed");
+}
+
+#[test]
+fn freezes_the_leaderboard_of_a_series_round_it_rolls_past() {
+    let mut svm = setup();
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    let (_, start_slot) = series(&mut svm, &authority);
+    let (first_round_pda, _) = round_pda(&authority.pubkey(), 0);
 
     svm.warp_to_slot(start_slot);
+    let instr = build_activate_round_instr(&authority.pubkey(), &first_round_pda);
+    send_instr(&mut svm, instr, &authority).expect("activate should succeed");
+    let user = funded_user(&mut svm, &first_round_pda, build_join_round_instr);
+    submit_score(&mut svm, &authority, &first_round_pda, &user.pubkey(), 10);
 
-    let instr = build_activate_round_instr(&authority.pubkey(), &pda);
-    send_instr(&mut svm, instr, &authority).expect("first activate should succeed");
+    svm.warp_to_slot(start_slot + SERIES_INTERVAL);
+    let instr = build_roll_series_instr(&authority.pubkey(), &authority.pubkey(), 0, 1);
+    send_instr(&mut svm, instr, &authority).expect("roll should succeed");
 
-    // LiteSVM was rejecting the second identical txn as `AlreadyProcessed`,
-    // as the same instr w/ the same signer and same blockhash produces an identical txn hash.
-    // Expiring the blockhash forces a new one, making the txns distinct.
-    svm.expire_blockhash();
+    let leaderboard = fetch_leaderboard(&svm, &leaderboard_pda(&first_round_pda).0)
+        .expect("leaderboard should exist");
+    assert_eq!(leaderboard.frozen_at, Some(start_slot + SERIES_INTERVAL));
 
-    let instr = build_activate_round_instr(&authority.pubkey(), &pda);
+    let instr = build_submit_score_instr(&authority.pubkey(), &first_round_pda, &user.pubkey(), 20);
     let result = send_instr(&mut svm, instr, &authority);
-    assert!(result.is_err(), "second activate should fail");
-    assert_err_logs_contain(&result, "RoundAlreadyActive");
+    assert!(result.is_err(), "submit score after the roll should fail");
+    assert_err_logs_contain(&result, "LeaderboardFrozen");
 }
 
+// The authority completes and closes the series' current round before anyone rolls the series
 #[test]
-fn fails_to_activate_a_round_if_not_after_current_slot() {
+fn rolls_a_series_past_its_closed_current_round() {
     let mut svm = setup();
     let authority = Keypair::new();
     svm.airdrop(&authority.pubkey(), LAMPORTS_PER_SOL).unwrap();
-    let (pda, _) = round_pda(&authority.pubkey());
+    let (series_pda, start_slot) = series(&mut svm, &authority);
+    let (first_round_pda, _) = round_pda(&authority.pubkey(), 0);
 
-    let start_slot = current_slot(&svm) + 10;
-    let instr = build_initialise_round_instr(&authority.pubkey(), &pda, start_slot);
-    send_instr(&mut svm, instr, &authority).expect("initialise should succeed");
+    svm.warp_to_slot(start_slot);
+    let instr = build_activate_round_instr(&authority.pubkey(), &first_round_pda);
+    send_instr(&mut svm, instr, &authority).expect("activate should succeed");
+    let instr = build_complete_round_instr(&authority.pubkey(), &first_round_pda);
+    send_instr(&mut svm, instr, &authority).expect("complete should succeed");
+    let instr = build_close_round_instr(&authority.pubkey(), &first_round_pda);
+    send_instr(&mut svm, instr, &authority).expect("close should succeed");
 
-    svm.warp_to_slot(current_slot(&svm) + 5);
+    svm.warp_to_slot(start_slot + SERIES_INTERVAL);
+    let instr = build_roll_series_instr(&authority.pubkey(), &authority.pubkey(), 0, 1);
+    send_instr(&mut svm, instr, &authority).expect("roll should succeed");
 
-    let instr = build_activate_round_instr(&authority.pubkey(), &pda);
-    let result = send_instr(&mut svm, instr, &authority);
-    assert!(result.is_err(), "activate before start slot should fail");
-    assert_err_logs_contain(
-        &result,
-        "current slot must be greater than or equal to the start slot",
+    assert!(fetch_round(&svm, &first_round_pda).is_none());
+    let series = fetch_series(&svm, &series_pda).expect("series account should exist");
+    assert_eq!(series.round_id, 1);
+    let next_round =
+        fetch_round(&svm, &round_pda(&authority.pubkey(), 1).0).expect("round should exist");
+    assert_eq!(next_round.start_at, start_slot + SERIES_INTERVAL);
+    assert_eq!(next_round.status, RoundStatus::Scheduled);
+}
+
+#[test]
+fn cancels_a_series_round_that_was_never_activated() {
+    let mut svm = setup();
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    let (_, start_slot) = series(&mut svm, &authority);
+
+    svm.warp_to_slot(start_slot + SERIES_INTERVAL);
+    let instr = build_roll_series_instr(&authority.pubkey(), &authority.pubkey(), 0, 1);
+    send_instr(&mut svm, instr, &authority).expect("roll should succeed");
+
+    let first_round =
+        fetch_round(&svm, &round_pda(&authority.pubkey(), 0).0).expect("round should exist");
+    assert_eq!(
+        first_round.status,
+        RoundStatus::Cancelled {
+            cancelled_at: start_slot + SERIES_INTERVAL,
+        }
     );
 }
 
-// Round completion
+#[test]
+fn late_roll_skips_the_starts_it_missed() {
+    let mut svm = setup();
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    let (_, start_slot) = series(&mut svm, &authority);
+
+    svm.warp_to_slot(start_slot + 2 * SERIES_INTERVAL + 5);
+    let instr = build_roll_series_instr(&authority.pubkey(), &authority.pubkey(), 0, 1);
+    send_instr(&mut svm, instr, &authority).expect("roll should succeed");
 
+    let next_round =
+        fetch_round(&svm, &round_pda(&authority.pubkey(), 1).0).expect("round should exist");
+    assert_eq!(next_round.start_at, start_slot + 2 * SERIES_INTERVAL);
+    assert_eq!(
+        next_round.end_at,
+        start_slot + 2 * SERIES_INTERVAL + SERIES_DURATION
+    );
+}
+
+// Rolled after the missed round would have ended, so the series moves on to its next start
 #[test]
-fn completes_a_round() {
+fn late_roll_past_a_missed_round_end_schedules_the_next_start() {
     let mut svm = setup();
     let authority = Keypair::new();
     svm.airdrop(&authority.pubkey(), LAMPORTS_PER_SOL).unwrap();
-    let (pda, _) = round_pda(&authority.pubkey());
+    let (_, start_slot) = series(&mut svm, &authority);
 
-    let start_slot = current_slot(&svm) + 10;
-    let complete_slot = start_slot + 10;
-    let instr = build_initialise_round_instr(&authority.pubkey(), &pda, start_slot);
+    svm.warp_to_slot(start_slot + 2 * SERIES_INTERVAL + SERIES_DURATION);
+    let instr = build_roll_series_instr(&authority.pubkey(), &authority.pubkey(), 0, 1);
+    send_instr(&mut svm, instr, &authority).expect("roll should succeed");
+
+    let next_round =
+        fetch_round(&svm, &round_pda(&authority.pubkey(), 1).0).expect("round should exist");
+    assert_eq!(next_round.start_at, start_slot + 3 * SERIES_INTERVAL);
+    assert_eq!(
+        next_round.end_at,
+        start_slot + 3 * SERIES_INTERVAL + SERIES_DURATION
+    );
+    assert_eq!(next_round.status, RoundStatus::Scheduled);
+}
+
+#[test]
+fn series_rounds_take_the_authority_next_round_id() {
+    let mut svm = setup();
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    let (series_pda, start_slot) = series(&mut svm, &authority);
+
+    // A round of the authority's own takes id 1 between the series' rounds
+    let (own_round_pda, _) = round_pda(&authority.pubkey(), 1);
+    let instr = build_initialise_round_instr(
+        &authority.pubkey(),
+        &own_round_pda,
+        1,
+        start_slot,
+        start_slot + ROUND_LENGTH,
+    );
     send_instr(&mut svm, instr, &authority).expect("initialise should succeed");
 
-    svm.warp_to_slot(start_slot);
-    let instr = build_activate_round_instr(&authority.pubkey(), &pda);
-    send_instr(&mut svm, instr, &authority).expect("activate should succeed");
+    svm.warp_to_slot(start_slot + SERIES_INTERVAL);
+    let instr = build_roll_series_instr(&authority.pubkey(), &authority.pubkey(), 0, 2);
+    send_instr(&mut svm, instr, &authority).expect("roll should succeed");
 
-    svm.warp_to_slot(complete_slot);
-    let instr = build_complete_round_instr(&authority.pubkey(), &pda);
-    send_instr(&mut svm, instr, &authority).expect("complete should succeed");
+    let series = fetch_series(&svm, &series_pda).expect("series account should exist");
+    assert_eq!(series.round_id, 2);
+    let next_round =
+        fetch_round(&svm, &round_pda(&authority.pubkey(), 2).0).expect("round should exist");
+    assert_eq!(next_round.round_id, 2);
+    assert_eq!(next_round.start_at, start_slot + SERIES_INTERVAL);
+}
 
-    let round = fetch_round(&svm, &pda).expect("round account should exist");
-    assert_eq!(round.completed_at, Some(complete_slot));
+#[test]
+fn authority_cancels_and_closes_series_rounds() {
+    let mut svm = setup();
+    let authority = Keypair::new();
+    let keeper = Keypair::new();
+    svm.airdrop(&authority.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    svm.airdrop(&keeper.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    let (_, start_slot) = series(&mut svm, &authority);
+    let (first_round_pda, _) = round_pda(&authority.pubkey(), 0);
+    let (next_round_pda, _) = round_pda(&authority.pubkey(), 1);
+
+    svm.warp_to_slot(start_slot + SERIES_INTERVAL);
+    let instr = build_roll_series_instr(&keeper.pubkey(), &authority.pubkey(), 0, 1);
+    send_instr(&mut svm, instr, &keeper).expect("roll should succeed");
+
+    let instr = build_cancel_round_instr(&authority.pubkey(), &next_round_pda);
+    send_instr(&mut svm, instr, &authority).expect("cancel should succeed");
+
+    let authority_balance = svm.get_balance(&authority.pubkey()).unwrap();
+    let instr = build_close_round_instr(&authority.pubkey(), &first_round_pda);
+    send_instr(&mut svm, instr, &authority).expect("close should succeed");
+    let instr = build_close_round_instr(&authority.pubkey(), &next_round_pda);
+    send_instr(&mut svm, instr, &authority).expect("close should succeed");
+
+    assert!(fetch_round(&svm, &first_round_pda).is_none());
+    assert!(fetch_round(&svm, &next_round_pda).is_none());
+    // Less the fees for the authority's two signatures
+    assert_eq!(
+        svm.get_balance(&authority.pubkey()).unwrap(),
+        authority_balance + 2 * round_rent(&svm) - 10_000
+    );
 }
 
 #[test]
-fn fails_to_complete_a_round_if_signer_is_not_authority() {
+fn closes_a_series_to_stop_it_rolling() {
     let mut svm = setup();
     let authority = Keypair::new();
     svm.airdrop(&authority.pubkey(), LAMPORTS_PER_SOL).unwrap();
-    let (pda, _) = round_pda(&authority.pubkey());
+    let (series_pda, start_slot) = series(&mut svm, &authority);
+    let series_rent = svm.get_balance(&series_pda).unwrap();
+    let authority_balance = svm.get_balance(&authority.pubkey()).unwrap();
 
-    let start_slot = current_slot(&svm) + 10;
-    let complete_slot = start_slot + 10;
-    let instr = build_initialise_round_instr(&authority.pubkey(), &pda, start_slot);
-    send_instr(&mut svm, instr, &authority).expect("initialise should succeed");
+    let instr = build_close_series_instr(&authority.pubkey(), 0);
+    send_instr(&mut svm, instr, &authority).expect("close series should succeed");
 
-    svm.warp_to_slot(start_slot);
-    let instr = build_activate_round_instr(&authority.pubkey(), &pda);
-    send_instr(&mut svm, instr, &authority).expect("activate should succeed");
+    assert!(fetch_series(&svm, &series_pda).is_none());
+    // Less the fee for the authority's signature
+    assert_eq!(
+        svm.get_balance(&authority.pubkey()).unwrap(),
+        authority_balance + series_rent - 5_000
+    );
 
-    svm.warp_to_slot(complete_slot);
-    let non_authority = Keypair::new();
-    svm.airdrop(&non_authority.pubkey(), LAMPORTS_PER_SOL)
-        .unwrap();
-    let instr = build_complete_round_instr(&non_authority.pubkey(), &pda);
-    let result = send_instr(&mut svm, instr, &non_authority);
-    assert!(result.is_err(), "complete by non-authority should fail");
+    svm.warp_to_slot(start_slot + SERIES_INTERVAL);
+    let instr = build_roll_series_instr(&authority.pubkey(), &authority.pubkey(), 0, 1);
+    let result = send_instr(&mut svm, instr, &authority);
+    assert!(result.is_err(), "roll of a closed series should fail");
+    assert_err_logs_contain(&result, "AccountNotInitialized");
+
+    // The series' current round is still the authority's to finish
+    let instr = build_cancel_round_instr(&authority.pubkey(), &round_pda(&authority.pubkey(), 0).0);
+    send_instr(&mut svm, instr, &authority).expect("cancel should succeed");
+}
+
+#[test]
+fn fails_to_close_another_authority_series() {
+    let mut svm = setup();
+    let authority = Keypair::new();
+    let other = Keypair::new();
+    svm.airdrop(&authority.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    svm.airdrop(&other.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    let (series_pda, _) = series(&mut svm, &authority);
+
+    let instr = Instruction::new_with_bytes(
+        round::ID,
+        &anchor_discriminator("close_series"),
+        vec![
+            AccountMeta::new(series_pda, false),
+            AccountMeta::new(other.pubkey(), true),
+        ],
+    );
+    let result = send_instr(&mut svm, instr, &other);
+    assert!(
+        result.is_err(),
+        "close of another authority's series should fail"
+    );
     assert_err_logs_contain(&result, "ConstraintHasOne");
 }
 
 #[test]
-fn fails_to_complete_a_round_if_not_active() {
+fn fails_to_roll_a_series_before_the_interval_elapses() {
     let mut svm = setup();
     let authority = Keypair::new();
     svm.airdrop(&authority.pubkey(), LAMPORTS_PER_SOL).unwrap();
-    let (pda, _) = round_pda(&authority.pubkey());
+    let (_, start_slot) = series(&mut svm, &authority);
 
-    let start_slot = current_slot(&svm) + 10;
-    let instr = build_initialise_round_instr(&authority.pubkey(), &pda, start_slot);
-    send_instr(&mut svm, instr, &authority).expect("initialise should succeed");
+    svm.warp_to_slot(start_slot + SERIES_INTERVAL - 1);
+    let instr = build_roll_series_instr(&authority.pubkey(), &authority.pubkey(), 0, 1);
+    let result = send_instr(&mut svm, instr, &authority);
+    assert!(result.is_err(), "roll before the interval should fail");
+    assert_err_logs_contain(&result, "SeriesNotDue");
+}
 
-    let instr = build_complete_round_instr(&authority.pubkey(), &pda);
+#[test]
+fn fails_to_roll_a_series_from_a_past_round() {
+    let mut svm = setup();
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    let (_, start_slot) = series(&mut svm, &authority);
+    svm.warp_to_slot(start_slot + SERIES_INTERVAL);
+    let instr = build_roll_series_instr(&authority.pubkey(), &authority.pubkey(), 0, 1);
+    send_instr(&mut svm, instr, &authority).expect("roll should succeed");
+
+    svm.warp_to_slot(start_slot + 2 * SERIES_INTERVAL);
+    let instr = build_roll_series_instr(&authority.pubkey(), &authority.pubkey(), 0, 2);
     let result = send_instr(&mut svm, instr, &authority);
-    assert!(result.is_err(), "complete without activate should fail");
-    assert_err_logs_contain(&result, "round has not yet been activated");
+    assert!(result.is_err(), "roll from a past round should fail");
+    assert_err_logs_contain(&result, "ConstraintSeeds");
 }
 
 #[test]
-fn fails_to_complete_a_round_if_already_completed() {
+fn fails_to_initialise_a_series_unless_rounds_end_before_the_next_starts() {
     let mut svm = setup();
     let authority = Keypair::new();
     svm.airdrop(&authority.pubkey(), LAMPORTS_PER_SOL).unwrap();
-    let (pda, _) = round_pda(&authority.pubkey());
+    let start_slot = current_slot(&svm) + 10;
+
+    for (series_id, duration_slots) in [(0, 0), (1, SERIES_INTERVAL)] {
+        let instr = build_initialise_series_instr(
+            &authority.pubkey(),
+            series_id,
+            0,
+            start_slot,
+            SERIES_INTERVAL,
+            duration_slots,
+        );
+        let result = send_instr(&mut svm, instr, &authority);
+        assert!(
+            result.is_err(),
+            "initialise series with a bad cadence should fail"
+        );
+        assert_err_logs_contain(&result, "InvalidSeriesCadence");
+    }
+}
+
+// Events
+
+#[test]
+fn initialise_activate_and_complete_emit_events() {
+    let mut svm = setup();
+    let authority = Keypair::new();
+    let keeper = Keypair::new();
+    svm.airdrop(&authority.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    svm.airdrop(&keeper.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    let (pda, _) = round_pda(&authority.pubkey(), 0);
 
     let start_slot = current_slot(&svm) + 10;
-    let complete_slot = start_slot + 10;
-    let instr = build_initialise_round_instr(&authority.pubkey(), &pda, start_slot);
-    send_instr(&mut svm, instr, &authority).expect("initialise should succeed");
+    let instr = build_initialise_round_instr(
+        &authority.pubkey(),
+        &pda,
+        0,
+        start_slot,
+        start_slot + ROUND_LENGTH,
+    );
+    let meta = send_instr(&mut svm, instr, &authority).expect("initialise should succeed");
+
+    let events = log_events::<round::RoundInitialised>(&meta);
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].round, pda);
+    assert_eq!(events[0].authority, authority.pubkey());
+    assert_eq!(events[0].actor, authority.pubkey());
+    assert_eq!(events[0].slot, current_slot(&svm));
+    assert_eq!(events[0].round_id, 0);
+    assert_eq!(events[0].start_at, start_slot);
+    assert_eq!(events[0].end_at, start_slot + ROUND_LENGTH);
 
     svm.warp_to_slot(start_slot);
-    let instr = build_activate_round_instr(&authority.pubkey(), &pda);
-    send_instr(&mut svm, instr, &authority).expect("activate should succeed");
+    let instr = build_activate_round_instr(&keeper.pubkey(), &pda);
+    let meta = send_instr(&mut svm, instr, &keeper).expect("activate should succeed");
 
-    svm.warp_to_slot(complete_slot);
-    let instr = build_complete_round_instr(&authority.pubkey(), &pda);
-    send_instr(&mut svm, instr, &authority).expect("first complete should succeed");
+    let events = log_events::<round::RoundActivated>(&meta);
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].round, pda);
+    assert_eq!(events[0].authority, authority.pubkey());
+    assert_eq!(events[0].actor, keeper.pubkey());
+    assert_eq!(events[0].slot, start_slot);
 
-    // LiteSVM was rejecting the second identical txn as `AlreadyProcessed`,
-    // as the same instr w/ the same signer and same blockhash produces an identical txn hash.
-    // Expiring the blockhash forces a new one, making the txns distinct.
-    svm.expire_blockhash();
+    svm.warp_to_slot(start_slot + ROUND_LENGTH + 1);
+    let instr = build_complete_round_instr(&keeper.pubkey(), &pda);
+    let meta = send_instr(&mut svm, instr, &keeper).expect("complete should succeed");
 
-    let instr = build_complete_round_instr(&authority.pubkey(), &pda);
-    let result = send_instr(&mut svm, instr, &authority);
-    assert!(result.is_err(), "second complete should fail");
-    assert_err_logs_contain(&result, "RoundAlreadyComplete");
+    let events = log_events::<round::RoundCompleted>(&meta);
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].round, pda);
+    assert_eq!(events[0].authority, authority.pubkey());
+    assert_eq!(events[0].actor, keeper.pubkey());
+    assert_eq!(events[0].slot, start_slot + ROUND_LENGTH + 1);
 }
 
 #[test]
-fn fails_to_complete_a_round_if_not_initialised() {
+fn settling_emits_the_winner_and_payout() {
     let mut svm = setup();
     let authority = Keypair::new();
     svm.airdrop(&authority.pubkey(), LAMPORTS_PER_SOL).unwrap();
-    let (pda, _) = round_pda(&authority.pubkey());
+    // 10% commission
+    let pda = paid_round(&mut svm, &authority, 1_000);
+    let winner = funded_user(&mut svm, &pda, build_enter_round_instr);
+    complete_ended_round(&mut svm, &pda, &winner);
 
-    let instr = build_complete_round_instr(&authority.pubkey(), &pda);
-    let result = send_instr(&mut svm, instr, &authority);
-    assert!(result.is_err(), "complete without initialise should fail");
-    assert_err_logs_contain(&result, "AccountNotInitialized");
+    let instr = build_settle_round_instr(&authority.pubkey(), &pda, &winner.pubkey());
+    let meta = send_instr(&mut svm, instr, &authority).expect("settle should succeed");
+
+    let events = log_events::<round::RoundSettled>(&meta);
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].round, pda);
+    assert_eq!(events[0].actor, authority.pubkey());
+    assert_eq!(events[0].slot, current_slot(&svm));
+    assert_eq!(events[0].winner, winner.pubkey());
+    assert_eq!(events[0].prize, ENTRY_FEE - ENTRY_FEE / 10);
+    assert_eq!(events[0].commission, ENTRY_FEE / 10);
 }
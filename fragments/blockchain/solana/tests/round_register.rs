@@ -0,0 +1,481 @@
+// Rounds gated on a confirmed registration in the `register` program, with both programs loaded
+use anchor_lang::{AccountDeserialize, AnchorSerialize};
+use litesvm::LiteSVM;
+use program_tests::{anchor_discriminator, anchor_instr_data, assert_err_logs_contain, send_instr};
+use round::RoundConfig;
+use solana_loader_v3_interface::{get_program_data_address, state::UpgradeableLoaderState};
+use solana_sdk::{
+    account::Account,
+    clock::Clock,
+    instruction::{AccountMeta, Instruction},
+    native_token::LAMPORTS_PER_SOL,
+    pubkey::Pubkey,
+    signature::Keypair,
+    signer::Signer,
+    system_program::ID as SYSTEM_PROGRAM_ID,
+};
+
+fn round_pda(authority: &Pubkey, round_id: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[b"round", authority.as_ref(), &round_id.to_le_bytes()],
+        &round::ID,
+    )
+}
+
+fn round_authority_pda(authority: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"round_authority", authority.as_ref()], &round::ID)
+}
+
+fn participant_pda(round_pda: &Pubkey, user: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[b"participant", round_pda.as_ref(), user.as_ref()],
+        &round::ID,
+    )
+}
+
+fn interest_pda(round_pda: &Pubkey, user: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[b"interest", round_pda.as_ref(), user.as_ref()],
+        &round::ID,
+    )
+}
+
+fn pot_pda(round_pda: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"pot", round_pda.as_ref()], &round::ID)
+}
+
+fn registry_state_pda() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"registry_state"], &register::ID)
+}
+
+fn registration_pda(registrant: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"registration", registrant.as_ref()], &register::ID)
+}
+
+// Signs the self-CPIs that `emit_cpi!` uses to publish events
+fn event_authority_pda() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"__event_authority"], &register::ID)
+}
+
+// Anchor takes the program id in place of an omitted optional account
+fn registration_meta(registration: Option<&Pubkey>) -> AccountMeta {
+    AccountMeta::new_readonly(*registration.unwrap_or(&round::ID), false)
+}
+
+fn build_initialise_round_instr(
+    authority: &Pubkey,
+    round_pda: &Pubkey,
+    start_slot: u64,
+    config: &RoundConfig,
+) -> Instruction {
+    let (round_authority_pda, _) = round_authority_pda(authority);
+    let (pot_pda, _) = pot_pda(round_pda);
+    let mut args = 0u64.to_le_bytes().to_vec();
+    args.extend_from_slice(&start_slot.to_le_bytes());
+    args.extend_from_slice(&(start_slot + 100).to_le_bytes());
+    config.serialize(&mut args).unwrap();
+    Instruction::new_with_bytes(
+        round::ID,
+        &anchor_instr_data("initialise_round", &args),
+        vec![
+            AccountMeta::new(round_authority_pda, false),
+            AccountMeta::new(*round_pda, false),
+            AccountMeta::new(pot_pda, false),
+            AccountMeta::new(*authority, true),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+        ],
+    )
+}
+
+fn build_activate_round_instr(
+    user: &Pubkey,
+    round_pda: &Pubkey,
+    registration: Option<&Pubkey>,
+) -> Instruction {
+    Instruction::new_with_bytes(
+        round::ID,
+        &anchor_discriminator("activate_round"),
+        vec![
+            AccountMeta::new(*round_pda, false),
+            AccountMeta::new(*user, true),
+            registration_meta(registration),
+        ],
+    )
+}
+
+fn build_join_round_instr(
+    user: &Pubkey,
+    round_pda: &Pubkey,
+    registration: Option<&Pubkey>,
+) -> Instruction {
+    let (participant_pda, _) = participant_pda(round_pda, user);
+    Instruction::new_with_bytes(
+        round::ID,
+        &anchor_discriminator("join_round"),
+        vec![
+            AccountMeta::new(*round_pda, false),
+            AccountMeta::new(participant_pda, false),
+            AccountMeta::new(*user, true),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+            registration_meta(registration),
+        ],
+    )
+}
+
+fn build_enter_round_instr(
+    user: &Pubkey,
+    round_pda: &Pubkey,
+    registration: Option<&Pubkey>,
+) -> Instruction {
+    let (participant_pda, _) = participant_pda(round_pda, user);
+    let (pot_pda, _) = pot_pda(round_pda);
+    Instruction::new_with_bytes(
+        round::ID,
+        &anchor_discriminator("enter_round"),
+        vec![
+            AccountMeta::new(*round_pda, false),
+            AccountMeta::new(participant_pda, false),
+            AccountMeta::new(pot_pda, false),
+            AccountMeta::new(*user, true),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+            registration_meta(registration),
+        ],
+    )
+}
+
+fn build_signal_interest_instr(
+    user: &Pubkey,
+    round_pda: &Pubkey,
+    registration: Option<&Pubkey>,
+) -> Instruction {
+    let (interest_pda, _) = interest_pda(round_pda, user);
+    Instruction::new_with_bytes(
+        round::ID,
+        &anchor_discriminator("signal_interest"),
+        vec![
+            AccountMeta::new(*round_pda, false),
+            AccountMeta::new(interest_pda, false),
+            AccountMeta::new(*user, true),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+            registration_meta(registration),
+        ],
+    )
+}
+
+fn build_initialise_registry_instr(authority: &Pubkey, registry_state_pda: &Pubkey) -> Instruction {
+    Instruction::new_with_bytes(
+        register::ID,
+        &anchor_instr_data("initialise_registry", &[false as u8]),
+        vec![
+            AccountMeta::new(*authority, true),
+            AccountMeta::new(*registry_state_pda, false),
+            AccountMeta::new_readonly(get_program_data_address(&register::ID), false),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+            AccountMeta::new_readonly(event_authority_pda().0, false),
+            AccountMeta::new_readonly(register::ID, false),
+        ],
+    )
+}
+
+fn build_register_instr(registrant: &Pubkey, registry_state_pda: &Pubkey) -> Instruction {
+    let (registration_pda, _) = registration_pda(registrant);
+    Instruction::new_with_bytes(
+        register::ID,
+        // An empty allowlist proof
+        &anchor_instr_data("register", &0u32.to_le_bytes()),
+        vec![
+            AccountMeta::new(*registrant, true),
+            AccountMeta::new_readonly(*registrant, true),
+            AccountMeta::new(*registry_state_pda, false),
+            AccountMeta::new(registration_pda, false),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+            AccountMeta::new_readonly(event_authority_pda().0, false),
+            AccountMeta::new_readonly(register::ID, false),
+        ],
+    )
+}
+
+fn build_confirm_registration_instr(
+    authority: &Pubkey,
+    registry_state_pda: &Pubkey,
+    registrant: &Pubkey,
+) -> Instruction {
+    let (registration_pda, _) = registration_pda(registrant);
+    Instruction::new_with_bytes(
+        register::ID,
+        &anchor_discriminator("confirm_registration"),
+        vec![
+            AccountMeta::new(*registry_state_pda, false),
+            AccountMeta::new_readonly(*authority, true),
+            AccountMeta::new(registration_pda, false),
+            AccountMeta::new_readonly(event_authority_pda().0, false),
+            AccountMeta::new_readonly(register::ID, false),
+        ],
+    )
+}
+
+// Loads both programs and initialises the registry, with `registry_authority` as the register
+// program's upgrade authority
+fn setup(registry_authority: &Keypair) -> LiteSVM {
+    let mut svm = LiteSVM::new();
+    svm.add_program_from_file(round::ID, "../target/deploy/round.so")
+        .expect("Failed to load round program");
+    svm.add_program_from_file(register::ID, "../target/deploy/register.so")
+        .expect("Failed to load register program");
+
+    let program_data_state = UpgradeableLoaderState::ProgramData {
+        slot: 0,
+        upgrade_authority_address: Some(registry_authority.pubkey()),
+    };
+    let data = bincode::serialize(&program_data_state).unwrap();
+    svm.set_account(
+        get_program_data_address(&register::ID),
+        Account {
+            lamports: 1_000_000,
+            data,
+            owner: solana_sdk_ids::bpf_loader_upgradeable::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    )
+    .unwrap();
+
+    svm.airdrop(&registry_authority.pubkey(), LAMPORTS_PER_SOL)
+        .unwrap();
+    let (state_pda, _) = registry_state_pda();
+    let instr = build_initialise_registry_instr(&registry_authority.pubkey(), &state_pda);
+    send_instr(&mut svm, instr, registry_authority).expect("initialise registry should succeed");
+
+    svm
+}
+
+// Funds a new user and registers them, confirming the registration if `confirmed`
+fn registered_user(svm: &mut LiteSVM, registry_authority: &Keypair, confirmed: bool) -> Keypair {
+    let user = Keypair::new();
+    svm.airdrop(&user.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    let (state_pda, _) = registry_state_pda();
+
+    let instr = build_register_instr(&user.pubkey(), &state_pda);
+    send_instr(svm, instr, &user).expect("register should succeed");
+
+    if confirmed {
+        let instr = build_confirm_registration_instr(
+            &registry_authority.pubkey(),
+            &state_pda,
+            &user.pubkey(),
+        );
+        send_instr(svm, instr, registry_authority).expect("confirm should succeed");
+    }
+
+    user
+}
+
+// Initialises a round with `config`, returning its PDA and start slot
+fn gated_round(svm: &mut LiteSVM, authority: &Keypair, config: RoundConfig) -> (Pubkey, u64) {
+    svm.airdrop(&authority.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    let (pda, _) = round_pda(&authority.pubkey(), 0);
+    let start_slot = svm.get_sysvar::<Clock>().slot + 10;
+    let instr = build_initialise_round_instr(&authority.pubkey(), &pda, start_slot, &config);
+    send_instr(svm, instr, authority).expect("initialise should succeed");
+    (pda, start_slot)
+}
+
+// Initialises a round open only to registered participants and activates it
+fn active_gated_round(svm: &mut LiteSVM, authority: &Keypair, entry_fee: u64) -> Pubkey {
+    let (pda, start_slot) = gated_round(
+        svm,
+        authority,
+        RoundConfig {
+            entry_fee,
+            participation_requires_registration: true,
+            ..Default::default()
+        },
+    );
+    svm.warp_to_slot(start_slot);
+    let instr = build_activate_round_instr(&authority.pubkey(), &pda, None);
+    send_instr(svm, instr, authority).expect("activate should succeed");
+    pda
+}
+
+fn fetch_round(svm: &LiteSVM, pda: &Pubkey) -> Option<round::Round> {
+    let account = svm.get_account(pda)?;
+    let mut data = account.data.as_slice();
+    round::Round::try_deserialize(&mut data).ok()
+}
+
+// Participation
+
+#[test]
+fn confirmed_user_joins_a_gated_round() {
+    let registry_authority = Keypair::new();
+    let mut svm = setup(&registry_authority);
+    let pda = active_gated_round(&mut svm, &Keypair::new(), 0);
+    let user = registered_user(&mut svm, &registry_authority, true);
+
+    let (registration, _) = registration_pda(&user.pubkey());
+    let instr = build_join_round_instr(&user.pubkey(), &pda, Some(&registration));
+    send_instr(&mut svm, instr, &user).expect("join should succeed");
+
+    let round = fetch_round(&svm, &pda).expect("round account should exist");
+    assert!(round.participation_requires_registration);
+    assert_eq!(round.participant_count, 1);
+}
+
+#[test]
+fn confirmed_user_enters_a_gated_paid_round() {
+    let registry_authority = Keypair::new();
+    let mut svm = setup(&registry_authority);
+    let pda = active_gated_round(&mut svm, &Keypair::new(), LAMPORTS_PER_SOL / 10);
+    let user = registered_user(&mut svm, &registry_authority, true);
+
+    let (registration, _) = registration_pda(&user.pubkey());
+    let instr = build_enter_round_instr(&user.pubkey(), &pda, Some(&registration));
+    send_instr(&mut svm, instr, &user).expect("enter should succeed");
+
+    let round = fetch_round(&svm, &pda).expect("round account should exist");
+    assert_eq!(round.participant_count, 1);
+}
+
+#[test]
+fn fails_to_join_a_gated_round_without_a_registration() {
+    let registry_authority = Keypair::new();
+    let mut svm = setup(&registry_authority);
+    let pda = active_gated_round(&mut svm, &Keypair::new(), 0);
+    let user = Keypair::new();
+    svm.airdrop(&user.pubkey(), LAMPORTS_PER_SOL).unwrap();
+
+    let instr = build_join_round_instr(&user.pubkey(), &pda, None);
+    let result = send_instr(&mut svm, instr, &user);
+    assert!(result.is_err(), "join without a registration should fail");
+    assert_err_logs_contain(&result, "RegistrationRequired");
+}
+
+#[test]
+fn fails_to_join_a_gated_round_with_an_unconfirmed_registration() {
+    let registry_authority = Keypair::new();
+    let mut svm = setup(&registry_authority);
+    let pda = active_gated_round(&mut svm, &Keypair::new(), 0);
+    let user = registered_user(&mut svm, &registry_authority, false);
+
+    let (registration, _) = registration_pda(&user.pubkey());
+    let instr = build_join_round_instr(&user.pubkey(), &pda, Some(&registration));
+    let result = send_instr(&mut svm, instr, &user);
+    assert!(
+        result.is_err(),
+        "join with an unconfirmed registration should fail"
+    );
+    assert_err_logs_contain(&result, "RegistrationNotConfirmed");
+}
+
+#[test]
+fn fails_to_join_a_gated_round_with_another_users_registration() {
+    let registry_authority = Keypair::new();
+    let mut svm = setup(&registry_authority);
+    let pda = active_gated_round(&mut svm, &Keypair::new(), 0);
+    let registered = registered_user(&mut svm, &registry_authority, true);
+    let user = Keypair::new();
+    svm.airdrop(&user.pubkey(), LAMPORTS_PER_SOL).unwrap();
+
+    let (registration, _) = registration_pda(&registered.pubkey());
+    let instr = build_join_round_instr(&user.pubkey(), &pda, Some(&registration));
+    let result = send_instr(&mut svm, instr, &user);
+    assert!(
+        result.is_err(),
+        "join with another user's registration should fail"
+    );
+    assert_err_logs_contain(&result, "ConstraintSeeds");
+}
+
+#[test]
+fn only_a_confirmed_user_signals_interest_in_a_gated_round() {
+    let registry_authority = Keypair::new();
+    let mut svm = setup(&registry_authority);
+    let (pda, _) = gated_round(
+        &mut svm,
+        &Keypair::new(),
+        RoundConfig {
+            participation_requires_registration: true,
+            min_participants: 1,
+            ..Default::default()
+        },
+    );
+    let user = registered_user(&mut svm, &registry_authority, true);
+    let unregistered = Keypair::new();
+    svm.airdrop(&unregistered.pubkey(), LAMPORTS_PER_SOL)
+        .unwrap();
+
+    let instr = build_signal_interest_instr(&unregistered.pubkey(), &pda, None);
+    let result = send_instr(&mut svm, instr, &unregistered);
+    assert!(
+        result.is_err(),
+        "signal interest without a registration should fail"
+    );
+    assert_err_logs_contain(&result, "RegistrationRequired");
+
+    let (registration, _) = registration_pda(&user.pubkey());
+    let instr = build_signal_interest_instr(&user.pubkey(), &pda, Some(&registration));
+    send_instr(&mut svm, instr, &user).expect("signal interest should succeed");
+
+    let round = fetch_round(&svm, &pda).expect("round account should exist");
+    assert_eq!(round.interest_count, 1);
+}
+
+// Activation
+
+#[test]
+fn only_a_confirmed_user_activates_a_gated_round() {
+    let registry_authority = Keypair::new();
+    let mut svm = setup(&registry_authority);
+    let (pda, start_slot) = gated_round(
+        &mut svm,
+        &Keypair::new(),
+        RoundConfig {
+            activation_requires_registration: true,
+            ..Default::default()
+        },
+    );
+    let unconfirmed = registered_user(&mut svm, &registry_authority, false);
+    let keeper = registered_user(&mut svm, &registry_authority, true);
+    svm.warp_to_slot(start_slot);
+
+    let (registration, _) = registration_pda(&unconfirmed.pubkey());
+    let instr = build_activate_round_instr(&unconfirmed.pubkey(), &pda, Some(&registration));
+    let result = send_instr(&mut svm, instr, &unconfirmed);
+    assert!(
+        result.is_err(),
+        "activate with an unconfirmed registration should fail"
+    );
+    assert_err_logs_contain(&result, "RegistrationNotConfirmed");
+
+    let instr = build_activate_round_instr(&keeper.pubkey(), &pda, None);
+    let result = send_instr(&mut svm, instr, &keeper);
+    assert!(
+        result.is_err(),
+        "activate without a registration should fail"
+    );
+    assert_err_logs_contain(&result, "RegistrationRequired");
+
+    let (registration, _) = registration_pda(&keeper.pubkey());
+    let instr = build_activate_round_instr(&keeper.pubkey(), &pda, Some(&registration));
+    send_instr(&mut svm, instr, &keeper).expect("activate should succeed");
+
+    let round = fetch_round(&svm, &pda).expect("round account should exist");
+    assert!(round.activation_requires_registration);
+    assert!(!round.participation_requires_registration);
+}
+
+#[test]
+fn ungated_round_ignores_registrations() {
+    let registry_authority = Keypair::new();
+    let mut svm = setup(&registry_authority);
+    let (pda, start_slot) = gated_round(&mut svm, &Keypair::new(), RoundConfig::default());
+    let user = Keypair::new();
+    svm.airdrop(&user.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    svm.warp_to_slot(start_slot);
+
+    let instr = build_activate_round_instr(&user.pubkey(), &pda, None);
+    send_instr(&mut svm, instr, &user).expect("activate should succeed");
+
+    let instr = build_join_round_instr(&user.pubkey(), &pda, None);
+    send_instr(&mut svm, instr, &user).expect("join should succeed");
+}
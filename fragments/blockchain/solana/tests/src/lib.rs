@@ -1,3 +1,5 @@
+use anchor_lang::{event::EVENT_IX_TAG_LE, Event};
+use base64::prelude::{Engine, BASE64_STANDARD};
 use litesvm::types::{FailedTransactionMetadata, TransactionMetadata};
 use litesvm::LiteSVM;
 use sha2::{Digest, Sha256};
@@ -23,14 +25,61 @@ pub fn anchor_instr_data(name: &str, args: &[u8]) -> Vec<u8> {
     data
 }
 
+// Decodes the `E` events a program published with `emit_cpi!`. Each is a self-CPI whose data is
+// Anchor's event tag, then the event discriminator and its serialised fields.
+pub fn cpi_events<E: Event>(meta: &TransactionMetadata) -> Vec<E> {
+    meta.inner_instructions
+        .iter()
+        .flatten()
+        .filter_map(|inner| {
+            let data = inner.instruction.data.strip_prefix(EVENT_IX_TAG_LE)?;
+            let data = data.strip_prefix(E::DISCRIMINATOR)?;
+            E::try_from_slice(data).ok()
+        })
+        .collect()
+}
+
+// Decodes the `E` events a program published with `emit!`. Each is logged as "Program data: "
+// followed by the base64 of the event discriminator and its serialised fields.
+pub fn log_events<E: Event>(meta: &TransactionMetadata) -> Vec<E> {
+    meta.logs
+        .iter()
+        .filter_map(|log| {
+            let data = BASE64_STANDARD
+                .decode(log.strip_prefix("Program data: ")?)
+                .ok()?;
+            let data = data.strip_prefix(E::DISCRIMINATOR)?;
+            E::try_from_slice(data).ok()
+        })
+        .collect()
+}
+
 pub fn send_instr(
     svm: &mut LiteSVM,
     instr: Instruction,
     payer: &Keypair,
+) -> BoxedTransactionResult {
+    send_instrs(svm, &[instr], payer)
+}
+
+pub fn send_instrs(
+    svm: &mut LiteSVM,
+    instrs: &[Instruction],
+    payer: &Keypair,
+) -> BoxedTransactionResult {
+    send_instrs_with_signers(svm, instrs, payer, &[])
+}
+
+// For txns where the fee payer isn't the only signer, e.g. a sponsor paying for a registrant.
+pub fn send_instrs_with_signers(
+    svm: &mut LiteSVM,
+    instrs: &[Instruction],
+    payer: &Keypair,
+    signers: &[&Keypair],
 ) -> BoxedTransactionResult {
     let blockhash = svm.latest_blockhash();
-    let tx =
-        Transaction::new_signed_with_payer(&[instr], Some(&payer.pubkey()), &[payer], blockhash);
+    let signers = [&[payer], signers].concat();
+    let tx = Transaction::new_signed_with_payer(instrs, Some(&payer.pubkey()), &signers, blockhash);
     svm.send_transaction(tx).map_err(Box::new)
 }
 
@@ -1 +1,5 @@
-from .solana_program_utils import get_instruction_discriminator, get_program_derived_address
+from .solana_program_utils import (
+    get_event_authority_pda,
+    get_instruction_discriminator,
+    get_program_derived_address,
+)
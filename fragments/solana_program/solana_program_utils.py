@@ -40,3 +40,10 @@ def get_program_derived_address(user_address: Pubkey, program_address: Pubkey, a
     seed2 = bytes(user_address)
     pda, _ = Pubkey.find_program_address([seed1, seed2], program_address)
     return pda
+
+
+# Anchor programs built with `event-cpi` sign their event self-CPIs with this PDA. Instructions that
+# emit events (`#[event_cpi]`) take it, followed by the program itself, as their last two accounts.
+def get_event_authority_pda(program_address: Pubkey) -> Pubkey:
+    pda, _ = Pubkey.find_program_address([b"__event_authority"], program_address)
+    return pda
@@ -1,6 +1,6 @@
 import assert from "node:assert/strict";
 import test, { describe } from "node:test";
-import { getInstructionDiscriminator, getPda } from "./solana_program_utils";
+import { getEventAuthorityPda, getInstructionDiscriminator, getPda } from "./solana_program_utils";
 import { address } from "@solana/kit";
 
 describe("solana program utils", () => {
@@ -36,4 +36,10 @@ describe("solana program utils", () => {
     const pda = await getPda(userAddress, programAddress, "counter");
     assert.strictEqual(pda, "9yFnCu3Nyr4aa7kdd4ckAyPKABQyTPLX2Xm4Aj2MXsLc");
   });
+
+  test("getEventAuthorityPda returns the program's event authority", async () => {
+    const programAddress = address("23Ww1C2uzCiH9zjmfhG6QmkopkeanZM87mjDHu8MMwXY");
+    const pda = await getEventAuthorityPda(programAddress);
+    assert.strictEqual(pda, "A7D7eNfxCmGnQ3MdUKxrRGgUoLZeis8nueVQZ5vhhgwY");
+  });
 });
@@ -47,3 +47,13 @@ export const getPda = async (
 export const skipAnchorDiscriminator: Parameters<typeof offsetDecoder>[1] = {
   preOffset: ({ wrapBytes }) => wrapBytes(8),
 };
+
+// Anchor programs built with `event-cpi` sign their event self-CPIs with this PDA. Instructions that
+// emit events (`#[event_cpi]`) take it, followed by the program itself, as their last two accounts.
+export const getEventAuthorityPda = async (programAddress: Address): Promise<Address> => {
+  const [pda] = await getProgramDerivedAddress({
+    programAddress,
+    seeds: [Buffer.from("__event_authority")],
+  });
+  return pda;
+};
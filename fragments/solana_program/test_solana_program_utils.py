@@ -1,6 +1,10 @@
 import unittest
 from solders.pubkey import Pubkey
-from fragments.solana_program import get_instruction_discriminator, get_program_derived_address
+from fragments.solana_program import (
+    get_event_authority_pda,
+    get_instruction_discriminator,
+    get_program_derived_address,
+)
 
 
 class TestSolanaProgramUtils(unittest.TestCase):
@@ -22,3 +26,9 @@ class TestSolanaProgramUtils(unittest.TestCase):
         pda = get_program_derived_address(user_address, program_address, "counter")
 
         self.assertEqual(pda, Pubkey.from_string("9yFnCu3Nyr4aa7kdd4ckAyPKABQyTPLX2Xm4Aj2MXsLc"))
+
+    def test_solana_get_event_authority_pda(self):
+        program_address = Pubkey.from_string("23Ww1C2uzCiH9zjmfhG6QmkopkeanZM87mjDHu8MMwXY")
+        pda = get_event_authority_pda(program_address)
+
+        self.assertEqual(pda, Pubkey.from_string("A7D7eNfxCmGnQ3MdUKxrRGgUoLZeis8nueVQZ5vhhgwY"))
@@ -2,6 +2,11 @@ from .solana_register_interface import (
     initialise_registry,
     register,
     confirm_registration,
+    configure_approvers,
+    approve_registration,
+    set_allowlist_root,
+    sign_voucher,
+    register_with_voucher,
     get_registry_state_account,
     get_registration_account,
 )
@@ -4,15 +4,32 @@ from solders.pubkey import Pubkey
 from solders.signature import Signature
 from solders.instruction import Instruction, AccountMeta
 from solana.constants import SYSTEM_PROGRAM_ID, BPF_LOADER_PROGRAM_ID
-from construct import Struct, Int64ul, Bytes, Flag, If
-from fragments.solana_program import get_instruction_discriminator, get_program_derived_address
+from construct import Struct, Int8ul, Int16ul, Int32ul, Int64ul, Bytes, Flag, If, PrefixedArray
+from fragments.solana_program import (
+    get_event_authority_pda,
+    get_instruction_discriminator,
+    get_program_derived_address,
+)
 from fragments.solana_rpc import init_rpc_client
 from fragments.solana_transaction import create_tx_with_fee_payer_and_lifetime
 
 
+ED25519_PROGRAM_ID = Pubkey.from_string("Ed25519SigVerify111111111111111111111111111")
+INSTRUCTIONS_SYSVAR_ID = Pubkey.from_string("Sysvar1nstructions1111111111111111111111111")
+
+
 class RegistryStateAccount(TypedDict):
     authority: Pubkey
     registration_count: int
+    fifo_only: bool
+    next_to_confirm: int
+    approvers: list[Pubkey]
+    approval_threshold: int
+    allowlist_root: Optional[bytes]
+    version: int
+    confirmed_count: int
+    pending_count: int
+    approver_epoch: int
 
 
 class RegistrationAccount(TypedDict):
@@ -20,11 +37,24 @@ class RegistrationAccount(TypedDict):
     registration_index: int
     registered_at: int
     confirmed_at: Optional[int]
+    approvals: int
+    version: int
+    approver_epoch: int
 
 
 registry_state_schema = Struct(
     "authority" / Bytes(32),
     "registration_count" / Int64ul,
+    "fifo_only" / Flag,
+    "next_to_confirm" / Int64ul,
+    "approvers" / PrefixedArray(Int32ul, Bytes(32)),
+    "approval_threshold" / Int8ul,
+    "allowlist_root_present" / Flag,
+    "allowlist_root" / If(lambda ctx: ctx.allowlist_root_present, Bytes(32)),
+    "version" / Int8ul,
+    "confirmed_count" / Int64ul,
+    "pending_count" / Int64ul,
+    "approver_epoch" / Int64ul,
 )
 
 registration_schema = Struct(
@@ -33,6 +63,26 @@ registration_schema = Struct(
     "registered_at" / Int64ul,
     "confirmed_at_present" / Flag,
     "confirmed_at" / If(lambda ctx: ctx.confirmed_at_present, Int64ul),
+    "approvals" / Int8ul,
+    "version" / Int8ul,
+    "approver_epoch" / Int64ul,
+)
+
+
+proof_schema = PrefixedArray(Int32ul, Bytes(32))
+approvers_schema = PrefixedArray(Int32ul, Bytes(32))
+
+# One signature, with its public key and message in the same instruction (`0xFFFF`), which follow these offsets
+ed25519_offsets_schema = Struct(
+    "signature_count" / Int8ul,
+    "padding" / Int8ul,
+    "signature_offset" / Int16ul,
+    "signature_instruction_index" / Int16ul,
+    "public_key_offset" / Int16ul,
+    "public_key_instruction_index" / Int16ul,
+    "message_offset" / Int16ul,
+    "message_size" / Int16ul,
+    "message_instruction_index" / Int16ul,
 )
 
 
@@ -46,7 +96,44 @@ def get_program_data_address(program_address: Pubkey) -> Pubkey:
     return pda
 
 
-async def initialise_registry(authority: Keypair, program_address: Pubkey) -> Signature:
+def get_voucher_nonce_pda(program_address: Pubkey, nonce: int) -> Pubkey:
+    pda, _ = Pubkey.find_program_address([b"voucher_nonce", Int64ul.build(nonce)], program_address)
+    return pda
+
+
+# `registrant || registry_state || expiry_slot || nonce`, as the program checks it
+def get_voucher_message(program_address: Pubkey, registrant_address: Pubkey, expiry_slot: int, nonce: int) -> bytes:
+    return (
+        bytes(registrant_address)
+        + bytes(get_registry_state_pda(program_address))
+        + Int64ul.build(expiry_slot)
+        + Int64ul.build(nonce)
+    )
+
+
+def get_ed25519_instruction(public_key: Pubkey, signature: bytes, message: bytes) -> Instruction:
+    public_key_offset = ed25519_offsets_schema.sizeof()
+    signature_offset = public_key_offset + 32
+    message_offset = signature_offset + 64
+    offsets = ed25519_offsets_schema.build(
+        dict(
+            signature_count=1,
+            padding=0,
+            signature_offset=signature_offset,
+            signature_instruction_index=0xFFFF,
+            public_key_offset=public_key_offset,
+            public_key_instruction_index=0xFFFF,
+            message_offset=message_offset,
+            message_size=len(message),
+            message_instruction_index=0xFFFF,
+        )
+    )
+    return Instruction(
+        program_id=ED25519_PROGRAM_ID, data=offsets + bytes(public_key) + signature + message, accounts=[]
+    )
+
+
+async def initialise_registry(authority: Keypair, program_address: Pubkey, fifo_only: bool = False) -> Signature:
     discriminator = get_instruction_discriminator("initialise_registry", "register")
     payer = authority.pubkey()
     registry_state_pda = get_registry_state_pda(program_address)
@@ -54,12 +141,14 @@ async def initialise_registry(authority: Keypair, program_address: Pubkey) -> Si
     client = init_rpc_client()
     instruction = Instruction(
         program_id=program_address,
-        data=discriminator,
+        data=discriminator + Flag.build(fifo_only),
         accounts=[
             AccountMeta(pubkey=payer, is_signer=True, is_writable=True),
             AccountMeta(pubkey=registry_state_pda, is_signer=False, is_writable=True),
             AccountMeta(pubkey=program_data_address, is_signer=False, is_writable=False),
             AccountMeta(pubkey=SYSTEM_PROGRAM_ID, is_signer=False, is_writable=False),
+            AccountMeta(pubkey=get_event_authority_pda(program_address), is_signer=False, is_writable=False),
+            AccountMeta(pubkey=program_address, is_signer=False, is_writable=False),
         ],
     )
     tx = await create_tx_with_fee_payer_and_lifetime(authority, instruction)
@@ -67,23 +156,34 @@ async def initialise_registry(authority: Keypair, program_address: Pubkey) -> Si
     return response.value
 
 
-async def register(registrant: Keypair, program_address: Pubkey) -> Signature:
+# `proof` is only required when the registry has an allowlist; see the `allowlist` crate.
+# `payer` covers the registration rent, e.g. a sponsor wallet; the registrant still signs.
+async def register(
+    registrant: Keypair,
+    program_address: Pubkey,
+    proof: Optional[list[bytes]] = None,
+    payer: Optional[Keypair] = None,
+) -> Signature:
     discriminator = get_instruction_discriminator("register", "register")
-    payer = registrant.pubkey()
+    payer = payer or registrant
+    is_sponsored = payer.pubkey() != registrant.pubkey()
     registry_state_pda = get_registry_state_pda(program_address)
-    registration_pda = get_program_derived_address(payer, program_address, "registration")
+    registration_pda = get_program_derived_address(registrant.pubkey(), program_address, "registration")
     client = init_rpc_client()
     instruction = Instruction(
         program_id=program_address,
-        data=discriminator,
+        data=discriminator + proof_schema.build(proof or []),
         accounts=[
-            AccountMeta(pubkey=payer, is_signer=True, is_writable=True),
+            AccountMeta(pubkey=payer.pubkey(), is_signer=True, is_writable=True),
+            AccountMeta(pubkey=registrant.pubkey(), is_signer=True, is_writable=False),
             AccountMeta(pubkey=registry_state_pda, is_signer=False, is_writable=True),
             AccountMeta(pubkey=registration_pda, is_signer=False, is_writable=True),
             AccountMeta(pubkey=SYSTEM_PROGRAM_ID, is_signer=False, is_writable=False),
+            AccountMeta(pubkey=get_event_authority_pda(program_address), is_signer=False, is_writable=False),
+            AccountMeta(pubkey=program_address, is_signer=False, is_writable=False),
         ],
     )
-    tx = await create_tx_with_fee_payer_and_lifetime(registrant, instruction)
+    tx = await create_tx_with_fee_payer_and_lifetime(payer, instruction, [registrant] if is_sponsored else None)
     response = await client.send_transaction(tx)
     return response.value
 
@@ -97,9 +197,11 @@ async def confirm_registration(authority: Keypair, program_address: Pubkey, regi
         program_id=program_address,
         data=discriminator,
         accounts=[
-            AccountMeta(pubkey=registry_state_pda, is_signer=False, is_writable=False),
+            AccountMeta(pubkey=registry_state_pda, is_signer=False, is_writable=True),
             AccountMeta(pubkey=authority.pubkey(), is_signer=True, is_writable=False),
             AccountMeta(pubkey=registration_pda, is_signer=False, is_writable=True),
+            AccountMeta(pubkey=get_event_authority_pda(program_address), is_signer=False, is_writable=False),
+            AccountMeta(pubkey=program_address, is_signer=False, is_writable=False),
         ],
     )
     tx = await create_tx_with_fee_payer_and_lifetime(authority, instruction)
@@ -107,6 +209,124 @@ async def confirm_registration(authority: Keypair, program_address: Pubkey, regi
     return response.value
 
 
+# Hands confirmation to `approvers`, any `approval_threshold` of whom confirm a registration between them.
+# Pass no approvers and a zero threshold to hand it back to the authority.
+async def configure_approvers(
+    authority: Keypair, program_address: Pubkey, approvers: list[Pubkey], approval_threshold: int
+) -> Signature:
+    discriminator = get_instruction_discriminator("configure_approvers", "register")
+    registry_state_pda = get_registry_state_pda(program_address)
+    args = approvers_schema.build([bytes(approver) for approver in approvers]) + Int8ul.build(approval_threshold)
+    client = init_rpc_client()
+    instruction = Instruction(
+        program_id=program_address,
+        data=discriminator + args,
+        accounts=[
+            AccountMeta(pubkey=registry_state_pda, is_signer=False, is_writable=True),
+            AccountMeta(pubkey=authority.pubkey(), is_signer=True, is_writable=False),
+            AccountMeta(pubkey=get_event_authority_pda(program_address), is_signer=False, is_writable=False),
+            AccountMeta(pubkey=program_address, is_signer=False, is_writable=False),
+        ],
+    )
+    tx = await create_tx_with_fee_payer_and_lifetime(authority, instruction)
+    response = await client.send_transaction(tx)
+    return response.value
+
+
+# The approval that reaches the registry's threshold confirms the registration
+async def approve_registration(approver: Keypair, program_address: Pubkey, registrant_address: Pubkey) -> Signature:
+    discriminator = get_instruction_discriminator("approve_registration", "register")
+    registry_state_pda = get_registry_state_pda(program_address)
+    registration_pda = get_program_derived_address(registrant_address, program_address, "registration")
+    client = init_rpc_client()
+    instruction = Instruction(
+        program_id=program_address,
+        data=discriminator,
+        accounts=[
+            AccountMeta(pubkey=registry_state_pda, is_signer=False, is_writable=True),
+            AccountMeta(pubkey=approver.pubkey(), is_signer=True, is_writable=False),
+            AccountMeta(pubkey=registration_pda, is_signer=False, is_writable=True),
+            AccountMeta(pubkey=get_event_authority_pda(program_address), is_signer=False, is_writable=False),
+            AccountMeta(pubkey=program_address, is_signer=False, is_writable=False),
+        ],
+    )
+    tx = await create_tx_with_fee_payer_and_lifetime(approver, instruction)
+    response = await client.send_transaction(tx)
+    return response.value
+
+
+# `allowlist_root` is built by the `allowlist` crate, pass `None` to open the registry to anyone
+async def set_allowlist_root(authority: Keypair, program_address: Pubkey, allowlist_root: Optional[bytes]) -> Signature:
+    discriminator = get_instruction_discriminator("set_allowlist_root", "register")
+    registry_state_pda = get_registry_state_pda(program_address)
+    client = init_rpc_client()
+    instruction = Instruction(
+        program_id=program_address,
+        data=discriminator + (b"\x00" if allowlist_root is None else b"\x01" + allowlist_root),
+        accounts=[
+            AccountMeta(pubkey=registry_state_pda, is_signer=False, is_writable=True),
+            AccountMeta(pubkey=authority.pubkey(), is_signer=True, is_writable=False),
+            AccountMeta(pubkey=get_event_authority_pda(program_address), is_signer=False, is_writable=False),
+            AccountMeta(pubkey=program_address, is_signer=False, is_writable=False),
+        ],
+    )
+    tx = await create_tx_with_fee_payer_and_lifetime(authority, instruction)
+    response = await client.send_transaction(tx)
+    return response.value
+
+
+# The registry authority vouches for `registrant_address` off-chain, the voucher is good until `expiry_slot`
+# and `nonce` can only be used once.
+def sign_voucher(
+    authority: Keypair, program_address: Pubkey, registrant_address: Pubkey, expiry_slot: int, nonce: int
+) -> bytes:
+    message = get_voucher_message(program_address, registrant_address, expiry_slot, nonce)
+    return bytes(authority.sign_message(message))
+
+
+# Registers with a `voucher_signature` from `sign_voucher`, checked by an Ed25519 program instruction sent
+# ahead of it. Vouched registrants skip the allowlist. `payer` covers the rent, as for `register`.
+async def register_with_voucher(
+    registrant: Keypair,
+    program_address: Pubkey,
+    authority_address: Pubkey,
+    expiry_slot: int,
+    nonce: int,
+    voucher_signature: bytes,
+    payer: Optional[Keypair] = None,
+) -> Signature:
+    discriminator = get_instruction_discriminator("register_with_voucher", "register")
+    payer = payer or registrant
+    is_sponsored = payer.pubkey() != registrant.pubkey()
+    registry_state_pda = get_registry_state_pda(program_address)
+    registration_pda = get_program_derived_address(registrant.pubkey(), program_address, "registration")
+    message = get_voucher_message(program_address, registrant.pubkey(), expiry_slot, nonce)
+    client = init_rpc_client()
+    instruction = Instruction(
+        program_id=program_address,
+        data=discriminator + Int64ul.build(expiry_slot) + Int64ul.build(nonce),
+        accounts=[
+            AccountMeta(pubkey=payer.pubkey(), is_signer=True, is_writable=True),
+            AccountMeta(pubkey=registrant.pubkey(), is_signer=True, is_writable=False),
+            AccountMeta(pubkey=registry_state_pda, is_signer=False, is_writable=True),
+            AccountMeta(pubkey=registration_pda, is_signer=False, is_writable=True),
+            AccountMeta(pubkey=get_voucher_nonce_pda(program_address, nonce), is_signer=False, is_writable=True),
+            AccountMeta(pubkey=INSTRUCTIONS_SYSVAR_ID, is_signer=False, is_writable=False),
+            AccountMeta(pubkey=SYSTEM_PROGRAM_ID, is_signer=False, is_writable=False),
+            AccountMeta(pubkey=get_event_authority_pda(program_address), is_signer=False, is_writable=False),
+            AccountMeta(pubkey=program_address, is_signer=False, is_writable=False),
+        ],
+    )
+    tx = await create_tx_with_fee_payer_and_lifetime(
+        payer,
+        instruction,
+        [registrant] if is_sponsored else None,
+        [get_ed25519_instruction(authority_address, voucher_signature, message)],
+    )
+    response = await client.send_transaction(tx)
+    return response.value
+
+
 async def get_registry_state_account(program_address: Pubkey) -> RegistryStateAccount:
     client = init_rpc_client()
     registry_state_pda = get_registry_state_pda(program_address)
@@ -122,6 +342,15 @@ async def get_registry_state_account(program_address: Pubkey) -> RegistryStateAc
     return RegistryStateAccount(
         authority=Pubkey.from_bytes(parsed.authority),
         registration_count=parsed.registration_count,
+        fifo_only=parsed.fifo_only,
+        next_to_confirm=parsed.next_to_confirm,
+        approvers=[Pubkey.from_bytes(approver) for approver in parsed.approvers],
+        approval_threshold=parsed.approval_threshold,
+        allowlist_root=parsed.allowlist_root,
+        version=parsed.version,
+        confirmed_count=parsed.confirmed_count,
+        pending_count=parsed.pending_count,
+        approver_epoch=parsed.approver_epoch,
     )
 
 
@@ -142,4 +371,7 @@ async def get_registration_account(registrant_address: Pubkey, program_address:
         registration_index=parsed.registration_index,
         registered_at=parsed.registered_at,
         confirmed_at=parsed.confirmed_at,
+        approvals=parsed.approvals,
+        version=parsed.version,
+        approver_epoch=parsed.approver_epoch,
     )
@@ -1,25 +1,34 @@
 import assert from "node:assert/strict";
+import { createHash, randomBytes } from "node:crypto";
 import { readFileSync } from "node:fs";
 import test, { before, describe } from "node:test";
 import process from "node:process";
 import { sendAndConfirmAirdrop } from "../solana_airdrop/solana_airdrop_utils";
 import {
+  approveRegistration,
+  configureApprovers,
   confirmRegistration,
   getRegistrationAccount,
   getRegistryStateAccount,
   initialiseRegistry,
   register,
+  registerWithVoucher,
+  setAllowlistRoot,
+  signVoucher,
 } from "./solana_register_interface";
 import {
   Address,
   address,
   createKeyPairSignerFromBytes,
   generateKeyPairSigner,
+  getAddressEncoder,
   isNone,
   isSome,
   KeyPairSigner,
+  some,
 } from "@solana/kit";
 import { getEnvVar } from "../env_vars/env_vars_utils";
+import { initRpcClient } from "../solana_rpc/solana_rpc_utils";
 import { confirmRecentSignature } from "../solana_transaction/solana_transaction_utils";
 import { LAMPORTS_PER_SOL } from "@solana/web3.js";
 
@@ -85,6 +94,20 @@ describe("solana program register interface", () => {
     assert.ok(isNone(registration.confirmed_at));
   });
 
+  test("sponsor pays for a registration", async () => {
+    const sponsor = await generateKeyPairSigner();
+    await sendAndConfirmAirdrop(sponsor.address, BigInt(LAMPORTS_PER_SOL));
+
+    // The registrant holds no SOL, the sponsor covers the fee and rent
+    const registrant = await generateKeyPairSigner();
+
+    const registerTxSig = await register(registrant, programAddress, [], sponsor);
+    await confirmRecentSignature(registerTxSig);
+
+    const registration = await getRegistrationAccount(registrant.address, programAddress);
+    assert.strictEqual(registration.registrant, registrant.address);
+  });
+
   test("confirm registration", async () => {
     const registrant = await generateKeyPairSigner();
     await sendAndConfirmAirdrop(registrant.address, BigInt(LAMPORTS_PER_SOL));
@@ -99,6 +122,30 @@ describe("solana program register interface", () => {
     assert.ok(isSome(registration.confirmed_at));
   });
 
+  test("registry counts track registrations and confirmations", async () => {
+    const registrant = await generateKeyPairSigner();
+    await sendAndConfirmAirdrop(registrant.address, BigInt(LAMPORTS_PER_SOL));
+
+    const registerTxSig = await register(registrant, programAddress);
+    await confirmRecentSignature(registerTxSig);
+
+    const registeredState = await getRegistryStateAccount(programAddress);
+    assert.strictEqual(
+      registeredState.confirmed_count + registeredState.pending_count,
+      registeredState.registration_count,
+    );
+
+    const confirmTxSig = await confirmRegistration(authority, programAddress, registrant.address);
+    await confirmRecentSignature(confirmTxSig);
+
+    const confirmedState = await getRegistryStateAccount(programAddress);
+    assert.ok(confirmedState.confirmed_count > registeredState.confirmed_count);
+    assert.strictEqual(
+      confirmedState.confirmed_count + confirmedState.pending_count,
+      confirmedState.registration_count,
+    );
+  });
+
   test("register multiple registrants", async () => {
     const registryStateBefore = await getRegistryStateAccount(programAddress);
     const countBefore = registryStateBefore.registration_count;
@@ -141,6 +188,92 @@ describe("solana program register interface", () => {
     });
   });
 
+  test("approvers confirm a registration between them", async () => {
+    const approver = await generateKeyPairSigner();
+    const registrant = await generateKeyPairSigner();
+    await sendAndConfirmAirdrop(approver.address, BigInt(LAMPORTS_PER_SOL));
+    await sendAndConfirmAirdrop(registrant.address, BigInt(LAMPORTS_PER_SOL));
+
+    const configureTxSig = await configureApprovers(authority, programAddress, [approver.address], 1);
+    await confirmRecentSignature(configureTxSig);
+
+    const registryState = await getRegistryStateAccount(programAddress);
+    assert.deepStrictEqual(registryState.approvers, [approver.address]);
+    assert.strictEqual(registryState.approval_threshold, 1);
+
+    const registerTxSig = await register(registrant, programAddress);
+    await confirmRecentSignature(registerTxSig);
+
+    const approveTxSig = await approveRegistration(approver, programAddress, registrant.address);
+    await confirmRecentSignature(approveTxSig);
+
+    const registration = await getRegistrationAccount(registrant.address, programAddress);
+    assert.strictEqual(registration.approvals, 1);
+    assert.strictEqual(registration.approver_epoch, registryState.approver_epoch);
+    assert.ok(isSome(registration.confirmed_at));
+
+    // The registry is shared across tests, so confirmation goes back to the authority
+    const resetTxSig = await configureApprovers(authority, programAddress, [], 0);
+    await confirmRecentSignature(resetTxSig);
+  });
+
+  test("register against an allowlist root", async () => {
+    const invitee = await generateKeyPairSigner();
+    const uninvited = await generateKeyPairSigner();
+    await sendAndConfirmAirdrop(invitee.address, BigInt(LAMPORTS_PER_SOL));
+    await sendAndConfirmAirdrop(uninvited.address, BigInt(LAMPORTS_PER_SOL));
+
+    // A single key allowlist's root is its leaf, `sha256(0x00 || pubkey)`, so the invitee needs no proof
+    const allowlistRoot = new Uint8Array(
+      createHash("sha256").update(new Uint8Array([0])).update(getAddressEncoder().encode(invitee.address)).digest(),
+    );
+    const setRootTxSig = await setAllowlistRoot(authority, programAddress, allowlistRoot);
+    await confirmRecentSignature(setRootTxSig);
+
+    const registryState = await getRegistryStateAccount(programAddress);
+    assert.deepStrictEqual(registryState.allowlist_root, some(allowlistRoot));
+
+    const registerTxSig = await register(invitee, programAddress);
+    await confirmRecentSignature(registerTxSig);
+
+    const registration = await getRegistrationAccount(invitee.address, programAddress);
+    assert.strictEqual(registration.registrant, invitee.address);
+
+    await assert.rejects(register(uninvited, programAddress), (err: ProgramError) => {
+      const errorMessage = err.context.logs.join(" ");
+      assert.match(errorMessage, /NotOnAllowlist/);
+      return true;
+    });
+
+    // The registry is shared across tests, so it opens up to anyone again
+    const clearRootTxSig = await setAllowlistRoot(authority, programAddress, null);
+    await confirmRecentSignature(clearRootTxSig);
+  });
+
+  test("register with a voucher from the authority", async () => {
+    const registrant = await generateKeyPairSigner();
+    await sendAndConfirmAirdrop(registrant.address, BigInt(LAMPORTS_PER_SOL));
+    const recentSlot = await initRpcClient().getSlot({ commitment: "confirmed" }).send();
+    const expirySlot = recentSlot + 100n;
+    // Each nonce can only be used once, a random one keeps reruns against the same validator passing
+    const nonce = randomBytes(8).readBigUInt64LE();
+
+    const voucherSignature = await signVoucher(authority, programAddress, registrant.address, expirySlot, nonce);
+    const registerTxSig = await registerWithVoucher(
+      registrant,
+      programAddress,
+      authority.address,
+      expirySlot,
+      nonce,
+      voucherSignature,
+    );
+    await confirmRecentSignature(registerTxSig);
+
+    const registration = await getRegistrationAccount(registrant.address, programAddress);
+    assert.strictEqual(registration.registrant, registrant.address);
+    assert.ok(isNone(registration.confirmed_at));
+  });
+
   test("get registration account before it exists", async () => {
     const registrant = await generateKeyPairSigner();
 
@@ -1,22 +1,39 @@
 import {
   AccountRole,
   Address,
+  address,
   appendTransactionMessageInstruction,
+  appendTransactionMessageInstructions,
   assertAccountExists,
   Decoder,
   fetchEncodedAccount,
+  fixDecoderSize,
+  fixEncoderSize,
   getAddressDecoder,
   getAddressEncoder,
+  getArrayDecoder,
+  getArrayEncoder,
+  getBooleanDecoder,
+  getBooleanEncoder,
+  getBytesDecoder,
+  getBytesEncoder,
   getOptionDecoder,
+  getOptionEncoder,
   getProgramDerivedAddress,
   getStructDecoder,
   getU64Decoder,
+  getU64Encoder,
+  getU8Decoder,
+  getU8Encoder,
   KeyPairSigner,
   offsetDecoder,
   Option,
+  ReadonlyUint8Array,
+  signBytes,
 } from "@solana/kit";
 import {
   BPF_LOADER_UPGRADEABLE_ID,
+  getEventAuthorityPda,
   getInstructionDiscriminator,
   getPda,
   skipAnchorDiscriminator,
@@ -29,21 +46,28 @@ import {
   signAndSendTransaction,
 } from "../solana_transaction/solana_transaction_utils";
 
-export const initialiseRegistry = async (authority: KeyPairSigner, programAddress: Address) => {
+export const initialiseRegistry = async (
+  authority: KeyPairSigner,
+  programAddress: Address,
+  fifoOnly = false,
+) => {
   const discriminator = getInstructionDiscriminator("initialise_registry", "register");
   const payer = authority.address;
   const registryStatePda = await getRegistryStatePda(programAddress);
   const programDataAddress = await getProgramDataAddress(programAddress);
+  const eventAuthorityPda = await getEventAuthorityPda(programAddress);
   const baseTx = await createBaseTxWithFeePayerAndLifetime(payer);
 
   const tx = appendTransactionMessageInstruction({
     programAddress,
-    data: discriminator,
+    data: Buffer.concat([discriminator, Buffer.from(getBooleanEncoder().encode(fifoOnly))]),
     accounts: [
       { address: payer, role: AccountRole.WRITABLE_SIGNER },
       { address: registryStatePda, role: AccountRole.WRITABLE },
       { address: programDataAddress, role: AccountRole.READONLY },
       { address: SYSTEM_PROGRAM_ADDRESS, role: AccountRole.READONLY },
+      { address: eventAuthorityPda, role: AccountRole.READONLY },
+      { address: programAddress, role: AccountRole.READONLY },
     ],
   }, baseTx);
 
@@ -52,25 +76,37 @@ export const initialiseRegistry = async (authority: KeyPairSigner, programAddres
   return sig;
 };
 
-export const register = async (registrant: KeyPairSigner, programAddress: Address) => {
+// `proof` is only required when the registry has an allowlist; see the `allowlist` crate.
+// `payer` covers the registration rent, e.g. a sponsor wallet; the registrant still signs.
+export const register = async (
+  registrant: KeyPairSigner,
+  programAddress: Address,
+  proof: Uint8Array[] = [],
+  payer: KeyPairSigner = registrant,
+) => {
   const discriminator = getInstructionDiscriminator("register", "register");
-  const payer = registrant.address;
   const registryStatePda = await getRegistryStatePda(programAddress);
-  const registrationPda = await getPda(payer, programAddress, "registration");
-  const baseTx = await createBaseTxWithFeePayerAndLifetime(payer);
+  const registrationPda = await getPda(registrant.address, programAddress, "registration");
+  const eventAuthorityPda = await getEventAuthorityPda(programAddress);
+  const baseTx = await createBaseTxWithFeePayerAndLifetime(payer.address);
 
   const tx = appendTransactionMessageInstruction({
     programAddress,
-    data: discriminator,
+    data: Buffer.concat([discriminator, Buffer.from(proofEncoder.encode(proof))]),
     accounts: [
-      { address: payer, role: AccountRole.WRITABLE_SIGNER },
+      { address: payer.address, role: AccountRole.WRITABLE_SIGNER },
+      { address: registrant.address, role: AccountRole.READONLY_SIGNER },
       { address: registryStatePda, role: AccountRole.WRITABLE },
       { address: registrationPda, role: AccountRole.WRITABLE },
       { address: SYSTEM_PROGRAM_ADDRESS, role: AccountRole.READONLY },
+      { address: eventAuthorityPda, role: AccountRole.READONLY },
+      { address: programAddress, role: AccountRole.READONLY },
     ],
   }, baseTx);
 
-  const sig = await signAndSendTransaction(tx, registrant.keyPair);
+  const sig = payer.address === registrant.address
+    ? await signAndSendTransaction(tx, registrant.keyPair)
+    : await signAndSendTransaction(tx, payer.keyPair, registrant.keyPair);
 
   return sig;
 };
@@ -83,15 +119,18 @@ export const confirmRegistration = async (
   const discriminator = getInstructionDiscriminator("confirm_registration", "register");
   const registryStatePda = await getRegistryStatePda(programAddress);
   const registrationPda = await getPda(registrantAddress, programAddress, "registration");
+  const eventAuthorityPda = await getEventAuthorityPda(programAddress);
   const baseTx = await createBaseTxWithFeePayerAndLifetime(authority.address);
 
   const tx = appendTransactionMessageInstruction({
     programAddress,
     data: discriminator,
     accounts: [
-      { address: registryStatePda, role: AccountRole.READONLY },
+      { address: registryStatePda, role: AccountRole.WRITABLE },
       { address: authority.address, role: AccountRole.READONLY_SIGNER },
       { address: registrationPda, role: AccountRole.WRITABLE },
+      { address: eventAuthorityPda, role: AccountRole.READONLY },
+      { address: programAddress, role: AccountRole.READONLY },
     ],
   }, baseTx);
 
@@ -100,6 +139,164 @@ export const confirmRegistration = async (
   return sig;
 };
 
+// Hands confirmation to `approvers`, any `approvalThreshold` of whom confirm a registration between them.
+// Pass no approvers and a zero threshold to hand it back to the authority.
+export const configureApprovers = async (
+  authority: KeyPairSigner,
+  programAddress: Address,
+  approvers: Address[],
+  approvalThreshold: number,
+) => {
+  const discriminator = getInstructionDiscriminator("configure_approvers", "register");
+  const registryStatePda = await getRegistryStatePda(programAddress);
+  const eventAuthorityPda = await getEventAuthorityPda(programAddress);
+  const baseTx = await createBaseTxWithFeePayerAndLifetime(authority.address);
+
+  const tx = appendTransactionMessageInstruction({
+    programAddress,
+    data: Buffer.concat([
+      discriminator,
+      Buffer.from(approversEncoder.encode(approvers)),
+      Buffer.from(getU8Encoder().encode(approvalThreshold)),
+    ]),
+    accounts: [
+      { address: registryStatePda, role: AccountRole.WRITABLE },
+      { address: authority.address, role: AccountRole.READONLY_SIGNER },
+      { address: eventAuthorityPda, role: AccountRole.READONLY },
+      { address: programAddress, role: AccountRole.READONLY },
+    ],
+  }, baseTx);
+
+  const sig = await signAndSendTransaction(tx, authority.keyPair);
+
+  return sig;
+};
+
+// The approval that reaches the registry's threshold confirms the registration
+export const approveRegistration = async (
+  approver: KeyPairSigner,
+  programAddress: Address,
+  registrantAddress: Address,
+) => {
+  const discriminator = getInstructionDiscriminator("approve_registration", "register");
+  const registryStatePda = await getRegistryStatePda(programAddress);
+  const registrationPda = await getPda(registrantAddress, programAddress, "registration");
+  const eventAuthorityPda = await getEventAuthorityPda(programAddress);
+  const baseTx = await createBaseTxWithFeePayerAndLifetime(approver.address);
+
+  const tx = appendTransactionMessageInstruction({
+    programAddress,
+    data: discriminator,
+    accounts: [
+      { address: registryStatePda, role: AccountRole.WRITABLE },
+      { address: approver.address, role: AccountRole.READONLY_SIGNER },
+      { address: registrationPda, role: AccountRole.WRITABLE },
+      { address: eventAuthorityPda, role: AccountRole.READONLY },
+      { address: programAddress, role: AccountRole.READONLY },
+    ],
+  }, baseTx);
+
+  const sig = await signAndSendTransaction(tx, approver.keyPair);
+
+  return sig;
+};
+
+// `allowlistRoot` is built by the `allowlist` crate, pass `null` to open the registry to anyone
+export const setAllowlistRoot = async (
+  authority: KeyPairSigner,
+  programAddress: Address,
+  allowlistRoot: Uint8Array | null,
+) => {
+  const discriminator = getInstructionDiscriminator("set_allowlist_root", "register");
+  const registryStatePda = await getRegistryStatePda(programAddress);
+  const eventAuthorityPda = await getEventAuthorityPda(programAddress);
+  const baseTx = await createBaseTxWithFeePayerAndLifetime(authority.address);
+
+  const tx = appendTransactionMessageInstruction({
+    programAddress,
+    data: Buffer.concat([discriminator, Buffer.from(allowlistRootEncoder.encode(allowlistRoot))]),
+    accounts: [
+      { address: registryStatePda, role: AccountRole.WRITABLE },
+      { address: authority.address, role: AccountRole.READONLY_SIGNER },
+      { address: eventAuthorityPda, role: AccountRole.READONLY },
+      { address: programAddress, role: AccountRole.READONLY },
+    ],
+  }, baseTx);
+
+  const sig = await signAndSendTransaction(tx, authority.keyPair);
+
+  return sig;
+};
+
+// The registry authority vouches for `registrantAddress` off-chain, the voucher is good until `expirySlot`
+// and `nonce` can only be used once.
+export const signVoucher = async (
+  authority: KeyPairSigner,
+  programAddress: Address,
+  registrantAddress: Address,
+  expirySlot: bigint,
+  nonce: bigint,
+) => {
+  const message = await getVoucherMessage(programAddress, registrantAddress, expirySlot, nonce);
+
+  return await signBytes(authority.keyPair.privateKey, message);
+};
+
+// Registers with a `voucherSignature` from `signVoucher`, checked by an Ed25519 program instruction sent
+// ahead of it. Vouched registrants skip the allowlist. `payer` covers the rent, as for `register`.
+export const registerWithVoucher = async (
+  registrant: KeyPairSigner,
+  programAddress: Address,
+  authorityAddress: Address,
+  expirySlot: bigint,
+  nonce: bigint,
+  voucherSignature: Uint8Array,
+  payer: KeyPairSigner = registrant,
+) => {
+  const discriminator = getInstructionDiscriminator("register_with_voucher", "register");
+  const registryStatePda = await getRegistryStatePda(programAddress);
+  const registrationPda = await getPda(registrant.address, programAddress, "registration");
+  const [voucherNoncePda] = await getProgramDerivedAddress({
+    programAddress,
+    seeds: [Buffer.from("voucher_nonce"), getU64Encoder().encode(nonce)],
+  });
+  const eventAuthorityPda = await getEventAuthorityPda(programAddress);
+  const message = await getVoucherMessage(programAddress, registrant.address, expirySlot, nonce);
+  const baseTx = await createBaseTxWithFeePayerAndLifetime(payer.address);
+
+  const tx = appendTransactionMessageInstructions([
+    {
+      programAddress: ED25519_PROGRAM_ADDRESS,
+      data: getEd25519InstructionData(authorityAddress, voucherSignature, message),
+    },
+    {
+      programAddress,
+      data: Buffer.concat([
+        discriminator,
+        Buffer.from(getU64Encoder().encode(expirySlot)),
+        Buffer.from(getU64Encoder().encode(nonce)),
+      ]),
+      accounts: [
+        { address: payer.address, role: AccountRole.WRITABLE_SIGNER },
+        { address: registrant.address, role: AccountRole.READONLY_SIGNER },
+        { address: registryStatePda, role: AccountRole.WRITABLE },
+        { address: registrationPda, role: AccountRole.WRITABLE },
+        { address: voucherNoncePda, role: AccountRole.WRITABLE },
+        { address: INSTRUCTIONS_SYSVAR_ADDRESS, role: AccountRole.READONLY },
+        { address: SYSTEM_PROGRAM_ADDRESS, role: AccountRole.READONLY },
+        { address: eventAuthorityPda, role: AccountRole.READONLY },
+        { address: programAddress, role: AccountRole.READONLY },
+      ],
+    },
+  ], baseTx);
+
+  const sig = payer.address === registrant.address
+    ? await signAndSendTransaction(tx, registrant.keyPair)
+    : await signAndSendTransaction(tx, payer.keyPair, registrant.keyPair);
+
+  return sig;
+};
+
 export const getRegistryStateAccount = async (programAddress: Address) => {
   const client = initRpcClient();
   const registryStatePda = await getRegistryStatePda(programAddress);
@@ -154,13 +351,69 @@ const getRegistryStatePda = async (programAddress: Address): Promise<Address> =>
   return pda;
 };
 
+// `registrant || registry_state || expiry_slot || nonce`, as the program checks it
+const getVoucherMessage = async (
+  programAddress: Address,
+  registrantAddress: Address,
+  expirySlot: bigint,
+  nonce: bigint,
+) => {
+  const registryStatePda = await getRegistryStatePda(programAddress);
+
+  return Buffer.concat([
+    getAddressEncoder().encode(registrantAddress),
+    getAddressEncoder().encode(registryStatePda),
+    getU64Encoder().encode(expirySlot),
+    getU64Encoder().encode(nonce),
+  ]);
+};
+
+// One signature, with its public key and message in the same instruction: a count and padding byte, the
+// offsets, then the public key, signature and message they point to.
+const getEd25519InstructionData = (publicKey: Address, signature: Uint8Array, message: Uint8Array) => {
+  const publicKeyOffset = 16;
+  const signatureOffset = publicKeyOffset + 32;
+  const messageOffset = signatureOffset + 64;
+  const header = Buffer.alloc(publicKeyOffset);
+  header.writeUInt8(1, 0);
+  [signatureOffset, 0xffff, publicKeyOffset, 0xffff, messageOffset, message.length, 0xffff]
+    .forEach((value, i) => header.writeUInt16LE(value, 2 + 2 * i));
+
+  return Buffer.concat([header, getAddressEncoder().encode(publicKey), signature, message]);
+};
+
+const ED25519_PROGRAM_ADDRESS = address("Ed25519SigVerify111111111111111111111111111");
+const INSTRUCTIONS_SYSVAR_ADDRESS = address("Sysvar1nstructions1111111111111111111111111");
+
+const proofEncoder = getArrayEncoder(fixEncoderSize(getBytesEncoder(), 32));
+const approversEncoder = getArrayEncoder(getAddressEncoder());
+const allowlistRootEncoder = getOptionEncoder(fixEncoderSize(getBytesEncoder(), 32));
+
 const registryStateDecoder: Decoder<{
   authority: Address;
   registration_count: bigint;
+  fifo_only: boolean;
+  next_to_confirm: bigint;
+  approvers: Address[];
+  approval_threshold: number;
+  allowlist_root: Option<ReadonlyUint8Array>;
+  version: number;
+  confirmed_count: bigint;
+  pending_count: bigint;
+  approver_epoch: bigint;
 }> = offsetDecoder(
   getStructDecoder([
     ["authority", getAddressDecoder()],
     ["registration_count", getU64Decoder()],
+    ["fifo_only", getBooleanDecoder()],
+    ["next_to_confirm", getU64Decoder()],
+    ["approvers", getArrayDecoder(getAddressDecoder())],
+    ["approval_threshold", getU8Decoder()],
+    ["allowlist_root", getOptionDecoder(fixDecoderSize(getBytesDecoder(), 32))],
+    ["version", getU8Decoder()],
+    ["confirmed_count", getU64Decoder()],
+    ["pending_count", getU64Decoder()],
+    ["approver_epoch", getU64Decoder()],
   ]),
   skipAnchorDiscriminator,
 );
@@ -170,12 +423,18 @@ const registrationDecoder: Decoder<{
   registration_index: bigint;
   registered_at: bigint;
   confirmed_at: Option<bigint>;
+  approvals: number;
+  version: number;
+  approver_epoch: bigint;
 }> = offsetDecoder(
   getStructDecoder([
     ["registrant", getAddressDecoder()],
     ["registration_index", getU64Decoder()],
     ["registered_at", getU64Decoder()],
     ["confirmed_at", getOptionDecoder(getU64Decoder())],
+    ["approvals", getU8Decoder()],
+    ["version", getU8Decoder()],
+    ["approver_epoch", getU64Decoder()],
   ]),
   skipAnchorDiscriminator,
 );
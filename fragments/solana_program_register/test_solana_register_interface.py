@@ -1,4 +1,6 @@
+import hashlib
 import os
+import secrets
 import unittest
 from pathlib import Path
 from dotenv import load_dotenv
@@ -8,10 +10,16 @@ from solana.constants import LAMPORTS_PER_SOL
 from solana.rpc.core import RPCException
 from fragments.env_vars import get_env_var
 from fragments.solana_airdrop import send_and_confirm_airdrop
+from fragments.solana_rpc import init_rpc_client
 from fragments.solana_program_register import (
     initialise_registry,
     register,
     confirm_registration,
+    configure_approvers,
+    approve_registration,
+    set_allowlist_root,
+    sign_voucher,
+    register_with_voucher,
     get_registry_state_account,
     get_registration_account,
 )
@@ -72,6 +80,21 @@ class TestSolanaRegisterInterface(unittest.IsolatedAsyncioTestCase):
         self.assertGreater(registration["registered_at"], 0)
         self.assertIsNone(registration["confirmed_at"])
 
+    async def test_sponsor_pays_for_registration(self):
+        sponsor = Keypair()
+        await send_and_confirm_airdrop(sponsor.pubkey(), LAMPORTS_PER_SOL)
+
+        # The registrant holds no SOL, the sponsor covers the fee and rent
+        registrant = Keypair()
+
+        register_tx_sig = await register(registrant, self.program_id, payer=sponsor)
+        instr_confirmed = await confirm_recent_signature(register_tx_sig)
+        if not instr_confirmed:
+            self.fail("Register instruction failed")
+
+        registration = await get_registration_account(registrant.pubkey(), self.program_id)
+        self.assertEqual(registration["registrant"], registrant.pubkey())
+
     async def test_confirm_registration(self):
         registrant = Keypair()
         await send_and_confirm_airdrop(registrant.pubkey(), LAMPORTS_PER_SOL)
@@ -89,6 +112,33 @@ class TestSolanaRegisterInterface(unittest.IsolatedAsyncioTestCase):
         registration = await get_registration_account(registrant.pubkey(), self.program_id)
         self.assertIsNotNone(registration["confirmed_at"])
 
+    async def test_registry_counts_track_registrations_and_confirmations(self):
+        registrant = Keypair()
+        await send_and_confirm_airdrop(registrant.pubkey(), LAMPORTS_PER_SOL)
+
+        register_tx_sig = await register(registrant, self.program_id)
+        instr_confirmed = await confirm_recent_signature(register_tx_sig)
+        if not instr_confirmed:
+            self.fail("Register instruction failed")
+
+        registered_state = await get_registry_state_account(self.program_id)
+        self.assertEqual(
+            registered_state["confirmed_count"] + registered_state["pending_count"],
+            registered_state["registration_count"],
+        )
+
+        confirm_tx_sig = await confirm_registration(self.authority, self.program_id, registrant.pubkey())
+        confirm_confirmed = await confirm_recent_signature(confirm_tx_sig)
+        if not confirm_confirmed:
+            self.fail("Confirm registration instruction failed")
+
+        confirmed_state = await get_registry_state_account(self.program_id)
+        self.assertGreater(confirmed_state["confirmed_count"], registered_state["confirmed_count"])
+        self.assertEqual(
+            confirmed_state["confirmed_count"] + confirmed_state["pending_count"],
+            confirmed_state["registration_count"],
+        )
+
     async def test_register_multiple_registrants(self):
         registry_state_before = await get_registry_state_account(self.program_id)
         count_before = registry_state_before["registration_count"]
@@ -143,3 +193,86 @@ class TestSolanaRegisterInterface(unittest.IsolatedAsyncioTestCase):
 
         error_str = str(cm.exception)
         self.assertRegex(error_str, r"Account .* does not exist")
+
+    async def test_approvers_confirm_registration_between_them(self):
+        approver = Keypair()
+        registrant = Keypair()
+        await send_and_confirm_airdrop(approver.pubkey(), LAMPORTS_PER_SOL)
+        await send_and_confirm_airdrop(registrant.pubkey(), LAMPORTS_PER_SOL)
+
+        configure_tx_sig = await configure_approvers(self.authority, self.program_id, [approver.pubkey()], 1)
+        if not await confirm_recent_signature(configure_tx_sig):
+            self.fail("Configure approvers instruction failed")
+
+        registry_state = await get_registry_state_account(self.program_id)
+        self.assertEqual(registry_state["approvers"], [approver.pubkey()])
+        self.assertEqual(registry_state["approval_threshold"], 1)
+
+        register_tx_sig = await register(registrant, self.program_id)
+        if not await confirm_recent_signature(register_tx_sig):
+            self.fail("Register instruction failed")
+
+        approve_tx_sig = await approve_registration(approver, self.program_id, registrant.pubkey())
+        if not await confirm_recent_signature(approve_tx_sig):
+            self.fail("Approve registration instruction failed")
+
+        registration = await get_registration_account(registrant.pubkey(), self.program_id)
+        self.assertEqual(registration["approvals"], 1)
+        self.assertEqual(registration["approver_epoch"], registry_state["approver_epoch"])
+        self.assertIsNotNone(registration["confirmed_at"])
+
+        # The registry is shared across tests, so confirmation goes back to the authority
+        reset_tx_sig = await configure_approvers(self.authority, self.program_id, [], 0)
+        if not await confirm_recent_signature(reset_tx_sig):
+            self.fail("Configure approvers instruction failed")
+
+    async def test_register_against_allowlist_root(self):
+        invitee = Keypair()
+        uninvited = Keypair()
+        await send_and_confirm_airdrop(invitee.pubkey(), LAMPORTS_PER_SOL)
+        await send_and_confirm_airdrop(uninvited.pubkey(), LAMPORTS_PER_SOL)
+
+        # A single key allowlist's root is its leaf, `sha256(0x00 || pubkey)`, so the invitee needs no proof
+        allowlist_root = hashlib.sha256(b"\x00" + bytes(invitee.pubkey())).digest()
+        set_root_tx_sig = await set_allowlist_root(self.authority, self.program_id, allowlist_root)
+        if not await confirm_recent_signature(set_root_tx_sig):
+            self.fail("Set allowlist root instruction failed")
+
+        registry_state = await get_registry_state_account(self.program_id)
+        self.assertEqual(registry_state["allowlist_root"], allowlist_root)
+
+        register_tx_sig = await register(invitee, self.program_id)
+        if not await confirm_recent_signature(register_tx_sig):
+            self.fail("Register instruction failed")
+
+        registration = await get_registration_account(invitee.pubkey(), self.program_id)
+        self.assertEqual(registration["registrant"], invitee.pubkey())
+
+        with self.assertRaises(RPCException) as cm:
+            await register(uninvited, self.program_id)
+
+        self.assertIn("NotOnAllowlist", str(cm.exception))
+
+        # The registry is shared across tests, so it opens up to anyone again
+        clear_root_tx_sig = await set_allowlist_root(self.authority, self.program_id, None)
+        if not await confirm_recent_signature(clear_root_tx_sig):
+            self.fail("Set allowlist root instruction failed")
+
+    async def test_register_with_voucher_from_authority(self):
+        registrant = Keypair()
+        await send_and_confirm_airdrop(registrant.pubkey(), LAMPORTS_PER_SOL)
+        recent_slot = (await init_rpc_client().get_slot()).value
+        expiry_slot = recent_slot + 100
+        # Each nonce can only be used once, a random one keeps reruns against the same validator passing
+        nonce = secrets.randbits(64)
+
+        voucher_signature = sign_voucher(self.authority, self.program_id, registrant.pubkey(), expiry_slot, nonce)
+        register_tx_sig = await register_with_voucher(
+            registrant, self.program_id, self.authority.pubkey(), expiry_slot, nonce, voucher_signature
+        )
+        if not await confirm_recent_signature(register_tx_sig):
+            self.fail("Register with voucher instruction failed")
+
+        registration = await get_registration_account(registrant.pubkey(), self.program_id)
+        self.assertEqual(registration["registrant"], registrant.pubkey())
+        self.assertIsNone(registration["confirmed_at"])
@@ -1 +1,30 @@
-from .solana_round_interface import initialise_round, get_round_account, activate_round, complete_round
+from .solana_round_interface import (
+    initialise_round,
+    get_round_account,
+    get_next_round_id,
+    initialise_series,
+    roll_series,
+    get_series_account,
+    close_series,
+    get_series_pda,
+    activate_round,
+    complete_round,
+    settle_round,
+    cancel_round,
+    join_round,
+    enter_round,
+    refund_entry,
+    reschedule_round,
+    signal_interest,
+    close_round,
+    close_participant,
+    close_submission,
+    close_interest,
+    draw_winner,
+    commit_submission,
+    reveal_submission,
+    get_participant_account,
+    get_submission_account,
+    submit_score,
+    get_leaderboard_account,
+)
@@ -1,46 +1,291 @@
+import hashlib
 from typing import TypedDict, Optional
 from solders.keypair import Keypair
 from solders.pubkey import Pubkey
 from solders.signature import Signature
 from solders.instruction import Instruction, AccountMeta
+from solders.sysvar import SLOT_HASHES
 from solana.constants import SYSTEM_PROGRAM_ID
-from construct import Struct, Int64ul, Bytes, Flag, If
+from construct import Struct, Int8ul, Int16ul, Int32ul, Int64ul, Bytes, Flag, If, PrefixedArray
 from fragments.solana_program import get_instruction_discriminator, get_program_derived_address
 from fragments.solana_rpc import init_rpc_client
 from fragments.solana_transaction import create_tx_with_fee_payer_and_lifetime
 
 
+# `RoundStatus` variants, in the order of their borsh tags
+ROUND_STATUSES = ("Scheduled", "Active", "Completed", "Cancelled")
+
+# `ScheduleMode` variants, in the order of their borsh tags
+SCHEDULE_MODES = ("Slot", "UnixTimestamp")
+
+
+# The outcome of `draw_winner`, with the inputs needed to verify it
+class Draw(TypedDict):
+    candidates: int
+    index: int
+    slot: int
+    slot_hash: bytes
+
+
+# `status` is the `RoundStatus` variant; the transition fields it doesn't carry are None
 class RoundAccount(TypedDict):
-    start_slot: int
+    start_at: int
     authority: Pubkey
+    round_id: int
+    end_at: int
+    status: str
     activated_at: Optional[int]
     activated_by: Optional[Pubkey]
     completed_at: Optional[int]
+    cancelled_at: Optional[int]
+    participant_count: int
+    max_participants: Optional[int]
+    entry_fee: int
+    commission_bps: int
+    winner: Optional[Pubkey]
+    draw_slot: int
+    draw: Optional[Draw]
+    activation_bounty: int
+    reschedule_count: int
+    schedule_mode: str
+    activation_requires_registration: bool
+    participation_requires_registration: bool
+    reveal_start_at: Optional[int]
+    reveal_count: int
+    scorer: Optional[Pubkey]
+    min_participants: int
+    interest_count: int
+    held_entries: int
+
+
+# `entry_index` is the participant's place in the entry order, the candidate list `draw_winner` indexes into
+class ParticipantAccount(TypedDict):
+    round: Pubkey
+    user: Pubkey
+    joined_at: int
+    entry_index: int
+
+
+# A participant's sealed submission, `value` is only set once revealed
+class SubmissionAccount(TypedDict):
+    round: Pubkey
+    user: Pubkey
+    commitment: bytes
+    committed_at: int
+    value: Optional[int]
+    revealed_at: Optional[int]
+
 
+# `round_id` is the authority's round id of the series' current round, `round_start_at` its start
+class SeriesAccount(TypedDict):
+    authority: Pubkey
+    series_id: int
+    interval_slots: int
+    duration_slots: int
+    round_id: int
+    round_start_at: int
+
+
+class LeaderboardEntry(TypedDict):
+    user: Pubkey
+    score: int
+    scored_at: int
+
+
+# Only scored rounds have a leaderboard, `entries` are best first
+class LeaderboardAccount(TypedDict):
+    round: Pubkey
+    frozen_at: Optional[int]
+    entries: list[LeaderboardEntry]
+
+
+round_status_schema = Struct(
+    "tag" / Int8ul,
+    "activated_at" / If(lambda ctx: ctx.tag in (1, 2), Int64ul),
+    "activated_by" / If(lambda ctx: ctx.tag in (1, 2), Bytes(32)),
+    "completed_at" / If(lambda ctx: ctx.tag == 2, Int64ul),
+    "cancelled_at" / If(lambda ctx: ctx.tag == 3, Int64ul),
+)
 
 round_account_schema = Struct(
-    "start_slot" / Int64ul,
+    "start_at" / Int64ul,
+    "authority" / Bytes(32),
+    "version" / Int8ul,
+    "round_id" / Int64ul,
+    "end_at" / Int64ul,
+    "status" / round_status_schema,
+    "participant_count" / Int64ul,
+    "max_participants_present" / Flag,
+    "max_participants" / If(lambda ctx: ctx.max_participants_present, Int64ul),
+    "entry_fee" / Int64ul,
+    "commission_bps" / Int16ul,
+    "winner_present" / Flag,
+    "winner" / If(lambda ctx: ctx.winner_present, Bytes(32)),
+    "draw_slot" / Int64ul,
+    "draw_present" / Flag,
+    "draw" / If(
+        lambda ctx: ctx.draw_present,
+        Struct("candidates" / Int32ul, "index" / Int32ul, "slot" / Int64ul, "slot_hash" / Bytes(32)),
+    ),
+    "activation_bounty" / Int64ul,
+    "reschedule_count" / Int32ul,
+    "schedule_mode" / Int8ul,
+    "activation_requires_registration" / Flag,
+    "participation_requires_registration" / Flag,
+    "reveal_start_at_present" / Flag,
+    "reveal_start_at" / If(lambda ctx: ctx.reveal_start_at_present, Int64ul),
+    "reveal_count" / Int64ul,
+    "scorer_present" / Flag,
+    "scorer" / If(lambda ctx: ctx.scorer_present, Bytes(32)),
+    "min_participants" / Int64ul,
+    "interest_count" / Int64ul,
+    "held_entries" / Int64ul,
+)
+
+participant_account_schema = Struct(
+    "round" / Bytes(32),
+    "user" / Bytes(32),
+    "joined_at" / Int64ul,
+    "entry_index" / Int64ul,
+)
+
+submission_account_schema = Struct(
+    "round" / Bytes(32),
+    "user" / Bytes(32),
+    "commitment" / Bytes(32),
+    "committed_at" / Int64ul,
+    "value_present" / Flag,
+    "value" / If(lambda ctx: ctx.value_present, Int64ul),
+    "revealed_at_present" / Flag,
+    "revealed_at" / If(lambda ctx: ctx.revealed_at_present, Int64ul),
+)
+
+leaderboard_account_schema = Struct(
+    "round" / Bytes(32),
+    "frozen_at_present" / Flag,
+    "frozen_at" / If(lambda ctx: ctx.frozen_at_present, Int64ul),
+    "entries" / PrefixedArray(Int32ul, Struct("user" / Bytes(32), "score" / Int64ul, "scored_at" / Int64ul)),
+)
+
+series_account_schema = Struct(
+    "authority" / Bytes(32),
+    "series_id" / Int64ul,
+    "interval_slots" / Int64ul,
+    "duration_slots" / Int64ul,
+    "round_id" / Int64ul,
+    "round_start_at" / Int64ul,
+)
+
+round_authority_account_schema = Struct(
     "authority" / Bytes(32),
-    "activated_at_present" / Flag,
-    "activated_at" / If(lambda ctx: ctx.activated_at_present, Int64ul),
-    "activated_by_present" / Flag,
-    "activated_by" / If(lambda ctx: ctx.activated_by_present, Bytes(32)),
-    "completed_at_present" / Flag,
-    "completed_at" / If(lambda ctx: ctx.completed_at_present, Int64ul),
+    "next_round_id" / Int64ul,
 )
 
 
-async def initialise_round(authority: Keypair, program_address: Pubkey, start_slot: int) -> Signature:
+def get_round_pda(authority: Pubkey, program_address: Pubkey, round_id: int) -> Pubkey:
+    pda, _ = Pubkey.find_program_address([b"round", bytes(authority), Int64ul.build(round_id)], program_address)
+    return pda
+
+
+def get_participant_pda(round_pda: Pubkey, user: Pubkey, program_address: Pubkey) -> Pubkey:
+    pda, _ = Pubkey.find_program_address([b"participant", bytes(round_pda), bytes(user)], program_address)
+    return pda
+
+
+def get_submission_pda(round_pda: Pubkey, user: Pubkey, program_address: Pubkey) -> Pubkey:
+    pda, _ = Pubkey.find_program_address([b"submission", bytes(round_pda), bytes(user)], program_address)
+    return pda
+
+
+def get_interest_pda(round_pda: Pubkey, user: Pubkey, program_address: Pubkey) -> Pubkey:
+    pda, _ = Pubkey.find_program_address([b"interest", bytes(round_pda), bytes(user)], program_address)
+    return pda
+
+
+# What `commit_submission` seals and `reveal_submission` opens, mirrors the program's `submission_commitment`
+def get_submission_commitment(round_pda: Pubkey, user: Pubkey, value: int, salt: bytes) -> bytes:
+    return hashlib.sha256(bytes(round_pda) + bytes(user) + Int64ul.build(value) + salt).digest()
+
+
+def get_series_pda(authority: Pubkey, program_address: Pubkey, series_id: int) -> Pubkey:
+    pda, _ = Pubkey.find_program_address([b"series", bytes(authority), Int64ul.build(series_id)], program_address)
+    return pda
+
+
+def get_leaderboard_pda(round_pda: Pubkey, program_address: Pubkey) -> Pubkey:
+    pda, _ = Pubkey.find_program_address([b"leaderboard", bytes(round_pda)], program_address)
+    return pda
+
+
+def get_pot_pda(round_pda: Pubkey, program_address: Pubkey) -> Pubkey:
+    pda, _ = Pubkey.find_program_address([b"pot", bytes(round_pda)], program_address)
+    return pda
+
+
+# The user's registration PDA in the register program, the program id stands in for it when omitted
+def get_registration_address(
+    user: Pubkey, program_address: Pubkey, register_program_address: Optional[Pubkey]
+) -> Pubkey:
+    if register_program_address is None:
+        return program_address
+    return get_program_derived_address(user, register_program_address, "registration")
+
+
+# `round_id` must be the authority's next round id, see `get_next_round_id`.
+# `max_participants` caps how many users can join; None leaves the round uncapped.
+# A non-zero `entry_fee` (lamports) makes the round paid, see `enter_round`. The authority keeps
+# `commission_bps` of the pot when a winner is paid. `activation_bounty` lamports go to whoever activates it.
+# `schedule_mode` sets what its start and end count, unix timestamps (seconds) rather than slots
+# follow the wall clock. The registration flags limit activating or joining the round to users holding a
+# confirmed registration in the register program. A `reveal_start_at` within the round lets participants
+# `commit_submission` sealed values before it and `reveal_submission` them after. A `scorer` can `submit_score`
+# alongside the authority. A round can't activate until `min_participants` users `signal_interest` in it.
+async def initialise_round(
+    authority: Keypair,
+    program_address: Pubkey,
+    round_id: int,
+    start_slot: int,
+    end_slot: int,
+    max_participants: Optional[int] = None,
+    entry_fee: int = 0,
+    commission_bps: int = 0,
+    activation_bounty: int = 0,
+    schedule_mode: str = "Slot",
+    activation_requires_registration: bool = False,
+    participation_requires_registration: bool = False,
+    reveal_start_at: Optional[int] = None,
+    scorer: Optional[Pubkey] = None,
+    min_participants: int = 0,
+) -> Signature:
     discriminator = get_instruction_discriminator("initialise_round", "round")
     payer = authority.pubkey()
-    pda = get_program_derived_address(payer, program_address, "round")
+    round_authority_pda = get_program_derived_address(payer, program_address, "round_authority")
+    pda = get_round_pda(payer, program_address, round_id)
+    pot_pda = get_pot_pda(pda, program_address)
     client = init_rpc_client()
-    encoded_start_slot = Int64ul.build(start_slot)
+    encoded_max_participants = b"\x00" if max_participants is None else b"\x01" + Int64ul.build(max_participants)
+    encoded_args = (
+        Int64ul.build(round_id)
+        + Int64ul.build(start_slot)
+        + Int64ul.build(end_slot)
+        + encoded_max_participants
+        + Int64ul.build(entry_fee)
+        + Int16ul.build(commission_bps)
+        + Int64ul.build(activation_bounty)
+        + Int8ul.build(SCHEDULE_MODES.index(schedule_mode))
+        + Flag.build(activation_requires_registration)
+        + Flag.build(participation_requires_registration)
+        + (b"\x00" if reveal_start_at is None else b"\x01" + Int64ul.build(reveal_start_at))
+        + (b"\x00" if scorer is None else b"\x01" + bytes(scorer))
+        + Int64ul.build(min_participants)
+    )
     instr = Instruction(
         program_id=program_address,
-        data=discriminator + encoded_start_slot,
+        data=discriminator + encoded_args,
         accounts=[
+            AccountMeta(pubkey=round_authority_pda, is_signer=False, is_writable=True),
             AccountMeta(pubkey=pda, is_signer=False, is_writable=True),
+            AccountMeta(pubkey=pot_pda, is_signer=False, is_writable=True),
             AccountMeta(pubkey=payer, is_signer=True, is_writable=True),
             AccountMeta(pubkey=SYSTEM_PROGRAM_ID, is_signer=False, is_writable=False),
         ],
@@ -50,30 +295,200 @@ async def initialise_round(authority: Keypair, program_address: Pubkey, start_sl
     return res.value
 
 
-async def get_round_account(authority: Pubkey, program_address: Pubkey) -> RoundAccount:
-    pda = get_program_derived_address(authority, program_address, "round")
+async def get_round_account(authority: Pubkey, program_address: Pubkey, round_id: int) -> RoundAccount:
+    pda = get_round_pda(authority, program_address, round_id)
     client = init_rpc_client()
     res = await client.get_account_info(pda)
     account_info = res.value
 
     if account_info is None:
-        raise ValueError(f"Round account not found for authority {authority} and program {program_address}")
+        raise ValueError(
+            f"Round account {round_id} not found for authority {authority} and program {program_address}"
+        )
 
     raw_bytes = bytes(account_info.data)[8:]  # Skip the first 8 bytes (discriminator)
     parsed = round_account_schema.parse(raw_bytes)
 
+    status = parsed.status
+
     return RoundAccount(
-        start_slot=parsed.start_slot,
+        start_at=parsed.start_at,
         authority=Pubkey.from_bytes(parsed.authority),
-        activated_at=parsed.activated_at,
-        activated_by=Pubkey.from_bytes(parsed.activated_by) if parsed.activated_by else None,
-        completed_at=parsed.completed_at,
+        round_id=parsed.round_id,
+        end_at=parsed.end_at,
+        status=ROUND_STATUSES[status.tag],
+        activated_at=status.activated_at,
+        activated_by=Pubkey.from_bytes(status.activated_by) if status.activated_by else None,
+        completed_at=status.completed_at,
+        cancelled_at=status.cancelled_at,
+        participant_count=parsed.participant_count,
+        max_participants=parsed.max_participants,
+        entry_fee=parsed.entry_fee,
+        commission_bps=parsed.commission_bps,
+        winner=Pubkey.from_bytes(parsed.winner) if parsed.winner else None,
+        draw_slot=parsed.draw_slot,
+        draw=(
+            Draw(
+                candidates=parsed.draw.candidates,
+                index=parsed.draw.index,
+                slot=parsed.draw.slot,
+                slot_hash=parsed.draw.slot_hash,
+            )
+            if parsed.draw
+            else None
+        ),
+        activation_bounty=parsed.activation_bounty,
+        reschedule_count=parsed.reschedule_count,
+        schedule_mode=SCHEDULE_MODES[parsed.schedule_mode],
+        activation_requires_registration=parsed.activation_requires_registration,
+        participation_requires_registration=parsed.participation_requires_registration,
+        reveal_start_at=parsed.reveal_start_at,
+        reveal_count=parsed.reveal_count,
+        scorer=Pubkey.from_bytes(parsed.scorer) if parsed.scorer else None,
+        min_participants=parsed.min_participants,
+        interest_count=parsed.interest_count,
+        held_entries=parsed.held_entries,
     )
 
 
-async def activate_round(payer: Keypair, program_address: Pubkey, authority: Pubkey) -> Signature:
+# An authority without rounds has no `RoundAuthority` account yet, so its first round id is 0.
+async def get_next_round_id(authority: Pubkey, program_address: Pubkey) -> int:
+    pda = get_program_derived_address(authority, program_address, "round_authority")
+    client = init_rpc_client()
+    res = await client.get_account_info(pda)
+    account_info = res.value
+
+    if account_info is None:
+        return 0
+
+    raw_bytes = bytes(account_info.data)[8:]
+    parsed = round_authority_account_schema.parse(raw_bytes)
+
+    return parsed.next_round_id
+
+
+# Schedules rounds `interval_slots` apart, each open for `duration_slots`, the first at `start_slot`.
+# Series rounds are free rounds of the authority, taking its next round ids, so it can cancel, settle and
+# close them as usual. Anyone can activate them and `roll_series` on to the next.
+async def initialise_series(
+    authority: Keypair,
+    program_address: Pubkey,
+    series_id: int,
+    start_slot: int,
+    interval_slots: int,
+    duration_slots: int,
+) -> Signature:
+    discriminator = get_instruction_discriminator("initialise_series", "round")
+    series_pda = get_series_pda(authority.pubkey(), program_address, series_id)
+    round_authority_pda = get_program_derived_address(authority.pubkey(), program_address, "round_authority")
+    round_id = await get_next_round_id(authority.pubkey(), program_address)
+    round_pda = get_round_pda(authority.pubkey(), program_address, round_id)
+    client = init_rpc_client()
+    encoded_args = (
+        Int64ul.build(series_id)
+        + Int64ul.build(start_slot)
+        + Int64ul.build(interval_slots)
+        + Int64ul.build(duration_slots)
+    )
+    instr = Instruction(
+        program_id=program_address,
+        data=discriminator + encoded_args,
+        accounts=[
+            AccountMeta(pubkey=series_pda, is_signer=False, is_writable=True),
+            AccountMeta(pubkey=round_authority_pda, is_signer=False, is_writable=True),
+            AccountMeta(pubkey=round_pda, is_signer=False, is_writable=True),
+            AccountMeta(pubkey=authority.pubkey(), is_signer=True, is_writable=True),
+            AccountMeta(pubkey=SYSTEM_PROGRAM_ID, is_signer=False, is_writable=False),
+        ],
+    )
+    tx = await create_tx_with_fee_payer_and_lifetime(authority, instr)
+    res = await client.send_transaction(tx)
+    return res.value
+
+
+# Anyone can roll a series once its next start is due, completing its current round and scheduling the next.
+# The `payer` covers the next round's rent, which goes to the authority when it closes the round.
+async def roll_series(payer: Keypair, program_address: Pubkey, authority: Pubkey, series_id: int) -> Signature:
+    discriminator = get_instruction_discriminator("roll_series", "round")
+    series_pda = get_series_pda(authority, program_address, series_id)
+    round_authority_pda = get_program_derived_address(authority, program_address, "round_authority")
+    round_id = (await get_series_account(authority, program_address, series_id))["round_id"]
+    current_round_pda = get_round_pda(authority, program_address, round_id)
+    next_round_id = await get_next_round_id(authority, program_address)
+    client = init_rpc_client()
+    instr = Instruction(
+        program_id=program_address,
+        data=discriminator,
+        accounts=[
+            AccountMeta(pubkey=series_pda, is_signer=False, is_writable=True),
+            AccountMeta(pubkey=round_authority_pda, is_signer=False, is_writable=True),
+            AccountMeta(pubkey=current_round_pda, is_signer=False, is_writable=True),
+            AccountMeta(
+                pubkey=get_round_pda(authority, program_address, next_round_id), is_signer=False, is_writable=True
+            ),
+            AccountMeta(pubkey=payer.pubkey(), is_signer=True, is_writable=True),
+            AccountMeta(pubkey=SYSTEM_PROGRAM_ID, is_signer=False, is_writable=False),
+            AccountMeta(
+                pubkey=get_leaderboard_pda(current_round_pda, program_address), is_signer=False, is_writable=True
+            ),
+        ],
+    )
+    tx = await create_tx_with_fee_payer_and_lifetime(payer, instr)
+    res = await client.send_transaction(tx)
+    return res.value
+
+
+# Stops a series rolling and returns its rent to the authority, its current round carries on as any other
+async def close_series(authority: Keypair, program_address: Pubkey, series_id: int) -> Signature:
+    discriminator = get_instruction_discriminator("close_series", "round")
+    series_pda = get_series_pda(authority.pubkey(), program_address, series_id)
+    client = init_rpc_client()
+    instr = Instruction(
+        program_id=program_address,
+        data=discriminator,
+        accounts=[
+            AccountMeta(pubkey=series_pda, is_signer=False, is_writable=True),
+            AccountMeta(pubkey=authority.pubkey(), is_signer=True, is_writable=True),
+        ],
+    )
+    tx = await create_tx_with_fee_payer_and_lifetime(authority, instr)
+    res = await client.send_transaction(tx)
+    return res.value
+
+
+async def get_series_account(authority: Pubkey, program_address: Pubkey, series_id: int) -> SeriesAccount:
+    pda = get_series_pda(authority, program_address, series_id)
+    client = init_rpc_client()
+    res = await client.get_account_info(pda)
+    account_info = res.value
+
+    if account_info is None:
+        raise ValueError(f"Series account not found for series {series_id} of {authority}")
+
+    raw_bytes = bytes(account_info.data)[8:]
+    parsed = series_account_schema.parse(raw_bytes)
+
+    return SeriesAccount(
+        authority=Pubkey.from_bytes(parsed.authority),
+        series_id=parsed.series_id,
+        interval_slots=parsed.interval_slots,
+        duration_slots=parsed.duration_slots,
+        round_id=parsed.round_id,
+        round_start_at=parsed.round_start_at,
+    )
+
+
+# Rounds gated on registration need `register_program_address`, to pass the payer's registration.
+async def activate_round(
+    payer: Keypair,
+    program_address: Pubkey,
+    authority: Pubkey,
+    round_id: int,
+    register_program_address: Optional[Pubkey] = None,
+) -> Signature:
     discriminator = get_instruction_discriminator("activate_round", "round")
-    pda = get_program_derived_address(authority, program_address, "round")
+    pda = get_round_pda(authority, program_address, round_id)
+    registration = get_registration_address(payer.pubkey(), program_address, register_program_address)
     client = init_rpc_client()
     instr = Instruction(
         program_id=program_address,
@@ -81,6 +496,7 @@ async def activate_round(payer: Keypair, program_address: Pubkey, authority: Pub
         accounts=[
             AccountMeta(pubkey=pda, is_signer=False, is_writable=True),
             AccountMeta(pubkey=payer.pubkey(), is_signer=True, is_writable=True),
+            AccountMeta(pubkey=registration, is_signer=False, is_writable=False),
         ],
     )
     tx = await create_tx_with_fee_payer_and_lifetime(payer, instr)
@@ -88,13 +504,192 @@ async def activate_round(payer: Keypair, program_address: Pubkey, authority: Pub
     return res.value
 
 
-async def complete_round(authority: Keypair, program_address: Pubkey) -> Signature:
+# The round's authority can complete it at any time, any `payer` once its end has passed.
+async def complete_round(payer: Keypair, program_address: Pubkey, authority: Pubkey, round_id: int) -> Signature:
     discriminator = get_instruction_discriminator("complete_round", "round")
-    pda = get_program_derived_address(authority.pubkey(), program_address, "round")
+    pda = get_round_pda(authority, program_address, round_id)
+    leaderboard_pda = get_leaderboard_pda(pda, program_address)
+    client = init_rpc_client()
+    instr = Instruction(
+        program_id=program_address,
+        data=discriminator,
+        accounts=[
+            AccountMeta(pubkey=pda, is_signer=False, is_writable=True),
+            AccountMeta(pubkey=payer.pubkey(), is_signer=True, is_writable=True),
+            AccountMeta(pubkey=leaderboard_pda, is_signer=False, is_writable=True),
+        ],
+    )
+    tx = await create_tx_with_fee_payer_and_lifetime(payer, instr)
+    res = await client.send_transaction(tx)
+    return res.value
+
+
+# Once the round is completed its authority names the `winner`, who must have entered, and pays them the pot less
+# the commission. A drawn round's winner must be the participant at the drawn entry index. Left unsettled past the
+# settlement window, the entries become refundable instead.
+async def settle_round(authority: Keypair, program_address: Pubkey, round_id: int, winner: Pubkey) -> Signature:
+    discriminator = get_instruction_discriminator("settle_round", "round")
+    pda = get_round_pda(authority.pubkey(), program_address, round_id)
+    pot_pda = get_pot_pda(pda, program_address)
+    winner_participant_pda = get_participant_pda(pda, winner, program_address)
+    client = init_rpc_client()
+    instr = Instruction(
+        program_id=program_address,
+        data=discriminator,
+        accounts=[
+            AccountMeta(pubkey=pda, is_signer=False, is_writable=True),
+            AccountMeta(pubkey=authority.pubkey(), is_signer=True, is_writable=True),
+            AccountMeta(pubkey=pot_pda, is_signer=False, is_writable=True),
+            AccountMeta(pubkey=winner, is_signer=False, is_writable=True),
+            AccountMeta(pubkey=winner_participant_pda, is_signer=False, is_writable=False),
+            AccountMeta(pubkey=SYSTEM_PROGRAM_ID, is_signer=False, is_writable=False),
+        ],
+    )
+    tx = await create_tx_with_fee_payer_and_lifetime(authority, instr)
+    res = await client.send_transaction(tx)
+    return res.value
+
+
+async def cancel_round(authority: Keypair, program_address: Pubkey, round_id: int) -> Signature:
+    discriminator = get_instruction_discriminator("cancel_round", "round")
+    pda = get_round_pda(authority.pubkey(), program_address, round_id)
+    client = init_rpc_client()
+    instr = Instruction(
+        program_id=program_address,
+        data=discriminator,
+        accounts=[
+            AccountMeta(pubkey=pda, is_signer=False, is_writable=True),
+            AccountMeta(pubkey=authority.pubkey(), is_signer=True, is_writable=True),
+        ],
+    )
+    tx = await create_tx_with_fee_payer_and_lifetime(authority, instr)
+    res = await client.send_transaction(tx)
+    return res.value
+
+
+# Rounds gated on registration need `register_program_address`, to pass the user's registration.
+async def join_round(
+    user: Keypair,
+    program_address: Pubkey,
+    authority: Pubkey,
+    round_id: int,
+    register_program_address: Optional[Pubkey] = None,
+) -> Signature:
+    discriminator = get_instruction_discriminator("join_round", "round")
+    pda = get_round_pda(authority, program_address, round_id)
+    participant_pda = get_participant_pda(pda, user.pubkey(), program_address)
+    registration = get_registration_address(user.pubkey(), program_address, register_program_address)
+    client = init_rpc_client()
+    instr = Instruction(
+        program_id=program_address,
+        data=discriminator,
+        accounts=[
+            AccountMeta(pubkey=pda, is_signer=False, is_writable=True),
+            AccountMeta(pubkey=participant_pda, is_signer=False, is_writable=True),
+            AccountMeta(pubkey=user.pubkey(), is_signer=True, is_writable=True),
+            AccountMeta(pubkey=SYSTEM_PROGRAM_ID, is_signer=False, is_writable=False),
+            AccountMeta(pubkey=registration, is_signer=False, is_writable=False),
+        ],
+    )
+    tx = await create_tx_with_fee_payer_and_lifetime(user, instr)
+    res = await client.send_transaction(tx)
+    return res.value
+
+
+# Pays the round's entry fee into its pot, `register_program_address` as for `join_round`
+async def enter_round(
+    user: Keypair,
+    program_address: Pubkey,
+    authority: Pubkey,
+    round_id: int,
+    register_program_address: Optional[Pubkey] = None,
+) -> Signature:
+    discriminator = get_instruction_discriminator("enter_round", "round")
+    pda = get_round_pda(authority, program_address, round_id)
+    participant_pda = get_participant_pda(pda, user.pubkey(), program_address)
+    pot_pda = get_pot_pda(pda, program_address)
+    registration = get_registration_address(user.pubkey(), program_address, register_program_address)
+    client = init_rpc_client()
+    instr = Instruction(
+        program_id=program_address,
+        data=discriminator,
+        accounts=[
+            AccountMeta(pubkey=pda, is_signer=False, is_writable=True),
+            AccountMeta(pubkey=participant_pda, is_signer=False, is_writable=True),
+            AccountMeta(pubkey=pot_pda, is_signer=False, is_writable=True),
+            AccountMeta(pubkey=user.pubkey(), is_signer=True, is_writable=True),
+            AccountMeta(pubkey=SYSTEM_PROGRAM_ID, is_signer=False, is_writable=False),
+            AccountMeta(pubkey=registration, is_signer=False, is_writable=False),
+        ],
+    )
+    tx = await create_tx_with_fee_payer_and_lifetime(user, instr)
+    res = await client.send_transaction(tx)
+    return res.value
+
+
+# Returns the entry fee once the round is cancelled, or completed and left unsettled past the settlement window
+async def refund_entry(user: Keypair, program_address: Pubkey, authority: Pubkey, round_id: int) -> Signature:
+    discriminator = get_instruction_discriminator("refund_entry", "round")
+    pda = get_round_pda(authority, program_address, round_id)
+    participant_pda = get_participant_pda(pda, user.pubkey(), program_address)
+    pot_pda = get_pot_pda(pda, program_address)
+    client = init_rpc_client()
+    instr = Instruction(
+        program_id=program_address,
+        data=discriminator,
+        accounts=[
+            AccountMeta(pubkey=pda, is_signer=False, is_writable=True),
+            AccountMeta(pubkey=participant_pda, is_signer=False, is_writable=True),
+            AccountMeta(pubkey=pot_pda, is_signer=False, is_writable=True),
+            AccountMeta(pubkey=user.pubkey(), is_signer=True, is_writable=True),
+            AccountMeta(pubkey=SYSTEM_PROGRAM_ID, is_signer=False, is_writable=False),
+        ],
+    )
+    tx = await create_tx_with_fee_payer_and_lifetime(user, instr)
+    res = await client.send_transaction(tx)
+    return res.value
+
+
+# Counts the `user` towards the round's `min_participants`, once per user before its start,
+# `register_program_address` as for `join_round`
+async def signal_interest(
+    user: Keypair,
+    program_address: Pubkey,
+    authority: Pubkey,
+    round_id: int,
+    register_program_address: Optional[Pubkey] = None,
+) -> Signature:
+    discriminator = get_instruction_discriminator("signal_interest", "round")
+    pda = get_round_pda(authority, program_address, round_id)
+    interest_pda = get_interest_pda(pda, user.pubkey(), program_address)
+    registration = get_registration_address(user.pubkey(), program_address, register_program_address)
     client = init_rpc_client()
     instr = Instruction(
         program_id=program_address,
         data=discriminator,
+        accounts=[
+            AccountMeta(pubkey=pda, is_signer=False, is_writable=True),
+            AccountMeta(pubkey=interest_pda, is_signer=False, is_writable=True),
+            AccountMeta(pubkey=user.pubkey(), is_signer=True, is_writable=True),
+            AccountMeta(pubkey=SYSTEM_PROGRAM_ID, is_signer=False, is_writable=False),
+            AccountMeta(pubkey=registration, is_signer=False, is_writable=False),
+        ],
+    )
+    tx = await create_tx_with_fee_payer_and_lifetime(user, instr)
+    res = await client.send_transaction(tx)
+    return res.value
+
+
+# Moves a round that has not started yet to `new_start_slot`, its end moves with it.
+async def reschedule_round(
+    authority: Keypair, program_address: Pubkey, round_id: int, new_start_slot: int
+) -> Signature:
+    discriminator = get_instruction_discriminator("reschedule_round", "round")
+    pda = get_round_pda(authority.pubkey(), program_address, round_id)
+    client = init_rpc_client()
+    instr = Instruction(
+        program_id=program_address,
+        data=discriminator + Int64ul.build(new_start_slot),
         accounts=[
             AccountMeta(pubkey=pda, is_signer=False, is_writable=True),
             AccountMeta(pubkey=authority.pubkey(), is_signer=True, is_writable=True),
@@ -103,3 +698,234 @@ async def complete_round(authority: Keypair, program_address: Pubkey) -> Signatu
     tx = await create_tx_with_fee_payer_and_lifetime(authority, instr)
     res = await client.send_transaction(tx)
     return res.value
+
+
+# Closes a completed or cancelled round, returning its rent to the authority.
+# A paid round's entry fees must have been paid out or refunded first.
+async def close_round(authority: Keypair, program_address: Pubkey, round_id: int) -> Signature:
+    discriminator = get_instruction_discriminator("close_round", "round")
+    pda = get_round_pda(authority.pubkey(), program_address, round_id)
+    pot_pda = get_pot_pda(pda, program_address)
+    leaderboard_pda = get_leaderboard_pda(pda, program_address)
+    client = init_rpc_client()
+    instr = Instruction(
+        program_id=program_address,
+        data=discriminator,
+        accounts=[
+            AccountMeta(pubkey=pda, is_signer=False, is_writable=True),
+            AccountMeta(pubkey=pot_pda, is_signer=False, is_writable=True),
+            AccountMeta(pubkey=authority.pubkey(), is_signer=True, is_writable=True),
+            AccountMeta(pubkey=SYSTEM_PROGRAM_ID, is_signer=False, is_writable=False),
+            AccountMeta(pubkey=leaderboard_pda, is_signer=False, is_writable=True),
+        ],
+    )
+    tx = await create_tx_with_fee_payer_and_lifetime(authority, instr)
+    res = await client.send_transaction(tx)
+    return res.value
+
+
+# Once the round is closed, takes back the rent of the user's participant account
+async def close_participant(user: Keypair, program_address: Pubkey, authority: Pubkey, round_id: int) -> Signature:
+    pda = get_round_pda(authority, program_address, round_id)
+    participant_pda = get_participant_pda(pda, user.pubkey(), program_address)
+    return await close_user_account("close_participant", user, program_address, pda, participant_pda)
+
+
+# Once the round is closed, takes back the rent of the user's submission account
+async def close_submission(user: Keypair, program_address: Pubkey, authority: Pubkey, round_id: int) -> Signature:
+    pda = get_round_pda(authority, program_address, round_id)
+    submission_pda = get_submission_pda(pda, user.pubkey(), program_address)
+    return await close_user_account("close_submission", user, program_address, pda, submission_pda)
+
+
+# Once the round is closed, takes back the rent of the user's interest account
+async def close_interest(user: Keypair, program_address: Pubkey, authority: Pubkey, round_id: int) -> Signature:
+    pda = get_round_pda(authority, program_address, round_id)
+    interest_pda = get_interest_pda(pda, user.pubkey(), program_address)
+    return await close_user_account("close_interest", user, program_address, pda, interest_pda)
+
+
+async def close_user_account(
+    instruction: str, user: Keypair, program_address: Pubkey, round_pda: Pubkey, account_pda: Pubkey
+) -> Signature:
+    discriminator = get_instruction_discriminator(instruction, "round")
+    client = init_rpc_client()
+    instr = Instruction(
+        program_id=program_address,
+        data=discriminator,
+        accounts=[
+            AccountMeta(pubkey=round_pda, is_signer=False, is_writable=False),
+            AccountMeta(pubkey=account_pda, is_signer=False, is_writable=True),
+            AccountMeta(pubkey=user.pubkey(), is_signer=True, is_writable=True),
+        ],
+    )
+    tx = await create_tx_with_fee_payer_and_lifetime(user, instr)
+    res = await client.send_transaction(tx)
+    return res.value
+
+
+# Seals `value` until the round's reveal start. Keep `salt` (32 random bytes) secret until revealing.
+async def commit_submission(
+    user: Keypair, program_address: Pubkey, authority: Pubkey, round_id: int, value: int, salt: bytes
+) -> Signature:
+    discriminator = get_instruction_discriminator("commit", "round")
+    pda = get_round_pda(authority, program_address, round_id)
+    participant_pda = get_participant_pda(pda, user.pubkey(), program_address)
+    submission_pda = get_submission_pda(pda, user.pubkey(), program_address)
+    client = init_rpc_client()
+    instr = Instruction(
+        program_id=program_address,
+        data=discriminator + get_submission_commitment(pda, user.pubkey(), value, salt),
+        accounts=[
+            AccountMeta(pubkey=pda, is_signer=False, is_writable=False),
+            AccountMeta(pubkey=participant_pda, is_signer=False, is_writable=False),
+            AccountMeta(pubkey=submission_pda, is_signer=False, is_writable=True),
+            AccountMeta(pubkey=user.pubkey(), is_signer=True, is_writable=True),
+            AccountMeta(pubkey=SYSTEM_PROGRAM_ID, is_signer=False, is_writable=False),
+        ],
+    )
+    tx = await create_tx_with_fee_payer_and_lifetime(user, instr)
+    res = await client.send_transaction(tx)
+    return res.value
+
+
+# Opens a committed submission, from the round's reveal start until its end
+async def reveal_submission(
+    user: Keypair, program_address: Pubkey, authority: Pubkey, round_id: int, value: int, salt: bytes
+) -> Signature:
+    discriminator = get_instruction_discriminator("reveal", "round")
+    pda = get_round_pda(authority, program_address, round_id)
+    submission_pda = get_submission_pda(pda, user.pubkey(), program_address)
+    client = init_rpc_client()
+    instr = Instruction(
+        program_id=program_address,
+        data=discriminator + Int64ul.build(value) + salt,
+        accounts=[
+            AccountMeta(pubkey=pda, is_signer=False, is_writable=True),
+            AccountMeta(pubkey=submission_pda, is_signer=False, is_writable=True),
+            AccountMeta(pubkey=user.pubkey(), is_signer=True, is_writable=False),
+        ],
+    )
+    tx = await create_tx_with_fee_payer_and_lifetime(user, instr)
+    res = await client.send_transaction(tx)
+    return res.value
+
+
+# Records a participant's `score` while the round is active, signed by its authority or scorer. Each
+# participant keeps their best score, the leaderboard the top scores with ties in the order they were reached.
+async def submit_score(
+    scorer: Keypair, program_address: Pubkey, authority: Pubkey, round_id: int, user: Pubkey, score: int
+) -> Signature:
+    discriminator = get_instruction_discriminator("submit_score", "round")
+    pda = get_round_pda(authority, program_address, round_id)
+    participant_pda = get_participant_pda(pda, user, program_address)
+    leaderboard_pda = get_leaderboard_pda(pda, program_address)
+    client = init_rpc_client()
+    instr = Instruction(
+        program_id=program_address,
+        data=discriminator + bytes(user) + Int64ul.build(score),
+        accounts=[
+            AccountMeta(pubkey=pda, is_signer=False, is_writable=False),
+            AccountMeta(pubkey=participant_pda, is_signer=False, is_writable=False),
+            AccountMeta(pubkey=leaderboard_pda, is_signer=False, is_writable=True),
+            AccountMeta(pubkey=scorer.pubkey(), is_signer=True, is_writable=True),
+            AccountMeta(pubkey=SYSTEM_PROGRAM_ID, is_signer=False, is_writable=False),
+        ],
+    )
+    tx = await create_tx_with_fee_payer_and_lifetime(scorer, instr)
+    res = await client.send_transaction(tx)
+    return res.value
+
+
+# Draws an index in `0..candidates` from the hash of the round's draw slot, once it has completed.
+# `candidates` must match the participant count if users joined on-chain, then any `payer` can draw.
+# Over an off-chain entry list only the round's authority can.
+async def draw_winner(
+    payer: Keypair, program_address: Pubkey, authority: Pubkey, round_id: int, candidates: int
+) -> Signature:
+    discriminator = get_instruction_discriminator("draw_winner", "round")
+    pda = get_round_pda(authority, program_address, round_id)
+    client = init_rpc_client()
+    instr = Instruction(
+        program_id=program_address,
+        data=discriminator + Int32ul.build(candidates),
+        accounts=[
+            AccountMeta(pubkey=pda, is_signer=False, is_writable=True),
+            AccountMeta(pubkey=payer.pubkey(), is_signer=True, is_writable=True),
+            AccountMeta(pubkey=SLOT_HASHES, is_signer=False, is_writable=False),
+        ],
+    )
+    tx = await create_tx_with_fee_payer_and_lifetime(payer, instr)
+    res = await client.send_transaction(tx)
+    return res.value
+
+
+async def get_participant_account(
+    user: Pubkey, program_address: Pubkey, authority: Pubkey, round_id: int
+) -> ParticipantAccount:
+    round_pda = get_round_pda(authority, program_address, round_id)
+    pda = get_participant_pda(round_pda, user, program_address)
+    client = init_rpc_client()
+    res = await client.get_account_info(pda)
+    account_info = res.value
+
+    if account_info is None:
+        raise ValueError(f"Participant account not found for user {user} in round {round_id} of {authority}")
+
+    raw_bytes = bytes(account_info.data)[8:]
+    parsed = participant_account_schema.parse(raw_bytes)
+
+    return ParticipantAccount(
+        round=Pubkey.from_bytes(parsed.round),
+        user=Pubkey.from_bytes(parsed.user),
+        joined_at=parsed.joined_at,
+        entry_index=parsed.entry_index,
+    )
+
+
+async def get_submission_account(
+    user: Pubkey, program_address: Pubkey, authority: Pubkey, round_id: int
+) -> SubmissionAccount:
+    round_pda = get_round_pda(authority, program_address, round_id)
+    pda = get_submission_pda(round_pda, user, program_address)
+    client = init_rpc_client()
+    res = await client.get_account_info(pda)
+    account_info = res.value
+
+    if account_info is None:
+        raise ValueError(f"Submission account not found for user {user} in round {round_id} of {authority}")
+
+    raw_bytes = bytes(account_info.data)[8:]
+    parsed = submission_account_schema.parse(raw_bytes)
+
+    return SubmissionAccount(
+        round=Pubkey.from_bytes(parsed.round),
+        user=Pubkey.from_bytes(parsed.user),
+        commitment=parsed.commitment,
+        committed_at=parsed.committed_at,
+        value=parsed.value,
+        revealed_at=parsed.revealed_at,
+    )
+
+
+async def get_leaderboard_account(authority: Pubkey, program_address: Pubkey, round_id: int) -> LeaderboardAccount:
+    round_pda = get_round_pda(authority, program_address, round_id)
+    pda = get_leaderboard_pda(round_pda, program_address)
+    client = init_rpc_client()
+    res = await client.get_account_info(pda)
+    account_info = res.value
+
+    if account_info is None:
+        raise ValueError(f"Leaderboard account not found for round {round_id} of {authority}")
+
+    raw_bytes = bytes(account_info.data)[8:]
+    parsed = leaderboard_account_schema.parse(raw_bytes)
+
+    return LeaderboardAccount(
+        round=Pubkey.from_bytes(parsed.round),
+        frozen_at=parsed.frozen_at,
+        entries=[
+            LeaderboardEntry(user=Pubkey.from_bytes(entry.user), score=entry.score, scored_at=entry.scored_at)
+            for entry in parsed.entries
+        ],
+    )
@@ -1,12 +1,40 @@
 import assert from "node:assert/strict";
 import test, { before, describe } from "node:test";
-import { activateRound, completeRound, getRoundAccount, initialiseRound } from "./solana_round_interface";
-import { Address, address, generateKeyPairSigner, isNone, isSome } from "@solana/kit";
+import {
+  activateRound,
+  cancelRound,
+  closeInterest,
+  closeParticipant,
+  closeRound,
+  closeSubmission,
+  commitSubmission,
+  completeRound,
+  drawWinner,
+  enterRound,
+  getNextRoundId,
+  getLeaderboardAccount,
+  getParticipantAccount,
+  getRoundAccount,
+  getSeriesAccount,
+  closeSeries,
+  getSubmissionAccount,
+  initialiseRound,
+  initialiseSeries,
+  joinRound,
+  rescheduleRound,
+  revealSubmission,
+  rollSeries,
+  settleRound,
+  signalInterest,
+  submitScore,
+} from "./solana_round_interface";
+import { Address, address, generateKeyPairSigner, isSome, some } from "@solana/kit";
 import { getEnvVar } from "../env_vars/env_vars_utils";
 import { sendAndConfirmAirdrop } from "../solana_airdrop/solana_airdrop_utils";
 import { LAMPORTS_PER_SOL } from "@solana/web3.js";
 import { confirmRecentSignature } from "../solana_transaction/solana_transaction_utils";
 import { initRpcClient, waitForSlot } from "../solana_rpc/solana_rpc_utils";
+import { getBalance } from "../solana_balance/solana_balance_utils";
 
 interface ProgramError {
   context: { logs: string[] };
@@ -32,33 +60,523 @@ describe("solana program round interface", () => {
     const recentSlot = await client.getSlot({ commitment: "confirmed" }).send();
     const startSlot = recentSlot + 3n;
 
-    const txSig = await initialiseRound(signer, programAddress, startSlot);
+    const txSig = await initialiseRound(signer, programAddress, 0n, startSlot, startSlot + 100n);
     await confirmRecentSignature(txSig);
 
-    let roundAccount = await getRoundAccount(signer.address, programAddress);
-    assert.strictEqual(roundAccount.start_slot, startSlot);
+    let roundAccount = await getRoundAccount(signer.address, programAddress, 0n);
+    assert.strictEqual(roundAccount.start_at, startSlot);
     assert.strictEqual(roundAccount.authority, signer.address);
-    assert.ok(isNone(roundAccount.activated_at));
-    assert.ok(isNone(roundAccount.activated_by));
-    assert.ok(isNone(roundAccount.completed_at));
+    assert.strictEqual(roundAccount.round_id, 0n);
+    assert.strictEqual(roundAccount.end_at, startSlot + 100n);
+    assert.deepStrictEqual(roundAccount.status, { __kind: "Scheduled" });
 
     const atSlot = await waitForSlot(startSlot);
     if (!atSlot) {
       assert.fail(`Round start slot ${startSlot} not reached within timeout`);
     }
 
-    const txSigActivate = await activateRound(signer, programAddress, signer.address);
+    const txSigActivate = await activateRound(signer, programAddress, signer.address, 0n);
     await confirmRecentSignature(txSigActivate);
 
-    roundAccount = await getRoundAccount(signer.address, programAddress);
-    assert.ok(isSome(roundAccount.activated_at));
-    assert.ok(isSome(roundAccount.activated_by));
+    roundAccount = await getRoundAccount(signer.address, programAddress, 0n);
+    assert.strictEqual(roundAccount.status.__kind, "Active");
+    assert.ok(roundAccount.status.__kind === "Active" && roundAccount.status.activated_by === signer.address);
 
-    const txSigComplete = await completeRound(signer, programAddress);
+    const txSigComplete = await completeRound(signer, programAddress, signer.address, 0n);
     await confirmRecentSignature(txSigComplete);
 
-    roundAccount = await getRoundAccount(signer.address, programAddress);
-    assert.ok(isSome(roundAccount.completed_at));
+    roundAccount = await getRoundAccount(signer.address, programAddress, 0n);
+    assert.strictEqual(roundAccount.status.__kind, "Completed");
+  });
+
+  test("join active round", async () => {
+    const signer = await generateKeyPairSigner();
+    const user = await generateKeyPairSigner();
+    await sendAndConfirmAirdrop(signer.address, BigInt(LAMPORTS_PER_SOL));
+    await sendAndConfirmAirdrop(user.address, BigInt(LAMPORTS_PER_SOL));
+    const recentSlot = await client.getSlot({ commitment: "confirmed" }).send();
+    const startSlot = recentSlot + 3n;
+
+    const txSig = await initialiseRound(signer, programAddress, 0n, startSlot, startSlot + 100n, {
+      maxParticipants: 10n,
+    });
+    await confirmRecentSignature(txSig);
+
+    const atSlot = await waitForSlot(startSlot);
+    if (!atSlot) {
+      assert.fail(`Round start slot ${startSlot} not reached within timeout`);
+    }
+
+    const txSigActivate = await activateRound(signer, programAddress, signer.address, 0n);
+    await confirmRecentSignature(txSigActivate);
+
+    const txSigJoin = await joinRound(user, programAddress, signer.address, 0n);
+    await confirmRecentSignature(txSigJoin);
+
+    const participantAccount = await getParticipantAccount(user.address, programAddress, signer.address, 0n);
+    assert.strictEqual(participantAccount.user, user.address);
+    assert.strictEqual(participantAccount.entry_index, 0n);
+
+    const roundAccount = await getRoundAccount(signer.address, programAddress, 0n);
+    assert.strictEqual(roundAccount.participant_count, 1n);
+    assert.deepStrictEqual(roundAccount.max_participants, some(10n));
+  });
+
+  test("join gated round without registration", async () => {
+    const signer = await generateKeyPairSigner();
+    const user = await generateKeyPairSigner();
+    await sendAndConfirmAirdrop(signer.address, BigInt(LAMPORTS_PER_SOL));
+    await sendAndConfirmAirdrop(user.address, BigInt(LAMPORTS_PER_SOL));
+    const recentSlot = await client.getSlot({ commitment: "confirmed" }).send();
+    const startSlot = recentSlot + 3n;
+
+    const txSig = await initialiseRound(signer, programAddress, 0n, startSlot, startSlot + 100n, {
+      participationRequiresRegistration: true,
+    });
+    await confirmRecentSignature(txSig);
+
+    const roundAccount = await getRoundAccount(signer.address, programAddress, 0n);
+    assert.strictEqual(roundAccount.activation_requires_registration, false);
+    assert.strictEqual(roundAccount.participation_requires_registration, true);
+
+    const atSlot = await waitForSlot(startSlot);
+    if (!atSlot) {
+      assert.fail(`Round start slot ${startSlot} not reached within timeout`);
+    }
+
+    const txSigActivate = await activateRound(signer, programAddress, signer.address, 0n);
+    await confirmRecentSignature(txSigActivate);
+
+    await assert.rejects(async () => {
+      await joinRound(user, programAddress, signer.address, 0n);
+    }, (err: ProgramError) => {
+      const errorMessage = err.context.logs.join(" ");
+      assert.match(errorMessage, /RegistrationRequired/);
+      return true;
+    });
+  });
+
+  test("enter paid round & pay winner", async () => {
+    const signer = await generateKeyPairSigner();
+    const user = await generateKeyPairSigner();
+    await sendAndConfirmAirdrop(signer.address, BigInt(LAMPORTS_PER_SOL));
+    await sendAndConfirmAirdrop(user.address, BigInt(LAMPORTS_PER_SOL));
+    const recentSlot = await client.getSlot({ commitment: "confirmed" }).send();
+    const startSlot = recentSlot + 3n;
+    const entryFee = BigInt(LAMPORTS_PER_SOL) / 10n;
+
+    const txSig = await initialiseRound(signer, programAddress, 0n, startSlot, startSlot + 100n, {
+      entryFee,
+      commissionBps: 1_000,
+    });
+    await confirmRecentSignature(txSig);
+
+    const atSlot = await waitForSlot(startSlot);
+    if (!atSlot) {
+      assert.fail(`Round start slot ${startSlot} not reached within timeout`);
+    }
+
+    const txSigActivate = await activateRound(signer, programAddress, signer.address, 0n);
+    await confirmRecentSignature(txSigActivate);
+
+    const txSigEnter = await enterRound(user, programAddress, signer.address, 0n);
+    await confirmRecentSignature(txSigEnter);
+
+    const txSigComplete = await completeRound(signer, programAddress, signer.address, 0n);
+    await confirmRecentSignature(txSigComplete);
+
+    const txSigSettle = await settleRound(signer, programAddress, 0n, user.address);
+    await confirmRecentSignature(txSigSettle);
+
+    const roundAccount = await getRoundAccount(signer.address, programAddress, 0n);
+    assert.strictEqual(roundAccount.entry_fee, entryFee);
+    assert.strictEqual(roundAccount.commission_bps, 1_000);
+    assert.deepStrictEqual(roundAccount.winner, some(user.address));
+  });
+
+  test("draw winner after completion", async () => {
+    const signer = await generateKeyPairSigner();
+    await sendAndConfirmAirdrop(signer.address, BigInt(LAMPORTS_PER_SOL));
+    const recentSlot = await client.getSlot({ commitment: "confirmed" }).send();
+    const startSlot = recentSlot + 3n;
+
+    const txSig = await initialiseRound(signer, programAddress, 0n, startSlot, startSlot + 5n);
+    await confirmRecentSignature(txSig);
+
+    let atSlot = await waitForSlot(startSlot);
+    if (!atSlot) {
+      assert.fail(`Round start slot ${startSlot} not reached within timeout`);
+    }
+
+    const txSigActivate = await activateRound(signer, programAddress, signer.address, 0n);
+    await confirmRecentSignature(txSigActivate);
+
+    const txSigComplete = await completeRound(signer, programAddress, signer.address, 0n);
+    await confirmRecentSignature(txSigComplete);
+
+    let roundAccount = await getRoundAccount(signer.address, programAddress, 0n);
+    assert.strictEqual(roundAccount.draw_slot, startSlot + 6n);
+
+    // The draw slot's hash is only in SlotHashes once a later slot has been reached
+    atSlot = await waitForSlot(startSlot + 8n);
+    if (!atSlot) {
+      assert.fail(`Draw slot ${startSlot + 6n} not passed within timeout`);
+    }
+
+    const txSigDraw = await drawWinner(signer, programAddress, signer.address, 0n, 5);
+    await confirmRecentSignature(txSigDraw);
+
+    roundAccount = await getRoundAccount(signer.address, programAddress, 0n);
+    assert.ok(isSome(roundAccount.draw));
+    assert.strictEqual(roundAccount.draw.value.candidates, 5);
+    assert.ok(roundAccount.draw.value.index < 5);
+  });
+
+  test("keeper activates round for bounty", async () => {
+    const signer = await generateKeyPairSigner();
+    const keeper = await generateKeyPairSigner();
+    await sendAndConfirmAirdrop(signer.address, BigInt(LAMPORTS_PER_SOL));
+    await sendAndConfirmAirdrop(keeper.address, BigInt(LAMPORTS_PER_SOL));
+    const recentSlot = await client.getSlot({ commitment: "confirmed" }).send();
+    const startSlot = recentSlot + 3n;
+    const activationBounty = BigInt(LAMPORTS_PER_SOL) / 100n;
+
+    const txSig = await initialiseRound(signer, programAddress, 0n, startSlot, startSlot + 100n, { activationBounty });
+    await confirmRecentSignature(txSig);
+
+    const atSlot = await waitForSlot(startSlot);
+    if (!atSlot) {
+      assert.fail(`Round start slot ${startSlot} not reached within timeout`);
+    }
+
+    const txSigActivate = await activateRound(keeper, programAddress, signer.address, 0n);
+    await confirmRecentSignature(txSigActivate);
+
+    const roundAccount = await getRoundAccount(signer.address, programAddress, 0n);
+    assert.strictEqual(roundAccount.activation_bounty, activationBounty);
+    assert.ok(roundAccount.status.__kind === "Active" && roundAccount.status.activated_by === keeper.address);
+
+    // The bounty more than covers the keeper's transaction fee
+    assert.ok((await getBalance(keeper.address)) > BigInt(LAMPORTS_PER_SOL));
+  });
+
+  test("cancel scheduled round", async () => {
+    const signer = await generateKeyPairSigner();
+    await sendAndConfirmAirdrop(signer.address, BigInt(LAMPORTS_PER_SOL));
+    const recentSlot = await client.getSlot({ commitment: "confirmed" }).send();
+    const startSlot = recentSlot + 50n;
+
+    const txSig = await initialiseRound(signer, programAddress, 0n, startSlot, startSlot + 100n);
+    await confirmRecentSignature(txSig);
+
+    const txSigCancel = await cancelRound(signer, programAddress, 0n);
+    await confirmRecentSignature(txSigCancel);
+
+    const roundAccount = await getRoundAccount(signer.address, programAddress, 0n);
+    assert.strictEqual(roundAccount.status.__kind, "Cancelled");
+
+    assert.rejects(async () => {
+      await activateRound(signer, programAddress, signer.address, 0n);
+    }, (err: ProgramError) => {
+      const errorMessage = err.context.logs.join(" ");
+      assert.match(errorMessage, /RoundCancelled/);
+      return true;
+    });
+  });
+
+  test("close cancelled round", async () => {
+    const signer = await generateKeyPairSigner();
+    await sendAndConfirmAirdrop(signer.address, BigInt(LAMPORTS_PER_SOL));
+    const recentSlot = await client.getSlot({ commitment: "confirmed" }).send();
+    const startSlot = recentSlot + 50n;
+
+    const txSig = await initialiseRound(signer, programAddress, 0n, startSlot, startSlot + 100n);
+    await confirmRecentSignature(txSig);
+
+    const txSigCancel = await cancelRound(signer, programAddress, 0n);
+    await confirmRecentSignature(txSigCancel);
+
+    const txSigClose = await closeRound(signer, programAddress, 0n);
+    await confirmRecentSignature(txSigClose);
+
+    await assert.rejects(getRoundAccount(signer.address, programAddress, 0n), /does not exist/);
+  });
+
+  test("reschedule round", async () => {
+    const signer = await generateKeyPairSigner();
+    await sendAndConfirmAirdrop(signer.address, BigInt(LAMPORTS_PER_SOL));
+    const recentSlot = await client.getSlot({ commitment: "confirmed" }).send();
+    const startSlot = recentSlot + 50n;
+
+    const txSig = await initialiseRound(signer, programAddress, 0n, startSlot, startSlot + 100n);
+    await confirmRecentSignature(txSig);
+
+    const txSigReschedule = await rescheduleRound(signer, programAddress, 0n, startSlot + 30n);
+    await confirmRecentSignature(txSigReschedule);
+
+    const roundAccount = await getRoundAccount(signer.address, programAddress, 0n);
+    assert.strictEqual(roundAccount.start_at, startSlot + 30n);
+    assert.strictEqual(roundAccount.end_at, startSlot + 130n);
+    assert.strictEqual(roundAccount.reschedule_count, 1);
+  });
+
+  test("activate round below minimum participants", async () => {
+    const signer = await generateKeyPairSigner();
+    const user = await generateKeyPairSigner();
+    await sendAndConfirmAirdrop(signer.address, BigInt(LAMPORTS_PER_SOL));
+    await sendAndConfirmAirdrop(user.address, BigInt(LAMPORTS_PER_SOL));
+    const recentSlot = await client.getSlot({ commitment: "confirmed" }).send();
+    const startSlot = recentSlot + 10n;
+
+    const txSig = await initialiseRound(signer, programAddress, 0n, startSlot, startSlot + 100n, {
+      minParticipants: 2n,
+    });
+    await confirmRecentSignature(txSig);
+
+    const txSigInterest = await signalInterest(user, programAddress, signer.address, 0n);
+    await confirmRecentSignature(txSigInterest);
+
+    const roundAccount = await getRoundAccount(signer.address, programAddress, 0n);
+    assert.strictEqual(roundAccount.min_participants, 2n);
+    assert.strictEqual(roundAccount.interest_count, 1n);
+
+    const atSlot = await waitForSlot(startSlot);
+    if (!atSlot) {
+      assert.fail(`Round start slot ${startSlot} not reached within timeout`);
+    }
+
+    await assert.rejects(async () => {
+      await activateRound(signer, programAddress, signer.address, 0n);
+    }, (err: ProgramError) => {
+      const errorMessage = err.context.logs.join(" ");
+      assert.match(errorMessage, /NotEnoughInterest/);
+      return true;
+    });
+  });
+
+  test("schedule round by unix timestamp", async () => {
+    const signer = await generateKeyPairSigner();
+    await sendAndConfirmAirdrop(signer.address, BigInt(LAMPORTS_PER_SOL));
+    const startTime = BigInt(Math.floor(Date.now() / 1000)) + 60n;
+
+    const txSig = await initialiseRound(signer, programAddress, 0n, startTime, startTime + 3600n, {
+      scheduleMode: "UnixTimestamp",
+    });
+    await confirmRecentSignature(txSig);
+
+    const roundAccount = await getRoundAccount(signer.address, programAddress, 0n);
+    assert.strictEqual(roundAccount.schedule_mode, "UnixTimestamp");
+    assert.strictEqual(roundAccount.start_at, startTime);
+
+    assert.rejects(async () => {
+      await activateRound(signer, programAddress, signer.address, 0n);
+    }, (err: ProgramError) => {
+      const errorMessage = err.context.logs.join(" ");
+      assert.match(errorMessage, /InvalidRoundActivationSlot/);
+      return true;
+    });
+  });
+
+  test("commit & reveal submission", async () => {
+    const signer = await generateKeyPairSigner();
+    const user = await generateKeyPairSigner();
+    await sendAndConfirmAirdrop(signer.address, BigInt(LAMPORTS_PER_SOL));
+    await sendAndConfirmAirdrop(user.address, BigInt(LAMPORTS_PER_SOL));
+    const recentSlot = await client.getSlot({ commitment: "confirmed" }).send();
+    const startSlot = recentSlot + 3n;
+    const revealStartSlot = startSlot + 20n;
+    const salt = new Uint8Array(32).fill(7);
+
+    const txSig = await initialiseRound(signer, programAddress, 0n, startSlot, startSlot + 100n, {
+      revealStartAt: revealStartSlot,
+    });
+    await confirmRecentSignature(txSig);
+
+    const atSlot = await waitForSlot(startSlot);
+    if (!atSlot) {
+      assert.fail(`Round start slot ${startSlot} not reached within timeout`);
+    }
+
+    await confirmRecentSignature(await activateRound(signer, programAddress, signer.address, 0n));
+    await confirmRecentSignature(await joinRound(user, programAddress, signer.address, 0n));
+
+    const txSigCommit = await commitSubmission(user, programAddress, signer.address, 0n, 42n, salt);
+    await confirmRecentSignature(txSigCommit);
+
+    const committed = await getSubmissionAccount(user.address, programAddress, signer.address, 0n);
+    assert.strictEqual(committed.user, user.address);
+    assert.strictEqual(isSome(committed.value), false);
+
+    const atRevealSlot = await waitForSlot(revealStartSlot);
+    if (!atRevealSlot) {
+      assert.fail(`Reveal start slot ${revealStartSlot} not reached within timeout`);
+    }
+
+    const txSigReveal = await revealSubmission(user, programAddress, signer.address, 0n, 42n, salt);
+    await confirmRecentSignature(txSigReveal);
+
+    const revealed = await getSubmissionAccount(user.address, programAddress, signer.address, 0n);
+    assert.deepStrictEqual(revealed.value, some(42n));
+
+    const roundAccount = await getRoundAccount(signer.address, programAddress, 0n);
+    assert.strictEqual(roundAccount.reveal_count, 1n);
+  });
+
+  test("close user accounts of a closed round", async () => {
+    const signer = await generateKeyPairSigner();
+    const user = await generateKeyPairSigner();
+    await sendAndConfirmAirdrop(signer.address, BigInt(LAMPORTS_PER_SOL));
+    await sendAndConfirmAirdrop(user.address, BigInt(LAMPORTS_PER_SOL));
+    const recentSlot = await client.getSlot({ commitment: "confirmed" }).send();
+    const startSlot = recentSlot + 10n;
+    const salt = new Uint8Array(32).fill(7);
+
+    const txSig = await initialiseRound(signer, programAddress, 0n, startSlot, startSlot + 100n, {
+      revealStartAt: startSlot + 50n,
+    });
+    await confirmRecentSignature(txSig);
+    await confirmRecentSignature(await signalInterest(user, programAddress, signer.address, 0n));
+
+    const atSlot = await waitForSlot(startSlot);
+    if (!atSlot) {
+      assert.fail(`Round start slot ${startSlot} not reached within timeout`);
+    }
+
+    await confirmRecentSignature(await activateRound(signer, programAddress, signer.address, 0n));
+    await confirmRecentSignature(await joinRound(user, programAddress, signer.address, 0n));
+    await confirmRecentSignature(await commitSubmission(user, programAddress, signer.address, 0n, 42n, salt));
+    await confirmRecentSignature(await completeRound(signer, programAddress, signer.address, 0n));
+    await confirmRecentSignature(await closeRound(signer, programAddress, 0n));
+
+    const txSigParticipant = await closeParticipant(user, programAddress, signer.address, 0n);
+    await confirmRecentSignature(txSigParticipant);
+    await assert.rejects(getParticipantAccount(user.address, programAddress, signer.address, 0n), /does not exist/);
+
+    const txSigSubmission = await closeSubmission(user, programAddress, signer.address, 0n);
+    await confirmRecentSignature(txSigSubmission);
+    await assert.rejects(getSubmissionAccount(user.address, programAddress, signer.address, 0n), /does not exist/);
+
+    // The interest account's rent comes back to the user, more than their transaction fee
+    const balance = await getBalance(user.address);
+    const txSigInterest = await closeInterest(user, programAddress, signer.address, 0n);
+    await confirmRecentSignature(txSigInterest);
+    assert.ok((await getBalance(user.address)) > balance);
+  });
+
+  test("score round & freeze leaderboard", async () => {
+    const signer = await generateKeyPairSigner();
+    const scorer = await generateKeyPairSigner();
+    const users = [await generateKeyPairSigner(), await generateKeyPairSigner()];
+    for (const account of [signer, scorer, ...users]) {
+      await sendAndConfirmAirdrop(account.address, BigInt(LAMPORTS_PER_SOL));
+    }
+    const recentSlot = await client.getSlot({ commitment: "confirmed" }).send();
+    const startSlot = recentSlot + 3n;
+
+    const txSig = await initialiseRound(signer, programAddress, 0n, startSlot, startSlot + 100n, {
+      scorer: scorer.address,
+    });
+    await confirmRecentSignature(txSig);
+
+    const atSlot = await waitForSlot(startSlot);
+    if (!atSlot) {
+      assert.fail(`Round start slot ${startSlot} not reached within timeout`);
+    }
+
+    await confirmRecentSignature(await activateRound(signer, programAddress, signer.address, 0n));
+    for (const user of users) {
+      await confirmRecentSignature(await joinRound(user, programAddress, signer.address, 0n));
+    }
+
+    await confirmRecentSignature(await submitScore(scorer, programAddress, signer.address, 0n, users[0].address, 10n));
+    await confirmRecentSignature(await submitScore(signer, programAddress, signer.address, 0n, users[1].address, 20n));
+
+    const txSigComplete = await completeRound(signer, programAddress, signer.address, 0n);
+    await confirmRecentSignature(txSigComplete);
+
+    const leaderboard = await getLeaderboardAccount(signer.address, programAddress, 0n);
+    assert.deepStrictEqual(
+      leaderboard.entries.map((entry) => [entry.user, entry.score]),
+      [[users[1].address, 20n], [users[0].address, 10n]],
+    );
+    assert.strictEqual(isSome(leaderboard.frozen_at), true);
+
+    const roundAccount = await getRoundAccount(signer.address, programAddress, 0n);
+    assert.deepStrictEqual(roundAccount.scorer, some(scorer.address));
+  });
+
+  test("roll round series", async () => {
+    const signer = await generateKeyPairSigner();
+    const keeper = await generateKeyPairSigner();
+    await sendAndConfirmAirdrop(signer.address, BigInt(LAMPORTS_PER_SOL));
+    await sendAndConfirmAirdrop(keeper.address, BigInt(LAMPORTS_PER_SOL));
+    const recentSlot = await client.getSlot({ commitment: "confirmed" }).send();
+    const startSlot = recentSlot + 5n;
+
+    const txSig = await initialiseSeries(signer, programAddress, 0n, startSlot, 20n, 10n);
+    await confirmRecentSignature(txSig);
+
+    const firstRound = await getRoundAccount(signer.address, programAddress, 0n);
+    assert.strictEqual(firstRound.start_at, startSlot);
+    assert.strictEqual(firstRound.end_at, startSlot + 10n);
+
+    const atSlot = await waitForSlot(startSlot + 20n);
+    if (!atSlot) {
+      assert.fail(`Series roll slot ${startSlot + 20n} not reached within timeout`);
+    }
+
+    const txSigRoll = await rollSeries(keeper, programAddress, signer.address, 0n);
+    await confirmRecentSignature(txSigRoll);
+
+    const series = await getSeriesAccount(signer.address, programAddress, 0n);
+    assert.strictEqual(series.round_id, 1n);
+    assert.strictEqual(series.round_start_at, startSlot + 20n);
+
+    const rolledRound = await getRoundAccount(signer.address, programAddress, 0n);
+    assert.strictEqual(rolledRound.status.__kind, "Cancelled");
+
+    const nextRound = await getRoundAccount(signer.address, programAddress, 1n);
+    assert.strictEqual(nextRound.start_at, startSlot + 20n);
+    assert.deepStrictEqual(nextRound.status, { __kind: "Scheduled" });
+
+    const txSigClose = await closeSeries(signer, programAddress, 0n);
+    await confirmRecentSignature(txSigClose);
+
+    await assert.rejects(getSeriesAccount(signer.address, programAddress, 0n), /does not exist/);
+  });
+
+  test("initialise successive rounds", async () => {
+    const signer = await generateKeyPairSigner();
+    await sendAndConfirmAirdrop(signer.address, BigInt(LAMPORTS_PER_SOL));
+    const recentSlot = await client.getSlot({ commitment: "confirmed" }).send();
+    const startSlot = recentSlot + 50n;
+
+    assert.strictEqual(await getNextRoundId(signer.address, programAddress), 0n);
+
+    for (const roundId of [0n, 1n]) {
+      const txSig = await initialiseRound(signer, programAddress, roundId, startSlot, startSlot + 100n);
+      await confirmRecentSignature(txSig);
+
+      const roundAccount = await getRoundAccount(signer.address, programAddress, roundId);
+      assert.strictEqual(roundAccount.round_id, roundId);
+    }
+
+    assert.strictEqual(await getNextRoundId(signer.address, programAddress), 2n);
+  });
+
+  test("initialise round with invalid round id", async () => {
+    const signer = await generateKeyPairSigner();
+    await sendAndConfirmAirdrop(signer.address, BigInt(LAMPORTS_PER_SOL));
+    const recentSlot = await client.getSlot({ commitment: "confirmed" }).send();
+    const startSlot = recentSlot + 50n;
+
+    assert.rejects(async () => {
+      await initialiseRound(signer, programAddress, 1n, startSlot, startSlot + 100n);
+    }, (err: ProgramError) => {
+      const errorMessage = err.context.logs.join(" ");
+      assert.match(errorMessage, /InvalidRoundId/);
+      return true;
+    });
   });
 
   test("initialise round with invalid start slot", async () => {
@@ -68,7 +586,7 @@ describe("solana program round interface", () => {
     const startSlot = recentSlot - 1n;
 
     assert.rejects(async () => {
-      await initialiseRound(signer, programAddress, startSlot);
+      await initialiseRound(signer, programAddress, 0n, startSlot, startSlot + 100n);
     }, (err: ProgramError) => {
       const errorMessage = err.context.logs.join(" ");
       assert.match(errorMessage, /InvalidStartSlot/);
@@ -76,12 +594,27 @@ describe("solana program round interface", () => {
     });
   });
 
+  test("initialise round with invalid end slot", async () => {
+    const signer = await generateKeyPairSigner();
+    await sendAndConfirmAirdrop(signer.address, BigInt(LAMPORTS_PER_SOL));
+    const recentSlot = await client.getSlot({ commitment: "confirmed" }).send();
+    const startSlot = recentSlot + 50n;
+
+    assert.rejects(async () => {
+      await initialiseRound(signer, programAddress, 0n, startSlot, startSlot);
+    }, (err: ProgramError) => {
+      const errorMessage = err.context.logs.join(" ");
+      assert.match(errorMessage, /InvalidEndSlot/);
+      return true;
+    });
+  });
+
   test("activate round before initialisation", async () => {
     const signer = await generateKeyPairSigner();
     await sendAndConfirmAirdrop(signer.address, BigInt(LAMPORTS_PER_SOL));
 
     assert.rejects(async () => {
-      await activateRound(signer, programAddress, signer.address);
+      await activateRound(signer, programAddress, signer.address, 0n);
     }, (err: ProgramError) => {
       const errorMessage = err.context.logs.join(" ");
       assert.match(errorMessage, /expected this account to be already initialized/);
@@ -95,11 +628,11 @@ describe("solana program round interface", () => {
     const recentSlot = await client.getSlot({ commitment: "confirmed" }).send();
     const startSlot = recentSlot + 50n;
 
-    const txSig = await initialiseRound(signer, programAddress, startSlot);
+    const txSig = await initialiseRound(signer, programAddress, 0n, startSlot, startSlot + 100n);
     await confirmRecentSignature(txSig);
 
     assert.rejects(async () => {
-      await activateRound(signer, programAddress, signer.address);
+      await activateRound(signer, programAddress, signer.address, 0n);
     }, (err: ProgramError) => {
       const errorMessage = err.context.logs.join(" ");
       assert.match(errorMessage, /InvalidRoundActivationSlot/);
@@ -112,7 +645,7 @@ describe("solana program round interface", () => {
     await sendAndConfirmAirdrop(signer.address, BigInt(LAMPORTS_PER_SOL));
 
     assert.rejects(async () => {
-      await completeRound(signer, programAddress);
+      await completeRound(signer, programAddress, signer.address, 0n);
     }, (err: ProgramError) => {
       const errorMessage = err.context.logs.join(" ");
       assert.match(errorMessage, /expected this account to be already initialized/);
@@ -5,39 +5,120 @@ import {
   assertAccountExists,
   Decoder,
   fetchEncodedAccount,
+  getBooleanDecoder,
+  getBooleanEncoder,
   getAddressDecoder,
+  getAddressEncoder,
+  getBytesDecoder,
+  getDiscriminatedUnionDecoder,
   getOptionDecoder,
+  getOptionEncoder,
+  getProgramDerivedAddress,
   getStructDecoder,
+  getU16Decoder,
+  getU16Encoder,
+  getU32Decoder,
+  getU32Encoder,
   getU64Decoder,
   getU64Encoder,
+  getU8Decoder,
+  getU8Encoder,
+  getUnitDecoder,
   KeyPairSigner,
   offsetDecoder,
+  fixDecoderSize,
+  getArrayDecoder,
   Option,
+  SYSVAR_SLOT_HASHES_ADDRESS,
+  transformDecoder,
 } from "@solana/kit";
 import { getInstructionDiscriminator, getPda, skipAnchorDiscriminator } from "../solana_program/solana_program_utils";
 import { SYSTEM_PROGRAM_ADDRESS } from "@solana-program/system";
 import { Buffer } from "node:buffer";
+import { createHash } from "node:crypto";
 import { initRpcClient } from "../solana_rpc/solana_rpc_utils";
 import {
   createBaseTxWithFeePayerAndLifetime,
   signAndSendTransaction,
 } from "../solana_transaction/solana_transaction_utils";
 
+// The optional settings of a round, the default is a free round without a participant cap or bounty.
+// `maxParticipants` caps how many users can join; `null` leaves the round uncapped.
+// A non-zero `entryFee` (lamports) makes the round paid, see `enterRound`. The authority keeps
+// `commissionBps` of the pot when a winner is paid. `activationBounty` lamports go to whoever activates it.
+// `scheduleMode` sets what its start and end count, unix timestamps (seconds) rather than slots
+// follow the wall clock. The registration flags limit activating or joining the round to users holding a
+// confirmed registration in the register program. A `revealStartAt` within the round lets participants
+// `commitSubmission` sealed values before it and `revealSubmission` them after. A `scorer` can `submitScore`
+// alongside the authority. A round can't activate until `minParticipants` users `signalInterest` in it.
+export type RoundConfig = {
+  maxParticipants?: bigint | null;
+  entryFee?: bigint;
+  commissionBps?: number;
+  activationBounty?: bigint;
+  scheduleMode?: ScheduleMode;
+  activationRequiresRegistration?: boolean;
+  participationRequiresRegistration?: boolean;
+  revealStartAt?: bigint | null;
+  scorer?: Address | null;
+  minParticipants?: bigint;
+};
+
+// `ScheduleMode` variants, in the order of their borsh tags
+const SCHEDULE_MODES = ["Slot", "UnixTimestamp"] as const;
+
+export type ScheduleMode = typeof SCHEDULE_MODES[number];
+
+// `roundId` must be the authority's next round id, see `getNextRoundId`.
 export const initialiseRound = async (
   authority: KeyPairSigner,
   programAddress: Address,
+  roundId: bigint,
   startSlot: bigint,
+  endSlot: bigint,
+  config: RoundConfig = {},
 ) => {
+  const {
+    maxParticipants = null,
+    entryFee = 0n,
+    commissionBps = 0,
+    activationBounty = 0n,
+    scheduleMode = "Slot",
+    activationRequiresRegistration = false,
+    participationRequiresRegistration = false,
+    revealStartAt = null,
+    scorer = null,
+    minParticipants = 0n,
+  } = config;
   const discriminator = getInstructionDiscriminator("initialise_round", "round");
   const payer = authority.address;
-  const pda = await getPda(payer, programAddress, "round");
+  const roundAuthorityPda = await getPda(payer, programAddress, "round_authority");
+  const pda = await getRoundPda(payer, programAddress, roundId);
+  const potPda = await getPotPda(pda, programAddress);
   const baseTx = await createBaseTxWithFeePayerAndLifetime(payer);
 
   const tx = appendTransactionMessageInstruction({
     programAddress,
-    data: Buffer.concat([discriminator, Buffer.from(getU64Encoder().encode(startSlot))]),
+    data: Buffer.concat([
+      discriminator,
+      Buffer.from(getU64Encoder().encode(roundId)),
+      Buffer.from(getU64Encoder().encode(startSlot)),
+      Buffer.from(getU64Encoder().encode(endSlot)),
+      Buffer.from(getOptionEncoder(getU64Encoder()).encode(maxParticipants)),
+      Buffer.from(getU64Encoder().encode(entryFee)),
+      Buffer.from(getU16Encoder().encode(commissionBps)),
+      Buffer.from(getU64Encoder().encode(activationBounty)),
+      Buffer.from(getU8Encoder().encode(SCHEDULE_MODES.indexOf(scheduleMode))),
+      Buffer.from(getBooleanEncoder().encode(activationRequiresRegistration)),
+      Buffer.from(getBooleanEncoder().encode(participationRequiresRegistration)),
+      Buffer.from(getOptionEncoder(getU64Encoder()).encode(revealStartAt)),
+      Buffer.from(getOptionEncoder(getAddressEncoder()).encode(scorer)),
+      Buffer.from(getU64Encoder().encode(minParticipants)),
+    ]),
     accounts: [
+      { address: roundAuthorityPda, role: AccountRole.WRITABLE },
       { address: pda, role: AccountRole.WRITABLE },
+      { address: potPda, role: AccountRole.WRITABLE },
       { address: payer, role: AccountRole.WRITABLE_SIGNER },
       { address: SYSTEM_PROGRAM_ADDRESS, role: AccountRole.READONLY },
     ],
@@ -48,9 +129,9 @@ export const initialiseRound = async (
   return sig;
 };
 
-export const getRoundAccount = async (authority: Address, programAddress: Address) => {
+export const getRoundAccount = async (authority: Address, programAddress: Address, roundId: bigint) => {
   const client = initRpcClient();
-  const pda = await getPda(authority, programAddress, "round");
+  const pda = await getRoundPda(authority, programAddress, roundId);
   const account = await fetchEncodedAccount(client, pda, {
     commitment: "confirmed",
     abortSignal: AbortSignal.timeout(5000),
@@ -67,9 +148,161 @@ export const getRoundAccount = async (authority: Address, programAddress: Addres
   return decoded;
 };
 
-export const activateRound = async (payer: KeyPairSigner, programAddress: Address, authority: Address) => {
+// An authority without rounds has no `RoundAuthority` account yet, so its first round id is 0.
+export const getNextRoundId = async (authority: Address, programAddress: Address) => {
+  const client = initRpcClient();
+  const pda = await getPda(authority, programAddress, "round_authority");
+  const account = await fetchEncodedAccount(client, pda, {
+    commitment: "confirmed",
+    abortSignal: AbortSignal.timeout(5000),
+  });
+
+  if (!account.exists) {
+    return 0n;
+  }
+
+  const decoded = roundAuthorityAccountDecoder.decode(account.data);
+
+  return decoded.next_round_id;
+};
+
+export const getSeriesAddress = async (authority: Address, programAddress: Address, seriesId: bigint) => {
+  const [pda] = await getProgramDerivedAddress({
+    programAddress,
+    seeds: [Buffer.from("series"), getAddressEncoder().encode(authority), getU64Encoder().encode(seriesId)],
+  });
+  return pda;
+};
+
+// Schedules rounds `intervalSlots` apart, each open for `durationSlots`, the first at `startSlot`.
+// Series rounds are free rounds of the authority, taking its next round ids, so it can cancel, settle and
+// close them as usual. Anyone can activate them and `rollSeries` on to the next.
+export const initialiseSeries = async (
+  authority: KeyPairSigner,
+  programAddress: Address,
+  seriesId: bigint,
+  startSlot: bigint,
+  intervalSlots: bigint,
+  durationSlots: bigint,
+) => {
+  const discriminator = getInstructionDiscriminator("initialise_series", "round");
+  const seriesPda = await getSeriesAddress(authority.address, programAddress, seriesId);
+  const roundAuthorityPda = await getPda(authority.address, programAddress, "round_authority");
+  const roundId = await getNextRoundId(authority.address, programAddress);
+  const roundPda = await getRoundPda(authority.address, programAddress, roundId);
+  const baseTx = await createBaseTxWithFeePayerAndLifetime(authority.address);
+
+  const tx = appendTransactionMessageInstruction({
+    programAddress,
+    data: Buffer.concat([
+      discriminator,
+      Buffer.from(getU64Encoder().encode(seriesId)),
+      Buffer.from(getU64Encoder().encode(startSlot)),
+      Buffer.from(getU64Encoder().encode(intervalSlots)),
+      Buffer.from(getU64Encoder().encode(durationSlots)),
+    ]),
+    accounts: [
+      { address: seriesPda, role: AccountRole.WRITABLE },
+      { address: roundAuthorityPda, role: AccountRole.WRITABLE },
+      { address: roundPda, role: AccountRole.WRITABLE },
+      { address: authority.address, role: AccountRole.WRITABLE_SIGNER },
+      { address: SYSTEM_PROGRAM_ADDRESS, role: AccountRole.READONLY },
+    ],
+  }, baseTx);
+
+  const sig = await signAndSendTransaction(tx, authority.keyPair);
+
+  return sig;
+};
+
+// Anyone can roll a series once its next start is due, completing its current round and scheduling the next.
+// The `payer` covers the next round's rent, which goes to the authority when it closes the round.
+export const rollSeries = async (
+  payer: KeyPairSigner,
+  programAddress: Address,
+  authority: Address,
+  seriesId: bigint,
+) => {
+  const discriminator = getInstructionDiscriminator("roll_series", "round");
+  const seriesPda = await getSeriesAddress(authority, programAddress, seriesId);
+  const roundAuthorityPda = await getPda(authority, programAddress, "round_authority");
+  const { round_id } = await getSeriesAccount(authority, programAddress, seriesId);
+  const currentRoundPda = await getRoundPda(authority, programAddress, round_id);
+  const leaderboardPda = await getLeaderboardPda(currentRoundPda, programAddress);
+  const nextRoundId = await getNextRoundId(authority, programAddress);
+  const nextRoundPda = await getRoundPda(authority, programAddress, nextRoundId);
+  const baseTx = await createBaseTxWithFeePayerAndLifetime(payer.address);
+
+  const tx = appendTransactionMessageInstruction({
+    programAddress,
+    data: discriminator,
+    accounts: [
+      { address: seriesPda, role: AccountRole.WRITABLE },
+      { address: roundAuthorityPda, role: AccountRole.WRITABLE },
+      { address: currentRoundPda, role: AccountRole.WRITABLE },
+      { address: nextRoundPda, role: AccountRole.WRITABLE },
+      { address: payer.address, role: AccountRole.WRITABLE_SIGNER },
+      { address: SYSTEM_PROGRAM_ADDRESS, role: AccountRole.READONLY },
+      { address: leaderboardPda, role: AccountRole.WRITABLE },
+    ],
+  }, baseTx);
+
+  const sig = await signAndSendTransaction(tx, payer.keyPair);
+
+  return sig;
+};
+
+// Stops a series rolling and returns its rent to the authority, its current round carries on as any other
+export const closeSeries = async (authority: KeyPairSigner, programAddress: Address, seriesId: bigint) => {
+  const discriminator = getInstructionDiscriminator("close_series", "round");
+  const seriesPda = await getSeriesAddress(authority.address, programAddress, seriesId);
+  const baseTx = await createBaseTxWithFeePayerAndLifetime(authority.address);
+
+  const tx = appendTransactionMessageInstruction({
+    programAddress,
+    data: discriminator,
+    accounts: [
+      { address: seriesPda, role: AccountRole.WRITABLE },
+      { address: authority.address, role: AccountRole.WRITABLE_SIGNER },
+    ],
+  }, baseTx);
+
+  const sig = await signAndSendTransaction(tx, authority.keyPair);
+
+  return sig;
+};
+
+// `round_id` is the authority's round id of the series' current round, `round_start_at` its start
+export const getSeriesAccount = async (authority: Address, programAddress: Address, seriesId: bigint) => {
+  const client = initRpcClient();
+  const pda = await getSeriesAddress(authority, programAddress, seriesId);
+  const account = await fetchEncodedAccount(client, pda, {
+    commitment: "confirmed",
+    abortSignal: AbortSignal.timeout(5000),
+  });
+
+  if (!account.exists) {
+    throw new Error(`Account ${pda} does not exist`);
+  }
+
+  assertAccountExists(account);
+
+  const decoded = seriesAccountDecoder.decode(account.data);
+
+  return decoded;
+};
+
+// Rounds gated on registration need `registerProgramAddress`, to pass the payer's registration.
+export const activateRound = async (
+  payer: KeyPairSigner,
+  programAddress: Address,
+  authority: Address,
+  roundId: bigint,
+  registerProgramAddress: Address | null = null,
+) => {
   const discriminator = getInstructionDiscriminator("activate_round", "round");
-  const pda = await getPda(authority, programAddress, "round");
+  const pda = await getRoundPda(authority, programAddress, roundId);
+  const registration = await getRegistrationAddress(payer.address, programAddress, registerProgramAddress);
   const baseTx = await createBaseTxWithFeePayerAndLifetime(payer.address);
 
   const tx = appendTransactionMessageInstruction({
@@ -78,6 +311,7 @@ export const activateRound = async (payer: KeyPairSigner, programAddress: Addres
     accounts: [
       { address: pda, role: AccountRole.WRITABLE },
       { address: payer.address, role: AccountRole.WRITABLE_SIGNER },
+      { address: registration, role: AccountRole.READONLY },
     ],
   }, baseTx);
 
@@ -86,9 +320,70 @@ export const activateRound = async (payer: KeyPairSigner, programAddress: Addres
   return sig;
 };
 
-export const completeRound = async (authority: KeyPairSigner, programAddress: Address) => {
+// The round's authority can complete it at any time, any `payer` once its end has passed. Completing a scored
+// round freezes its leaderboard.
+export const completeRound = async (
+  payer: KeyPairSigner,
+  programAddress: Address,
+  authority: Address,
+  roundId: bigint,
+) => {
   const discriminator = getInstructionDiscriminator("complete_round", "round");
-  const pda = await getPda(authority.address, programAddress, "round");
+  const pda = await getRoundPda(authority, programAddress, roundId);
+  const leaderboardPda = await getLeaderboardPda(pda, programAddress);
+  const baseTx = await createBaseTxWithFeePayerAndLifetime(payer.address);
+
+  const tx = appendTransactionMessageInstruction({
+    programAddress,
+    data: discriminator,
+    accounts: [
+      { address: pda, role: AccountRole.WRITABLE },
+      { address: payer.address, role: AccountRole.WRITABLE_SIGNER },
+      { address: leaderboardPda, role: AccountRole.WRITABLE },
+    ],
+  }, baseTx);
+
+  const sig = await signAndSendTransaction(tx, payer.keyPair);
+
+  return sig;
+};
+
+// Once the round is completed its authority names the `winner`, who must have entered, and pays them the pot less
+// the commission. A drawn round's winner must be the participant at the drawn entry index. Left unsettled past the
+// settlement window, the entries become refundable instead.
+export const settleRound = async (
+  authority: KeyPairSigner,
+  programAddress: Address,
+  roundId: bigint,
+  winner: Address,
+) => {
+  const discriminator = getInstructionDiscriminator("settle_round", "round");
+  const pda = await getRoundPda(authority.address, programAddress, roundId);
+  const potPda = await getPotPda(pda, programAddress);
+  const winnerParticipantPda = await getParticipantPda(pda, winner, programAddress);
+  const baseTx = await createBaseTxWithFeePayerAndLifetime(authority.address);
+
+  const tx = appendTransactionMessageInstruction({
+    programAddress,
+    data: discriminator,
+    accounts: [
+      { address: pda, role: AccountRole.WRITABLE },
+      { address: authority.address, role: AccountRole.WRITABLE_SIGNER },
+      { address: potPda, role: AccountRole.WRITABLE },
+      { address: winner, role: AccountRole.WRITABLE },
+      { address: winnerParticipantPda, role: AccountRole.READONLY },
+      { address: SYSTEM_PROGRAM_ADDRESS, role: AccountRole.READONLY },
+    ],
+  }, baseTx);
+
+  const sig = await signAndSendTransaction(tx, authority.keyPair);
+
+  return sig;
+};
+
+export const cancelRound = async (authority: KeyPairSigner, programAddress: Address, roundId: bigint) => {
+  const discriminator = getInstructionDiscriminator("cancel_round", "round");
+  const pda = await getRoundPda(authority.address, programAddress, roundId);
   const baseTx = await createBaseTxWithFeePayerAndLifetime(authority.address);
 
   const tx = appendTransactionMessageInstruction({
@@ -105,19 +400,671 @@ export const completeRound = async (authority: KeyPairSigner, programAddress: Ad
   return sig;
 };
 
+// Rounds gated on registration need `registerProgramAddress`, to pass the user's registration.
+export const joinRound = async (
+  user: KeyPairSigner,
+  programAddress: Address,
+  authority: Address,
+  roundId: bigint,
+  registerProgramAddress: Address | null = null,
+) => {
+  const discriminator = getInstructionDiscriminator("join_round", "round");
+  const pda = await getRoundPda(authority, programAddress, roundId);
+  const participantPda = await getParticipantPda(pda, user.address, programAddress);
+  const registration = await getRegistrationAddress(user.address, programAddress, registerProgramAddress);
+  const baseTx = await createBaseTxWithFeePayerAndLifetime(user.address);
+
+  const tx = appendTransactionMessageInstruction({
+    programAddress,
+    data: discriminator,
+    accounts: [
+      { address: pda, role: AccountRole.WRITABLE },
+      { address: participantPda, role: AccountRole.WRITABLE },
+      { address: user.address, role: AccountRole.WRITABLE_SIGNER },
+      { address: SYSTEM_PROGRAM_ADDRESS, role: AccountRole.READONLY },
+      { address: registration, role: AccountRole.READONLY },
+    ],
+  }, baseTx);
+
+  const sig = await signAndSendTransaction(tx, user.keyPair);
+
+  return sig;
+};
+
+// Pays the round's entry fee into its pot, `registerProgramAddress` as for `joinRound`
+export const enterRound = async (
+  user: KeyPairSigner,
+  programAddress: Address,
+  authority: Address,
+  roundId: bigint,
+  registerProgramAddress: Address | null = null,
+) => {
+  const discriminator = getInstructionDiscriminator("enter_round", "round");
+  const pda = await getRoundPda(authority, programAddress, roundId);
+  const participantPda = await getParticipantPda(pda, user.address, programAddress);
+  const potPda = await getPotPda(pda, programAddress);
+  const registration = await getRegistrationAddress(user.address, programAddress, registerProgramAddress);
+  const baseTx = await createBaseTxWithFeePayerAndLifetime(user.address);
+
+  const tx = appendTransactionMessageInstruction({
+    programAddress,
+    data: discriminator,
+    accounts: [
+      { address: pda, role: AccountRole.WRITABLE },
+      { address: participantPda, role: AccountRole.WRITABLE },
+      { address: potPda, role: AccountRole.WRITABLE },
+      { address: user.address, role: AccountRole.WRITABLE_SIGNER },
+      { address: SYSTEM_PROGRAM_ADDRESS, role: AccountRole.READONLY },
+      { address: registration, role: AccountRole.READONLY },
+    ],
+  }, baseTx);
+
+  const sig = await signAndSendTransaction(tx, user.keyPair);
+
+  return sig;
+};
+
+// Returns the entry fee once the round is cancelled, or completed and left unsettled past the settlement window
+export const refundEntry = async (
+  user: KeyPairSigner,
+  programAddress: Address,
+  authority: Address,
+  roundId: bigint,
+) => {
+  const discriminator = getInstructionDiscriminator("refund_entry", "round");
+  const pda = await getRoundPda(authority, programAddress, roundId);
+  const participantPda = await getParticipantPda(pda, user.address, programAddress);
+  const potPda = await getPotPda(pda, programAddress);
+  const baseTx = await createBaseTxWithFeePayerAndLifetime(user.address);
+
+  const tx = appendTransactionMessageInstruction({
+    programAddress,
+    data: discriminator,
+    accounts: [
+      { address: pda, role: AccountRole.WRITABLE },
+      { address: participantPda, role: AccountRole.WRITABLE },
+      { address: potPda, role: AccountRole.WRITABLE },
+      { address: user.address, role: AccountRole.WRITABLE_SIGNER },
+      { address: SYSTEM_PROGRAM_ADDRESS, role: AccountRole.READONLY },
+    ],
+  }, baseTx);
+
+  const sig = await signAndSendTransaction(tx, user.keyPair);
+
+  return sig;
+};
+
+// Counts the `user` towards the round's `minParticipants`, once per user before its start,
+// `registerProgramAddress` as for `joinRound`
+export const signalInterest = async (
+  user: KeyPairSigner,
+  programAddress: Address,
+  authority: Address,
+  roundId: bigint,
+  registerProgramAddress: Address | null = null,
+) => {
+  const discriminator = getInstructionDiscriminator("signal_interest", "round");
+  const pda = await getRoundPda(authority, programAddress, roundId);
+  const interestPda = await getInterestPda(pda, user.address, programAddress);
+  const registration = await getRegistrationAddress(user.address, programAddress, registerProgramAddress);
+  const baseTx = await createBaseTxWithFeePayerAndLifetime(user.address);
+
+  const tx = appendTransactionMessageInstruction({
+    programAddress,
+    data: discriminator,
+    accounts: [
+      { address: pda, role: AccountRole.WRITABLE },
+      { address: interestPda, role: AccountRole.WRITABLE },
+      { address: user.address, role: AccountRole.WRITABLE_SIGNER },
+      { address: SYSTEM_PROGRAM_ADDRESS, role: AccountRole.READONLY },
+      { address: registration, role: AccountRole.READONLY },
+    ],
+  }, baseTx);
+
+  const sig = await signAndSendTransaction(tx, user.keyPair);
+
+  return sig;
+};
+
+// Moves a round that has not started yet to `newStartSlot`, its end moves with it.
+export const rescheduleRound = async (
+  authority: KeyPairSigner,
+  programAddress: Address,
+  roundId: bigint,
+  newStartSlot: bigint,
+) => {
+  const discriminator = getInstructionDiscriminator("reschedule_round", "round");
+  const pda = await getRoundPda(authority.address, programAddress, roundId);
+  const baseTx = await createBaseTxWithFeePayerAndLifetime(authority.address);
+
+  const tx = appendTransactionMessageInstruction({
+    programAddress,
+    data: Buffer.concat([discriminator, Buffer.from(getU64Encoder().encode(newStartSlot))]),
+    accounts: [
+      { address: pda, role: AccountRole.WRITABLE },
+      { address: authority.address, role: AccountRole.WRITABLE_SIGNER },
+    ],
+  }, baseTx);
+
+  const sig = await signAndSendTransaction(tx, authority.keyPair);
+
+  return sig;
+};
+
+// Closes a completed or cancelled round and its leaderboard, returning their rent to the authority.
+// A paid round's entry fees must have been paid out or refunded first.
+export const closeRound = async (authority: KeyPairSigner, programAddress: Address, roundId: bigint) => {
+  const discriminator = getInstructionDiscriminator("close_round", "round");
+  const pda = await getRoundPda(authority.address, programAddress, roundId);
+  const potPda = await getPotPda(pda, programAddress);
+  const leaderboardPda = await getLeaderboardPda(pda, programAddress);
+  const baseTx = await createBaseTxWithFeePayerAndLifetime(authority.address);
+
+  const tx = appendTransactionMessageInstruction({
+    programAddress,
+    data: discriminator,
+    accounts: [
+      { address: pda, role: AccountRole.WRITABLE },
+      { address: potPda, role: AccountRole.WRITABLE },
+      { address: authority.address, role: AccountRole.WRITABLE_SIGNER },
+      { address: SYSTEM_PROGRAM_ADDRESS, role: AccountRole.READONLY },
+      { address: leaderboardPda, role: AccountRole.WRITABLE },
+    ],
+  }, baseTx);
+
+  const sig = await signAndSendTransaction(tx, authority.keyPair);
+
+  return sig;
+};
+
+// Once the round is closed, takes back the rent of the user's participant account
+export const closeParticipant = async (
+  user: KeyPairSigner,
+  programAddress: Address,
+  authority: Address,
+  roundId: bigint,
+) => {
+  const pda = await getRoundPda(authority, programAddress, roundId);
+  const participantPda = await getParticipantPda(pda, user.address, programAddress);
+
+  return await closeUserAccount("close_participant", user, programAddress, pda, participantPda);
+};
+
+// Once the round is closed, takes back the rent of the user's submission account
+export const closeSubmission = async (
+  user: KeyPairSigner,
+  programAddress: Address,
+  authority: Address,
+  roundId: bigint,
+) => {
+  const pda = await getRoundPda(authority, programAddress, roundId);
+  const submissionPda = await getSubmissionPda(pda, user.address, programAddress);
+
+  return await closeUserAccount("close_submission", user, programAddress, pda, submissionPda);
+};
+
+// Once the round is closed, takes back the rent of the user's interest account
+export const closeInterest = async (
+  user: KeyPairSigner,
+  programAddress: Address,
+  authority: Address,
+  roundId: bigint,
+) => {
+  const pda = await getRoundPda(authority, programAddress, roundId);
+  const interestPda = await getInterestPda(pda, user.address, programAddress);
+
+  return await closeUserAccount("close_interest", user, programAddress, pda, interestPda);
+};
+
+const closeUserAccount = async (
+  instruction: string,
+  user: KeyPairSigner,
+  programAddress: Address,
+  roundPda: Address,
+  accountPda: Address,
+) => {
+  const discriminator = getInstructionDiscriminator(instruction, "round");
+  const baseTx = await createBaseTxWithFeePayerAndLifetime(user.address);
+
+  const tx = appendTransactionMessageInstruction({
+    programAddress,
+    data: discriminator,
+    accounts: [
+      { address: roundPda, role: AccountRole.READONLY },
+      { address: accountPda, role: AccountRole.WRITABLE },
+      { address: user.address, role: AccountRole.WRITABLE_SIGNER },
+    ],
+  }, baseTx);
+
+  const sig = await signAndSendTransaction(tx, user.keyPair);
+
+  return sig;
+};
+
+// What `commitSubmission` seals and `revealSubmission` opens, mirrors the program's `submission_commitment`
+const getSubmissionCommitment = (roundPda: Address, user: Address, value: bigint, salt: Uint8Array) => {
+  return createHash("sha256")
+    .update(getAddressEncoder().encode(roundPda))
+    .update(getAddressEncoder().encode(user))
+    .update(getU64Encoder().encode(value))
+    .update(salt)
+    .digest();
+};
+
+// Seals `value` until the round's reveal start. Keep `salt` (32 random bytes) secret until revealing.
+export const commitSubmission = async (
+  user: KeyPairSigner,
+  programAddress: Address,
+  authority: Address,
+  roundId: bigint,
+  value: bigint,
+  salt: Uint8Array,
+) => {
+  const discriminator = getInstructionDiscriminator("commit", "round");
+  const pda = await getRoundPda(authority, programAddress, roundId);
+  const participantPda = await getParticipantPda(pda, user.address, programAddress);
+  const submissionPda = await getSubmissionPda(pda, user.address, programAddress);
+  const baseTx = await createBaseTxWithFeePayerAndLifetime(user.address);
+
+  const tx = appendTransactionMessageInstruction({
+    programAddress,
+    data: Buffer.concat([discriminator, getSubmissionCommitment(pda, user.address, value, salt)]),
+    accounts: [
+      { address: pda, role: AccountRole.READONLY },
+      { address: participantPda, role: AccountRole.READONLY },
+      { address: submissionPda, role: AccountRole.WRITABLE },
+      { address: user.address, role: AccountRole.WRITABLE_SIGNER },
+      { address: SYSTEM_PROGRAM_ADDRESS, role: AccountRole.READONLY },
+    ],
+  }, baseTx);
+
+  const sig = await signAndSendTransaction(tx, user.keyPair);
+
+  return sig;
+};
+
+// Opens a committed submission, from the round's reveal start until its end
+export const revealSubmission = async (
+  user: KeyPairSigner,
+  programAddress: Address,
+  authority: Address,
+  roundId: bigint,
+  value: bigint,
+  salt: Uint8Array,
+) => {
+  const discriminator = getInstructionDiscriminator("reveal", "round");
+  const pda = await getRoundPda(authority, programAddress, roundId);
+  const submissionPda = await getSubmissionPda(pda, user.address, programAddress);
+  const baseTx = await createBaseTxWithFeePayerAndLifetime(user.address);
+
+  const tx = appendTransactionMessageInstruction({
+    programAddress,
+    data: Buffer.concat([discriminator, Buffer.from(getU64Encoder().encode(value)), salt]),
+    accounts: [
+      { address: pda, role: AccountRole.WRITABLE },
+      { address: submissionPda, role: AccountRole.WRITABLE },
+      { address: user.address, role: AccountRole.READONLY_SIGNER },
+    ],
+  }, baseTx);
+
+  const sig = await signAndSendTransaction(tx, user.keyPair);
+
+  return sig;
+};
+
+// Records a participant's `score` while the round is active, signed by its authority or scorer. Each
+// participant keeps their best score, the leaderboard the top scores with ties in the order they were reached.
+export const submitScore = async (
+  scorer: KeyPairSigner,
+  programAddress: Address,
+  authority: Address,
+  roundId: bigint,
+  user: Address,
+  score: bigint,
+) => {
+  const discriminator = getInstructionDiscriminator("submit_score", "round");
+  const pda = await getRoundPda(authority, programAddress, roundId);
+  const participantPda = await getParticipantPda(pda, user, programAddress);
+  const leaderboardPda = await getLeaderboardPda(pda, programAddress);
+  const baseTx = await createBaseTxWithFeePayerAndLifetime(scorer.address);
+
+  const tx = appendTransactionMessageInstruction({
+    programAddress,
+    data: Buffer.concat([
+      discriminator,
+      Buffer.from(getAddressEncoder().encode(user)),
+      Buffer.from(getU64Encoder().encode(score)),
+    ]),
+    accounts: [
+      { address: pda, role: AccountRole.READONLY },
+      { address: participantPda, role: AccountRole.READONLY },
+      { address: leaderboardPda, role: AccountRole.WRITABLE },
+      { address: scorer.address, role: AccountRole.WRITABLE_SIGNER },
+      { address: SYSTEM_PROGRAM_ADDRESS, role: AccountRole.READONLY },
+    ],
+  }, baseTx);
+
+  const sig = await signAndSendTransaction(tx, scorer.keyPair);
+
+  return sig;
+};
+
+// Draws an index in `0..candidates` from the hash of the round's draw slot, once it has completed.
+// `candidates` must match the participant count if users joined on-chain, then any `payer` can draw.
+// Over an off-chain entry list only the round's authority can.
+export const drawWinner = async (
+  payer: KeyPairSigner,
+  programAddress: Address,
+  authority: Address,
+  roundId: bigint,
+  candidates: number,
+) => {
+  const discriminator = getInstructionDiscriminator("draw_winner", "round");
+  const pda = await getRoundPda(authority, programAddress, roundId);
+  const baseTx = await createBaseTxWithFeePayerAndLifetime(payer.address);
+
+  const tx = appendTransactionMessageInstruction({
+    programAddress,
+    data: Buffer.concat([discriminator, Buffer.from(getU32Encoder().encode(candidates))]),
+    accounts: [
+      { address: pda, role: AccountRole.WRITABLE },
+      { address: payer.address, role: AccountRole.WRITABLE_SIGNER },
+      { address: SYSVAR_SLOT_HASHES_ADDRESS, role: AccountRole.READONLY },
+    ],
+  }, baseTx);
+
+  const sig = await signAndSendTransaction(tx, payer.keyPair);
+
+  return sig;
+};
+
+export const getParticipantAccount = async (
+  user: Address,
+  programAddress: Address,
+  authority: Address,
+  roundId: bigint,
+) => {
+  const client = initRpcClient();
+  const roundPda = await getRoundPda(authority, programAddress, roundId);
+  const pda = await getParticipantPda(roundPda, user, programAddress);
+  const account = await fetchEncodedAccount(client, pda, {
+    commitment: "confirmed",
+    abortSignal: AbortSignal.timeout(5000),
+  });
+
+  if (!account.exists) {
+    throw new Error(`Account ${pda} does not exist`);
+  }
+
+  assertAccountExists(account);
+
+  const decoded = participantAccountDecoder.decode(account.data);
+
+  return decoded;
+};
+
+const getParticipantPda = async (roundPda: Address, user: Address, programAddress: Address): Promise<Address> => {
+  const [pda] = await getProgramDerivedAddress({
+    programAddress,
+    seeds: [Buffer.from("participant"), getAddressEncoder().encode(roundPda), getAddressEncoder().encode(user)],
+  });
+  return pda;
+};
+
+export const getSubmissionAccount = async (
+  user: Address,
+  programAddress: Address,
+  authority: Address,
+  roundId: bigint,
+) => {
+  const client = initRpcClient();
+  const roundPda = await getRoundPda(authority, programAddress, roundId);
+  const pda = await getSubmissionPda(roundPda, user, programAddress);
+  const account = await fetchEncodedAccount(client, pda, {
+    commitment: "confirmed",
+    abortSignal: AbortSignal.timeout(5000),
+  });
+
+  if (!account.exists) {
+    throw new Error(`Account ${pda} does not exist`);
+  }
+
+  assertAccountExists(account);
+
+  const decoded = submissionAccountDecoder.decode(account.data);
+
+  return decoded;
+};
+
+const getSubmissionPda = async (roundPda: Address, user: Address, programAddress: Address): Promise<Address> => {
+  const [pda] = await getProgramDerivedAddress({
+    programAddress,
+    seeds: [Buffer.from("submission"), getAddressEncoder().encode(roundPda), getAddressEncoder().encode(user)],
+  });
+  return pda;
+};
+
+const getInterestPda = async (roundPda: Address, user: Address, programAddress: Address): Promise<Address> => {
+  const [pda] = await getProgramDerivedAddress({
+    programAddress,
+    seeds: [Buffer.from("interest"), getAddressEncoder().encode(roundPda), getAddressEncoder().encode(user)],
+  });
+  return pda;
+};
+
+// Only scored rounds have a leaderboard, `entries` are best first
+export const getLeaderboardAccount = async (authority: Address, programAddress: Address, roundId: bigint) => {
+  const client = initRpcClient();
+  const roundPda = await getRoundPda(authority, programAddress, roundId);
+  const pda = await getLeaderboardPda(roundPda, programAddress);
+  const account = await fetchEncodedAccount(client, pda, {
+    commitment: "confirmed",
+    abortSignal: AbortSignal.timeout(5000),
+  });
+
+  if (!account.exists) {
+    throw new Error(`Account ${pda} does not exist`);
+  }
+
+  assertAccountExists(account);
+
+  const decoded = leaderboardAccountDecoder.decode(account.data);
+
+  return decoded;
+};
+
+const getLeaderboardPda = async (roundPda: Address, programAddress: Address): Promise<Address> => {
+  const [pda] = await getProgramDerivedAddress({
+    programAddress,
+    seeds: [Buffer.from("leaderboard"), getAddressEncoder().encode(roundPda)],
+  });
+  return pda;
+};
+
+const getPotPda = async (roundPda: Address, programAddress: Address): Promise<Address> => {
+  const [pda] = await getProgramDerivedAddress({
+    programAddress,
+    seeds: [Buffer.from("pot"), getAddressEncoder().encode(roundPda)],
+  });
+  return pda;
+};
+
+// The user's registration PDA in the register program, the program id stands in for it when omitted
+const getRegistrationAddress = async (
+  user: Address,
+  programAddress: Address,
+  registerProgramAddress: Address | null,
+): Promise<Address> => {
+  return registerProgramAddress ? await getPda(user, registerProgramAddress, "registration") : programAddress;
+};
+
+const getRoundPda = async (authority: Address, programAddress: Address, roundId: bigint): Promise<Address> => {
+  const [pda] = await getProgramDerivedAddress({
+    programAddress,
+    seeds: [Buffer.from("round"), getAddressEncoder().encode(authority), getU64Encoder().encode(roundId)],
+  });
+  return pda;
+};
+
+export type RoundStatus =
+  | { __kind: "Scheduled" }
+  | { __kind: "Active"; activated_at: bigint; activated_by: Address }
+  | { __kind: "Completed"; activated_at: bigint; activated_by: Address; completed_at: bigint }
+  | { __kind: "Cancelled"; cancelled_at: bigint };
+
+const roundStatusDecoder: Decoder<RoundStatus> = getDiscriminatedUnionDecoder([
+  ["Scheduled", getUnitDecoder()],
+  ["Active", getStructDecoder([["activated_at", getU64Decoder()], ["activated_by", getAddressDecoder()]])],
+  ["Completed", getStructDecoder([
+    ["activated_at", getU64Decoder()],
+    ["activated_by", getAddressDecoder()],
+    ["completed_at", getU64Decoder()],
+  ])],
+  ["Cancelled", getStructDecoder([["cancelled_at", getU64Decoder()]])],
+]);
+
+export type Draw = { candidates: number; index: number; slot: bigint; slot_hash: Uint8Array };
+
+const drawDecoder: Decoder<Draw> = getStructDecoder([
+  ["candidates", getU32Decoder()],
+  ["index", getU32Decoder()],
+  ["slot", getU64Decoder()],
+  ["slot_hash", fixDecoderSize(getBytesDecoder(), 32)],
+]);
+
 const roundAccountDecoder: Decoder<{
-  start_slot: bigint;
+  start_at: bigint;
+  authority: Address;
+  version: number;
+  round_id: bigint;
+  end_at: bigint;
+  status: RoundStatus;
+  participant_count: bigint;
+  max_participants: Option<bigint>;
+  entry_fee: bigint;
+  commission_bps: number;
+  winner: Option<Address>;
+  draw_slot: bigint;
+  draw: Option<Draw>;
+  activation_bounty: bigint;
+  reschedule_count: number;
+  schedule_mode: ScheduleMode;
+  activation_requires_registration: boolean;
+  participation_requires_registration: boolean;
+  reveal_start_at: Option<bigint>;
+  reveal_count: bigint;
+  scorer: Option<Address>;
+  min_participants: bigint;
+  interest_count: bigint;
+  held_entries: bigint;
+}> = offsetDecoder(
+  getStructDecoder([
+    ["start_at", getU64Decoder()],
+    ["authority", getAddressDecoder()],
+    ["version", getU8Decoder()],
+    ["round_id", getU64Decoder()],
+    ["end_at", getU64Decoder()],
+    ["status", roundStatusDecoder],
+    ["participant_count", getU64Decoder()],
+    ["max_participants", getOptionDecoder(getU64Decoder())],
+    ["entry_fee", getU64Decoder()],
+    ["commission_bps", getU16Decoder()],
+    ["winner", getOptionDecoder(getAddressDecoder())],
+    ["draw_slot", getU64Decoder()],
+    ["draw", getOptionDecoder(drawDecoder)],
+    ["activation_bounty", getU64Decoder()],
+    ["reschedule_count", getU32Decoder()],
+    ["schedule_mode", transformDecoder(getU8Decoder(), (tag) => SCHEDULE_MODES[tag])],
+    ["activation_requires_registration", getBooleanDecoder()],
+    ["participation_requires_registration", getBooleanDecoder()],
+    ["reveal_start_at", getOptionDecoder(getU64Decoder())],
+    ["reveal_count", getU64Decoder()],
+    ["scorer", getOptionDecoder(getAddressDecoder())],
+    ["min_participants", getU64Decoder()],
+    ["interest_count", getU64Decoder()],
+    ["held_entries", getU64Decoder()],
+  ]),
+  skipAnchorDiscriminator,
+);
+
+const participantAccountDecoder: Decoder<{
+  round: Address;
+  user: Address;
+  joined_at: bigint;
+  entry_index: bigint;
+}> = offsetDecoder(
+  getStructDecoder([
+    ["round", getAddressDecoder()],
+    ["user", getAddressDecoder()],
+    ["joined_at", getU64Decoder()],
+    ["entry_index", getU64Decoder()],
+  ]),
+  skipAnchorDiscriminator,
+);
+
+const submissionAccountDecoder: Decoder<{
+  round: Address;
+  user: Address;
+  commitment: Uint8Array;
+  committed_at: bigint;
+  value: Option<bigint>;
+  revealed_at: Option<bigint>;
+}> = offsetDecoder(
+  getStructDecoder([
+    ["round", getAddressDecoder()],
+    ["user", getAddressDecoder()],
+    ["commitment", fixDecoderSize(getBytesDecoder(), 32)],
+    ["committed_at", getU64Decoder()],
+    ["value", getOptionDecoder(getU64Decoder())],
+    ["revealed_at", getOptionDecoder(getU64Decoder())],
+  ]),
+  skipAnchorDiscriminator,
+);
+
+export type LeaderboardEntry = { user: Address; score: bigint; scored_at: bigint };
+
+const leaderboardAccountDecoder: Decoder<{
+  round: Address;
+  frozen_at: Option<bigint>;
+  entries: LeaderboardEntry[];
+}> = offsetDecoder(
+  getStructDecoder([
+    ["round", getAddressDecoder()],
+    ["frozen_at", getOptionDecoder(getU64Decoder())],
+    ["entries", getArrayDecoder(
+      getStructDecoder([["user", getAddressDecoder()], ["score", getU64Decoder()], ["scored_at", getU64Decoder()]]),
+      { size: getU32Decoder() },
+    )],
+  ]),
+  skipAnchorDiscriminator,
+);
+
+const seriesAccountDecoder: Decoder<{
+  authority: Address;
+  series_id: bigint;
+  interval_slots: bigint;
+  duration_slots: bigint;
+  round_id: bigint;
+  round_start_at: bigint;
+}> = offsetDecoder(
+  getStructDecoder([
+    ["authority", getAddressDecoder()],
+    ["series_id", getU64Decoder()],
+    ["interval_slots", getU64Decoder()],
+    ["duration_slots", getU64Decoder()],
+    ["round_id", getU64Decoder()],
+    ["round_start_at", getU64Decoder()],
+  ]),
+  skipAnchorDiscriminator,
+);
+
+const roundAuthorityAccountDecoder: Decoder<{
   authority: Address;
-  activated_at: Option<bigint>;
-  activated_by: Option<Address>;
-  completed_at: Option<bigint>;
+  next_round_id: bigint;
 }> = offsetDecoder(
   getStructDecoder([
-    ["start_slot", getU64Decoder()],
     ["authority", getAddressDecoder()],
-    ["activated_at", getOptionDecoder(getU64Decoder())],
-    ["activated_by", getOptionDecoder(getAddressDecoder())],
-    ["completed_at", getOptionDecoder(getU64Decoder())],
+    ["next_round_id", getU64Decoder()],
   ]),
   skipAnchorDiscriminator,
 );
@@ -1,4 +1,5 @@
 import unittest
+import time
 from pathlib import Path
 from dotenv import load_dotenv
 from solders.pubkey import Pubkey
@@ -8,11 +9,34 @@ from solana.rpc.core import RPCException
 from fragments.env_vars import get_env_var
 from fragments.solana_rpc import init_rpc_client, wait_for_slot
 from fragments.solana_airdrop import send_and_confirm_airdrop
+from fragments.solana_balance import get_balance
 from fragments.solana_program_round import (
     initialise_round,
     get_round_account,
+    get_next_round_id,
+    initialise_series,
+    roll_series,
+    get_series_account,
+    close_series,
     activate_round,
     complete_round,
+    settle_round,
+    cancel_round,
+    close_round,
+    close_participant,
+    close_submission,
+    close_interest,
+    commit_submission,
+    reveal_submission,
+    join_round,
+    enter_round,
+    draw_winner,
+    reschedule_round,
+    signal_interest,
+    get_participant_account,
+    get_submission_account,
+    submit_score,
+    get_leaderboard_account,
 )
 from fragments.solana_transaction import confirm_recent_signature
 
@@ -38,15 +62,22 @@ class TestSolanaRoundInterface(unittest.IsolatedAsyncioTestCase):
         recent_slot = (await client.get_slot()).value
 
         sig = await initialise_round(
-            authority=user_keypair, program_address=self.program_id, start_slot=recent_slot + 3
+            authority=user_keypair,
+            program_address=self.program_id,
+            round_id=0,
+            start_slot=recent_slot + 3,
+            end_slot=recent_slot + 103,
         )
         instr_confirmed = await confirm_recent_signature(sig)
         if not instr_confirmed:
             self.fail("Initialise round instruction failed")
 
-        round_account = await get_round_account(user_keypair.pubkey(), self.program_id)
-        self.assertEqual(round_account["start_slot"], recent_slot + 3)
+        round_account = await get_round_account(user_keypair.pubkey(), self.program_id, 0)
+        self.assertEqual(round_account["start_at"], recent_slot + 3)
         self.assertEqual(round_account["authority"], user_keypair.pubkey())
+        self.assertEqual(round_account["round_id"], 0)
+        self.assertEqual(round_account["end_at"], recent_slot + 103)
+        self.assertEqual(round_account["status"], "Scheduled")
         self.assertIsNone(round_account["activated_at"])
         self.assertIsNone(round_account["activated_by"])
         self.assertIsNone(round_account["completed_at"])
@@ -57,40 +88,784 @@ class TestSolanaRoundInterface(unittest.IsolatedAsyncioTestCase):
             self.fail("Failed to reach slot in time")
 
         activate_sig = await activate_round(
-            payer=user_keypair, program_address=self.program_id, authority=user_keypair.pubkey()
+            payer=user_keypair, program_address=self.program_id, authority=user_keypair.pubkey(), round_id=0
         )
         activate_confirmed = await confirm_recent_signature(activate_sig)
         if not activate_confirmed:
             self.fail("Activate round instruction failed")
 
-        round_account = await get_round_account(user_keypair.pubkey(), self.program_id)
+        round_account = await get_round_account(user_keypair.pubkey(), self.program_id, 0)
+        self.assertEqual(round_account["status"], "Active")
         self.assertIsNotNone(round_account["activated_at"])
         self.assertEqual(round_account["activated_by"], user_keypair.pubkey())
 
-        complete_sig = await complete_round(authority=user_keypair, program_address=self.program_id)
+        complete_sig = await complete_round(
+            payer=user_keypair, program_address=self.program_id, authority=user_keypair.pubkey(), round_id=0
+        )
         complete_confirmed = await confirm_recent_signature(complete_sig)
         if not complete_confirmed:
             self.fail("Complete round instruction failed")
 
-        round_account = await get_round_account(user_keypair.pubkey(), self.program_id)
+        round_account = await get_round_account(user_keypair.pubkey(), self.program_id, 0)
+        self.assertEqual(round_account["status"], "Completed")
         self.assertIsNotNone(round_account["completed_at"])
 
+    async def test_solana_join_active_round(self):
+        client = init_rpc_client()
+        authority_keypair = Keypair()
+        user_keypair = Keypair()
+        await send_and_confirm_airdrop(authority_keypair.pubkey(), LAMPORTS_PER_SOL)
+        await send_and_confirm_airdrop(user_keypair.pubkey(), LAMPORTS_PER_SOL)
+        recent_slot = (await client.get_slot()).value
+
+        sig = await initialise_round(
+            authority=authority_keypair,
+            program_address=self.program_id,
+            round_id=0,
+            start_slot=recent_slot + 3,
+            end_slot=recent_slot + 103,
+            max_participants=10,
+        )
+        instr_confirmed = await confirm_recent_signature(sig)
+        if not instr_confirmed:
+            self.fail("Initialise round instruction failed")
+
+        at_slot = await wait_for_slot(recent_slot + 3)
+
+        if not at_slot:
+            self.fail("Failed to reach slot in time")
+
+        activate_sig = await activate_round(
+            payer=authority_keypair, program_address=self.program_id, authority=authority_keypair.pubkey(), round_id=0
+        )
+        activate_confirmed = await confirm_recent_signature(activate_sig)
+        if not activate_confirmed:
+            self.fail("Activate round instruction failed")
+
+        join_sig = await join_round(
+            user=user_keypair, program_address=self.program_id, authority=authority_keypair.pubkey(), round_id=0
+        )
+        join_confirmed = await confirm_recent_signature(join_sig)
+        if not join_confirmed:
+            self.fail("Join round instruction failed")
+
+        participant_account = await get_participant_account(
+            user_keypair.pubkey(), self.program_id, authority_keypair.pubkey(), 0
+        )
+        self.assertEqual(participant_account["user"], user_keypair.pubkey())
+        self.assertEqual(participant_account["entry_index"], 0)
+
+        round_account = await get_round_account(authority_keypair.pubkey(), self.program_id, 0)
+        self.assertEqual(round_account["participant_count"], 1)
+        self.assertEqual(round_account["max_participants"], 10)
+
+    async def test_solana_join_gated_round_without_registration(self):
+        client = init_rpc_client()
+        authority_keypair = Keypair()
+        user_keypair = Keypair()
+        await send_and_confirm_airdrop(authority_keypair.pubkey(), LAMPORTS_PER_SOL)
+        await send_and_confirm_airdrop(user_keypair.pubkey(), LAMPORTS_PER_SOL)
+        recent_slot = (await client.get_slot()).value
+
+        sig = await initialise_round(
+            authority=authority_keypair,
+            program_address=self.program_id,
+            round_id=0,
+            start_slot=recent_slot + 3,
+            end_slot=recent_slot + 103,
+            participation_requires_registration=True,
+        )
+        instr_confirmed = await confirm_recent_signature(sig)
+        if not instr_confirmed:
+            self.fail("Initialise round instruction failed")
+
+        round_account = await get_round_account(authority_keypair.pubkey(), self.program_id, 0)
+        self.assertFalse(round_account["activation_requires_registration"])
+        self.assertTrue(round_account["participation_requires_registration"])
+
+        at_slot = await wait_for_slot(recent_slot + 3)
+
+        if not at_slot:
+            self.fail("Failed to reach slot in time")
+
+        activate_sig = await activate_round(
+            payer=authority_keypair, program_address=self.program_id, authority=authority_keypair.pubkey(), round_id=0
+        )
+        activate_confirmed = await confirm_recent_signature(activate_sig)
+        if not activate_confirmed:
+            self.fail("Activate round instruction failed")
+
+        with self.assertRaises(RPCException) as cm:
+            await join_round(
+                user=user_keypair, program_address=self.program_id, authority=authority_keypair.pubkey(), round_id=0
+            )
+
+        error_str = str(cm.exception)
+        self.assertIn("RegistrationRequired", error_str)
+
+    async def test_solana_enter_paid_round_and_pay_winner(self):
+        client = init_rpc_client()
+        authority_keypair = Keypair()
+        user_keypair = Keypair()
+        await send_and_confirm_airdrop(authority_keypair.pubkey(), LAMPORTS_PER_SOL)
+        await send_and_confirm_airdrop(user_keypair.pubkey(), LAMPORTS_PER_SOL)
+        recent_slot = (await client.get_slot()).value
+        entry_fee = LAMPORTS_PER_SOL // 10
+
+        sig = await initialise_round(
+            authority=authority_keypair,
+            program_address=self.program_id,
+            round_id=0,
+            start_slot=recent_slot + 3,
+            end_slot=recent_slot + 103,
+            entry_fee=entry_fee,
+            commission_bps=1_000,
+        )
+        instr_confirmed = await confirm_recent_signature(sig)
+        if not instr_confirmed:
+            self.fail("Initialise round instruction failed")
+
+        at_slot = await wait_for_slot(recent_slot + 3)
+
+        if not at_slot:
+            self.fail("Failed to reach slot in time")
+
+        activate_sig = await activate_round(
+            payer=authority_keypair, program_address=self.program_id, authority=authority_keypair.pubkey(), round_id=0
+        )
+        activate_confirmed = await confirm_recent_signature(activate_sig)
+        if not activate_confirmed:
+            self.fail("Activate round instruction failed")
+
+        enter_sig = await enter_round(
+            user=user_keypair, program_address=self.program_id, authority=authority_keypair.pubkey(), round_id=0
+        )
+        enter_confirmed = await confirm_recent_signature(enter_sig)
+        if not enter_confirmed:
+            self.fail("Enter round instruction failed")
+
+        complete_sig = await complete_round(
+            payer=authority_keypair,
+            program_address=self.program_id,
+            authority=authority_keypair.pubkey(),
+            round_id=0,
+        )
+        complete_confirmed = await confirm_recent_signature(complete_sig)
+        if not complete_confirmed:
+            self.fail("Complete round instruction failed")
+
+        settle_sig = await settle_round(
+            authority=authority_keypair, program_address=self.program_id, round_id=0, winner=user_keypair.pubkey()
+        )
+        settle_confirmed = await confirm_recent_signature(settle_sig)
+        if not settle_confirmed:
+            self.fail("Settle round instruction failed")
+
+        round_account = await get_round_account(authority_keypair.pubkey(), self.program_id, 0)
+        self.assertEqual(round_account["entry_fee"], entry_fee)
+        self.assertEqual(round_account["commission_bps"], 1_000)
+        self.assertEqual(round_account["winner"], user_keypair.pubkey())
+
+    async def test_solana_draw_winner_after_completion(self):
+        client = init_rpc_client()
+        user_keypair = Keypair()
+        await send_and_confirm_airdrop(user_keypair.pubkey(), LAMPORTS_PER_SOL)
+        recent_slot = (await client.get_slot()).value
+
+        sig = await initialise_round(
+            authority=user_keypair,
+            program_address=self.program_id,
+            round_id=0,
+            start_slot=recent_slot + 3,
+            end_slot=recent_slot + 8,
+        )
+        instr_confirmed = await confirm_recent_signature(sig)
+        if not instr_confirmed:
+            self.fail("Initialise round instruction failed")
+
+        at_slot = await wait_for_slot(recent_slot + 3)
+
+        if not at_slot:
+            self.fail("Failed to reach slot in time")
+
+        activate_sig = await activate_round(
+            payer=user_keypair, program_address=self.program_id, authority=user_keypair.pubkey(), round_id=0
+        )
+        activate_confirmed = await confirm_recent_signature(activate_sig)
+        if not activate_confirmed:
+            self.fail("Activate round instruction failed")
+
+        complete_sig = await complete_round(
+            payer=user_keypair, program_address=self.program_id, authority=user_keypair.pubkey(), round_id=0
+        )
+        complete_confirmed = await confirm_recent_signature(complete_sig)
+        if not complete_confirmed:
+            self.fail("Complete round instruction failed")
+
+        round_account = await get_round_account(user_keypair.pubkey(), self.program_id, 0)
+        self.assertEqual(round_account["draw_slot"], recent_slot + 9)
+
+        # The draw slot's hash is only in SlotHashes once a later slot has been reached
+        at_slot = await wait_for_slot(recent_slot + 11)
+
+        if not at_slot:
+            self.fail("Failed to reach slot in time")
+
+        draw_sig = await draw_winner(
+            payer=user_keypair,
+            program_address=self.program_id,
+            authority=user_keypair.pubkey(),
+            round_id=0,
+            candidates=5,
+        )
+        draw_confirmed = await confirm_recent_signature(draw_sig)
+        if not draw_confirmed:
+            self.fail("Draw winner instruction failed")
+
+        round_account = await get_round_account(user_keypair.pubkey(), self.program_id, 0)
+        draw = round_account["draw"]
+        self.assertIsNotNone(draw)
+        self.assertEqual(draw["candidates"], 5)
+        self.assertLess(draw["index"], 5)
+        self.assertGreaterEqual(draw["slot"], recent_slot + 9)
+
+    async def test_solana_keeper_activates_round_for_bounty(self):
+        client = init_rpc_client()
+        authority_keypair = Keypair()
+        keeper_keypair = Keypair()
+        await send_and_confirm_airdrop(authority_keypair.pubkey(), LAMPORTS_PER_SOL)
+        await send_and_confirm_airdrop(keeper_keypair.pubkey(), LAMPORTS_PER_SOL)
+        recent_slot = (await client.get_slot()).value
+        activation_bounty = LAMPORTS_PER_SOL // 100
+
+        sig = await initialise_round(
+            authority=authority_keypair,
+            program_address=self.program_id,
+            round_id=0,
+            start_slot=recent_slot + 3,
+            end_slot=recent_slot + 103,
+            activation_bounty=activation_bounty,
+        )
+        instr_confirmed = await confirm_recent_signature(sig)
+        if not instr_confirmed:
+            self.fail("Initialise round instruction failed")
+
+        at_slot = await wait_for_slot(recent_slot + 3)
+
+        if not at_slot:
+            self.fail("Failed to reach slot in time")
+
+        activate_sig = await activate_round(
+            payer=keeper_keypair, program_address=self.program_id, authority=authority_keypair.pubkey(), round_id=0
+        )
+        activate_confirmed = await confirm_recent_signature(activate_sig)
+        if not activate_confirmed:
+            self.fail("Activate round instruction failed")
+
+        round_account = await get_round_account(authority_keypair.pubkey(), self.program_id, 0)
+        self.assertEqual(round_account["activation_bounty"], activation_bounty)
+        self.assertEqual(round_account["activated_by"], keeper_keypair.pubkey())
+
+        # The bounty more than covers the keeper's transaction fee
+        self.assertGreater(await get_balance(keeper_keypair.pubkey()), LAMPORTS_PER_SOL)
+
+    async def test_solana_cancel_scheduled_round(self):
+        client = init_rpc_client()
+        user_keypair = Keypair()
+        await send_and_confirm_airdrop(user_keypair.pubkey(), LAMPORTS_PER_SOL)
+        recent_slot = (await client.get_slot()).value
+
+        sig = await initialise_round(
+            authority=user_keypair,
+            program_address=self.program_id,
+            round_id=0,
+            start_slot=recent_slot + 50,
+            end_slot=recent_slot + 150,
+        )
+        instr_confirmed = await confirm_recent_signature(sig)
+        if not instr_confirmed:
+            self.fail("Initialise round instruction failed")
+
+        cancel_sig = await cancel_round(authority=user_keypair, program_address=self.program_id, round_id=0)
+        cancel_confirmed = await confirm_recent_signature(cancel_sig)
+        if not cancel_confirmed:
+            self.fail("Cancel round instruction failed")
+
+        round_account = await get_round_account(user_keypair.pubkey(), self.program_id, 0)
+        self.assertEqual(round_account["status"], "Cancelled")
+        self.assertIsNotNone(round_account["cancelled_at"])
+
+    async def test_solana_close_cancelled_round(self):
+        client = init_rpc_client()
+        user_keypair = Keypair()
+        await send_and_confirm_airdrop(user_keypair.pubkey(), LAMPORTS_PER_SOL)
+        recent_slot = (await client.get_slot()).value
+
+        sig = await initialise_round(
+            authority=user_keypair,
+            program_address=self.program_id,
+            round_id=0,
+            start_slot=recent_slot + 50,
+            end_slot=recent_slot + 150,
+        )
+        instr_confirmed = await confirm_recent_signature(sig)
+        if not instr_confirmed:
+            self.fail("Initialise round instruction failed")
+
+        cancel_sig = await cancel_round(authority=user_keypair, program_address=self.program_id, round_id=0)
+        cancel_confirmed = await confirm_recent_signature(cancel_sig)
+        if not cancel_confirmed:
+            self.fail("Cancel round instruction failed")
+
+        close_sig = await close_round(authority=user_keypair, program_address=self.program_id, round_id=0)
+        close_confirmed = await confirm_recent_signature(close_sig)
+        if not close_confirmed:
+            self.fail("Close round instruction failed")
+
+        with self.assertRaises(ValueError):
+            await get_round_account(user_keypair.pubkey(), self.program_id, 0)
+
+    async def test_solana_reschedule_round(self):
+        client = init_rpc_client()
+        user_keypair = Keypair()
+        await send_and_confirm_airdrop(user_keypair.pubkey(), LAMPORTS_PER_SOL)
+        recent_slot = (await client.get_slot()).value
+
+        sig = await initialise_round(
+            authority=user_keypair,
+            program_address=self.program_id,
+            round_id=0,
+            start_slot=recent_slot + 50,
+            end_slot=recent_slot + 150,
+        )
+        instr_confirmed = await confirm_recent_signature(sig)
+        if not instr_confirmed:
+            self.fail("Initialise round instruction failed")
+
+        reschedule_sig = await reschedule_round(
+            authority=user_keypair, program_address=self.program_id, round_id=0, new_start_slot=recent_slot + 80
+        )
+        reschedule_confirmed = await confirm_recent_signature(reschedule_sig)
+        if not reschedule_confirmed:
+            self.fail("Reschedule round instruction failed")
+
+        round_account = await get_round_account(user_keypair.pubkey(), self.program_id, 0)
+        self.assertEqual(round_account["start_at"], recent_slot + 80)
+        self.assertEqual(round_account["end_at"], recent_slot + 180)
+        self.assertEqual(round_account["reschedule_count"], 1)
+
+    async def test_solana_activate_round_below_minimum_participants(self):
+        client = init_rpc_client()
+        authority_keypair = Keypair()
+        user_keypair = Keypair()
+        await send_and_confirm_airdrop(authority_keypair.pubkey(), LAMPORTS_PER_SOL)
+        await send_and_confirm_airdrop(user_keypair.pubkey(), LAMPORTS_PER_SOL)
+        recent_slot = (await client.get_slot()).value
+
+        sig = await initialise_round(
+            authority=authority_keypair,
+            program_address=self.program_id,
+            round_id=0,
+            start_slot=recent_slot + 10,
+            end_slot=recent_slot + 110,
+            min_participants=2,
+        )
+        instr_confirmed = await confirm_recent_signature(sig)
+        if not instr_confirmed:
+            self.fail("Initialise round instruction failed")
+
+        interest_sig = await signal_interest(
+            user=user_keypair, program_address=self.program_id, authority=authority_keypair.pubkey(), round_id=0
+        )
+        if not await confirm_recent_signature(interest_sig):
+            self.fail("Signal interest instruction failed")
+
+        round_account = await get_round_account(authority_keypair.pubkey(), self.program_id, 0)
+        self.assertEqual(round_account["min_participants"], 2)
+        self.assertEqual(round_account["interest_count"], 1)
+
+        if not await wait_for_slot(recent_slot + 10):
+            self.fail("Failed to reach slot in time")
+
+        with self.assertRaises(RPCException) as cm:
+            await activate_round(
+                payer=authority_keypair,
+                program_address=self.program_id,
+                authority=authority_keypair.pubkey(),
+                round_id=0,
+            )
+
+        error_str = str(cm.exception)
+        self.assertIn("NotEnoughInterest", error_str)
+
+    async def test_solana_schedule_round_by_unix_timestamp(self):
+        user_keypair = Keypair()
+        await send_and_confirm_airdrop(user_keypair.pubkey(), LAMPORTS_PER_SOL)
+        start_time = int(time.time()) + 60
+
+        sig = await initialise_round(
+            authority=user_keypair,
+            program_address=self.program_id,
+            round_id=0,
+            start_slot=start_time,
+            end_slot=start_time + 3600,
+            schedule_mode="UnixTimestamp",
+        )
+        instr_confirmed = await confirm_recent_signature(sig)
+        if not instr_confirmed:
+            self.fail("Initialise round instruction failed")
+
+        round_account = await get_round_account(user_keypair.pubkey(), self.program_id, 0)
+        self.assertEqual(round_account["schedule_mode"], "UnixTimestamp")
+        self.assertEqual(round_account["start_at"], start_time)
+
+        with self.assertRaises(RPCException) as cm:
+            await activate_round(
+                payer=user_keypair, program_address=self.program_id, authority=user_keypair.pubkey(), round_id=0
+            )
+
+        error_str = str(cm.exception)
+        self.assertIn("InvalidRoundActivationSlot", error_str)
+
+    async def test_solana_commit_and_reveal_submission(self):
+        client = init_rpc_client()
+        authority_keypair = Keypair()
+        user_keypair = Keypair()
+        await send_and_confirm_airdrop(authority_keypair.pubkey(), LAMPORTS_PER_SOL)
+        await send_and_confirm_airdrop(user_keypair.pubkey(), LAMPORTS_PER_SOL)
+        recent_slot = (await client.get_slot()).value
+        salt = bytes([7] * 32)
+
+        sig = await initialise_round(
+            authority=authority_keypair,
+            program_address=self.program_id,
+            round_id=0,
+            start_slot=recent_slot + 3,
+            end_slot=recent_slot + 103,
+            reveal_start_at=recent_slot + 23,
+        )
+        instr_confirmed = await confirm_recent_signature(sig)
+        if not instr_confirmed:
+            self.fail("Initialise round instruction failed")
+
+        at_slot = await wait_for_slot(recent_slot + 3)
+
+        if not at_slot:
+            self.fail("Failed to reach slot in time")
+
+        activate_sig = await activate_round(
+            payer=authority_keypair, program_address=self.program_id, authority=authority_keypair.pubkey(), round_id=0
+        )
+        if not await confirm_recent_signature(activate_sig):
+            self.fail("Activate round instruction failed")
+
+        join_sig = await join_round(
+            user=user_keypair, program_address=self.program_id, authority=authority_keypair.pubkey(), round_id=0
+        )
+        if not await confirm_recent_signature(join_sig):
+            self.fail("Join round instruction failed")
+
+        commit_sig = await commit_submission(
+            user_keypair, self.program_id, authority_keypair.pubkey(), 0, value=42, salt=salt
+        )
+        if not await confirm_recent_signature(commit_sig):
+            self.fail("Commit submission instruction failed")
+
+        committed = await get_submission_account(user_keypair.pubkey(), self.program_id, authority_keypair.pubkey(), 0)
+        self.assertEqual(committed["user"], user_keypair.pubkey())
+        self.assertIsNone(committed["value"])
+
+        if not await wait_for_slot(recent_slot + 23):
+            self.fail("Failed to reach reveal slot in time")
+
+        reveal_sig = await reveal_submission(
+            user_keypair, self.program_id, authority_keypair.pubkey(), 0, value=42, salt=salt
+        )
+        if not await confirm_recent_signature(reveal_sig):
+            self.fail("Reveal submission instruction failed")
+
+        revealed = await get_submission_account(user_keypair.pubkey(), self.program_id, authority_keypair.pubkey(), 0)
+        self.assertEqual(revealed["value"], 42)
+
+        round_account = await get_round_account(authority_keypair.pubkey(), self.program_id, 0)
+        self.assertEqual(round_account["reveal_count"], 1)
+
+    async def test_solana_close_user_accounts_of_closed_round(self):
+        client = init_rpc_client()
+        authority_keypair = Keypair()
+        user_keypair = Keypair()
+        await send_and_confirm_airdrop(authority_keypair.pubkey(), LAMPORTS_PER_SOL)
+        await send_and_confirm_airdrop(user_keypair.pubkey(), LAMPORTS_PER_SOL)
+        recent_slot = (await client.get_slot()).value
+        authority = authority_keypair.pubkey()
+
+        sig = await initialise_round(
+            authority=authority_keypair,
+            program_address=self.program_id,
+            round_id=0,
+            start_slot=recent_slot + 10,
+            end_slot=recent_slot + 110,
+            reveal_start_at=recent_slot + 60,
+        )
+        if not await confirm_recent_signature(sig):
+            self.fail("Initialise round instruction failed")
+
+        interest_sig = await signal_interest(
+            user=user_keypair, program_address=self.program_id, authority=authority, round_id=0
+        )
+        if not await confirm_recent_signature(interest_sig):
+            self.fail("Signal interest instruction failed")
+
+        if not await wait_for_slot(recent_slot + 10):
+            self.fail("Failed to reach slot in time")
+
+        activate_sig = await activate_round(
+            payer=authority_keypair, program_address=self.program_id, authority=authority, round_id=0
+        )
+        if not await confirm_recent_signature(activate_sig):
+            self.fail("Activate round instruction failed")
+
+        join_sig = await join_round(user=user_keypair, program_address=self.program_id, authority=authority, round_id=0)
+        if not await confirm_recent_signature(join_sig):
+            self.fail("Join round instruction failed")
+
+        salt = bytes([7] * 32)
+        commit_sig = await commit_submission(user_keypair, self.program_id, authority, 0, value=42, salt=salt)
+        if not await confirm_recent_signature(commit_sig):
+            self.fail("Commit submission instruction failed")
+
+        complete_sig = await complete_round(
+            payer=authority_keypair, program_address=self.program_id, authority=authority, round_id=0
+        )
+        if not await confirm_recent_signature(complete_sig):
+            self.fail("Complete round instruction failed")
+
+        close_sig = await close_round(authority=authority_keypair, program_address=self.program_id, round_id=0)
+        if not await confirm_recent_signature(close_sig):
+            self.fail("Close round instruction failed")
+
+        participant_sig = await close_participant(
+            user=user_keypair, program_address=self.program_id, authority=authority, round_id=0
+        )
+        if not await confirm_recent_signature(participant_sig):
+            self.fail("Close participant instruction failed")
+        with self.assertRaises(ValueError):
+            await get_participant_account(user_keypair.pubkey(), self.program_id, authority, 0)
+
+        submission_sig = await close_submission(
+            user=user_keypair, program_address=self.program_id, authority=authority, round_id=0
+        )
+        if not await confirm_recent_signature(submission_sig):
+            self.fail("Close submission instruction failed")
+        with self.assertRaises(ValueError):
+            await get_submission_account(user_keypair.pubkey(), self.program_id, authority, 0)
+
+        # The interest account's rent comes back to the user, more than their transaction fee
+        balance = await get_balance(user_keypair.pubkey())
+        interest_close_sig = await close_interest(
+            user=user_keypair, program_address=self.program_id, authority=authority, round_id=0
+        )
+        if not await confirm_recent_signature(interest_close_sig):
+            self.fail("Close interest instruction failed")
+        self.assertGreater(await get_balance(user_keypair.pubkey()), balance)
+
+    async def test_solana_score_round_and_freeze_leaderboard(self):
+        client = init_rpc_client()
+        authority_keypair = Keypair()
+        scorer_keypair = Keypair()
+        user_keypairs = [Keypair(), Keypair()]
+        for keypair in [authority_keypair, scorer_keypair, *user_keypairs]:
+            await send_and_confirm_airdrop(keypair.pubkey(), LAMPORTS_PER_SOL)
+        recent_slot = (await client.get_slot()).value
+
+        sig = await initialise_round(
+            authority=authority_keypair,
+            program_address=self.program_id,
+            round_id=0,
+            start_slot=recent_slot + 3,
+            end_slot=recent_slot + 103,
+            scorer=scorer_keypair.pubkey(),
+        )
+        instr_confirmed = await confirm_recent_signature(sig)
+        if not instr_confirmed:
+            self.fail("Initialise round instruction failed")
+
+        at_slot = await wait_for_slot(recent_slot + 3)
+
+        if not at_slot:
+            self.fail("Failed to reach slot in time")
+
+        activate_sig = await activate_round(
+            payer=authority_keypair, program_address=self.program_id, authority=authority_keypair.pubkey(), round_id=0
+        )
+        if not await confirm_recent_signature(activate_sig):
+            self.fail("Activate round instruction failed")
+
+        for user_keypair in user_keypairs:
+            join_sig = await join_round(
+                user=user_keypair, program_address=self.program_id, authority=authority_keypair.pubkey(), round_id=0
+            )
+            if not await confirm_recent_signature(join_sig):
+                self.fail("Join round instruction failed")
+
+        for scorer, user_keypair, score in [
+            (scorer_keypair, user_keypairs[0], 10),
+            (authority_keypair, user_keypairs[1], 20),
+        ]:
+            score_sig = await submit_score(
+                scorer, self.program_id, authority_keypair.pubkey(), 0, user=user_keypair.pubkey(), score=score
+            )
+            if not await confirm_recent_signature(score_sig):
+                self.fail("Submit score instruction failed")
+
+        complete_sig = await complete_round(
+            payer=authority_keypair, program_address=self.program_id, authority=authority_keypair.pubkey(), round_id=0
+        )
+        if not await confirm_recent_signature(complete_sig):
+            self.fail("Complete round instruction failed")
+
+        leaderboard = await get_leaderboard_account(authority_keypair.pubkey(), self.program_id, 0)
+        self.assertEqual(
+            [(entry["user"], entry["score"]) for entry in leaderboard["entries"]],
+            [(user_keypairs[1].pubkey(), 20), (user_keypairs[0].pubkey(), 10)],
+        )
+        self.assertIsNotNone(leaderboard["frozen_at"])
+
+        round_account = await get_round_account(authority_keypair.pubkey(), self.program_id, 0)
+        self.assertEqual(round_account["scorer"], scorer_keypair.pubkey())
+
+    async def test_solana_roll_round_series(self):
+        client = init_rpc_client()
+        authority_keypair = Keypair()
+        keeper_keypair = Keypair()
+        await send_and_confirm_airdrop(authority_keypair.pubkey(), LAMPORTS_PER_SOL)
+        await send_and_confirm_airdrop(keeper_keypair.pubkey(), LAMPORTS_PER_SOL)
+        recent_slot = (await client.get_slot()).value
+        start_slot = recent_slot + 5
+
+        sig = await initialise_series(
+            authority=authority_keypair,
+            program_address=self.program_id,
+            series_id=0,
+            start_slot=start_slot,
+            interval_slots=20,
+            duration_slots=10,
+        )
+        if not await confirm_recent_signature(sig):
+            self.fail("Initialise series instruction failed")
+
+        first_round = await get_round_account(authority_keypair.pubkey(), self.program_id, 0)
+        self.assertEqual(first_round["start_at"], start_slot)
+        self.assertEqual(first_round["end_at"], start_slot + 10)
+
+        if not await wait_for_slot(start_slot + 20):
+            self.fail("Failed to reach slot in time")
+
+        roll_sig = await roll_series(
+            payer=keeper_keypair, program_address=self.program_id, authority=authority_keypair.pubkey(), series_id=0
+        )
+        if not await confirm_recent_signature(roll_sig):
+            self.fail("Roll series instruction failed")
+
+        series = await get_series_account(authority_keypair.pubkey(), self.program_id, 0)
+        self.assertEqual(series["round_id"], 1)
+        self.assertEqual(series["round_start_at"], start_slot + 20)
+
+        rolled_round = await get_round_account(authority_keypair.pubkey(), self.program_id, 0)
+        self.assertEqual(rolled_round["status"], "Cancelled")
+
+        next_round = await get_round_account(authority_keypair.pubkey(), self.program_id, 1)
+        self.assertEqual(next_round["start_at"], start_slot + 20)
+        self.assertEqual(next_round["status"], "Scheduled")
+
+        close_sig = await close_series(authority=authority_keypair, program_address=self.program_id, series_id=0)
+        if not await confirm_recent_signature(close_sig):
+            self.fail("Close series instruction failed")
+
+        with self.assertRaises(ValueError):
+            await get_series_account(authority_keypair.pubkey(), self.program_id, 0)
+
+    async def test_solana_initialise_successive_rounds(self):
+        client = init_rpc_client()
+        user_keypair = Keypair()
+        await send_and_confirm_airdrop(user_keypair.pubkey(), LAMPORTS_PER_SOL)
+        recent_slot = (await client.get_slot()).value
+
+        self.assertEqual(await get_next_round_id(user_keypair.pubkey(), self.program_id), 0)
+
+        for round_id in [0, 1]:
+            sig = await initialise_round(
+                authority=user_keypair,
+                program_address=self.program_id,
+                round_id=round_id,
+                start_slot=recent_slot + 50,
+                end_slot=recent_slot + 150,
+            )
+            instr_confirmed = await confirm_recent_signature(sig)
+            if not instr_confirmed:
+                self.fail("Initialise round instruction failed")
+
+            round_account = await get_round_account(user_keypair.pubkey(), self.program_id, round_id)
+            self.assertEqual(round_account["round_id"], round_id)
+
+        self.assertEqual(await get_next_round_id(user_keypair.pubkey(), self.program_id), 2)
+
+    async def test_solana_initialise_round_invalid_round_id(self):
+        client = init_rpc_client()
+        user_keypair = Keypair()
+        await send_and_confirm_airdrop(user_keypair.pubkey(), LAMPORTS_PER_SOL)
+        recent_slot = (await client.get_slot()).value
+
+        with self.assertRaises(RPCException) as cm:
+            await initialise_round(
+                authority=user_keypair,
+                program_address=self.program_id,
+                round_id=1,
+                start_slot=recent_slot + 50,
+                end_slot=recent_slot + 150,
+            )
+
+        error_str = str(cm.exception)
+        self.assertIn("InvalidRoundId", error_str)
+
     async def test_solana_initialise_round_invalid_slot(self):
         user_keypair = Keypair()
         await send_and_confirm_airdrop(user_keypair.pubkey(), LAMPORTS_PER_SOL)
 
         with self.assertRaises(RPCException) as cm:
-            await initialise_round(authority=user_keypair, program_address=self.program_id, start_slot=0)
+            await initialise_round(
+                authority=user_keypair, program_address=self.program_id, round_id=0, start_slot=0, end_slot=100
+            )
 
         error_str = str(cm.exception)
         self.assertIn("InvalidStartSlot", error_str)
 
+    async def test_solana_initialise_round_invalid_end_slot(self):
+        client = init_rpc_client()
+        user_keypair = Keypair()
+        await send_and_confirm_airdrop(user_keypair.pubkey(), LAMPORTS_PER_SOL)
+        recent_slot = (await client.get_slot()).value
+
+        with self.assertRaises(RPCException) as cm:
+            await initialise_round(
+                authority=user_keypair,
+                program_address=self.program_id,
+                round_id=0,
+                start_slot=recent_slot + 50,
+                end_slot=recent_slot + 50,
+            )
+
+        error_str = str(cm.exception)
+        self.assertIn("InvalidEndSlot", error_str)
+
     async def test_solana_activate_round_without_initialise(self):
         user_keypair = Keypair()
         await send_and_confirm_airdrop(user_keypair.pubkey(), LAMPORTS_PER_SOL)
 
         with self.assertRaises(RPCException) as cm:
-            await activate_round(payer=user_keypair, program_address=self.program_id, authority=user_keypair.pubkey())
+            await activate_round(
+                payer=user_keypair, program_address=self.program_id, authority=user_keypair.pubkey(), round_id=0
+            )
 
         error_str = str(cm.exception)
         self.assertIn("AccountNotInitialized", error_str)
@@ -102,14 +877,20 @@ class TestSolanaRoundInterface(unittest.IsolatedAsyncioTestCase):
         recent_slot = (await client.get_slot()).value
 
         sig = await initialise_round(
-            authority=user_keypair, program_address=self.program_id, start_slot=recent_slot + 50
+            authority=user_keypair,
+            program_address=self.program_id,
+            round_id=0,
+            start_slot=recent_slot + 50,
+            end_slot=recent_slot + 150,
         )
         instr_confirmed = await confirm_recent_signature(sig)
         if not instr_confirmed:
             self.fail("Initialise round instruction failed")
 
         with self.assertRaises(RPCException) as cm:
-            await activate_round(payer=user_keypair, program_address=self.program_id, authority=user_keypair.pubkey())
+            await activate_round(
+                payer=user_keypair, program_address=self.program_id, authority=user_keypair.pubkey(), round_id=0
+            )
 
         error_str = str(cm.exception)
         self.assertIn("InvalidRoundActivationSlot", error_str)
@@ -119,7 +900,9 @@ class TestSolanaRoundInterface(unittest.IsolatedAsyncioTestCase):
         await send_and_confirm_airdrop(user_keypair.pubkey(), LAMPORTS_PER_SOL)
 
         with self.assertRaises(RPCException) as cm:
-            await complete_round(authority=user_keypair, program_address=self.program_id)
+            await complete_round(
+            payer=user_keypair, program_address=self.program_id, authority=user_keypair.pubkey(), round_id=0
+        )
 
         error_str = str(cm.exception)
         self.assertIn("AccountNotInitialized", error_str)
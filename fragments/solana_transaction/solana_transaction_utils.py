@@ -1,5 +1,6 @@
 import asyncio
 import time
+from typing import Optional
 from solders.signature import Signature
 from solders.keypair import Keypair
 from solders.instruction import Instruction
@@ -25,8 +26,12 @@ async def confirm_recent_signature(signature: Signature, timeout: float = 5.0) -
     return False
 
 
+# `preceding_instructions` run ahead of `instruction`, e.g. a signature check it reads back
 async def create_tx_with_fee_payer_and_lifetime(
-    user_keypair: Keypair, instruction: Instruction
+    user_keypair: Keypair,
+    instruction: Instruction,
+    additional_signers: Optional[list[Keypair]] = None,
+    preceding_instructions: Optional[list[Instruction]] = None,
 ) -> VersionedTransaction:
     client = init_rpc_client()
     latest_blockhash = (await client.get_latest_blockhash()).value.blockhash
@@ -34,8 +39,8 @@ async def create_tx_with_fee_payer_and_lifetime(
     msg = MessageV0.try_compile(
         payer=user_keypair.pubkey(),
         recent_blockhash=latest_blockhash,
-        instructions=[instruction],
+        instructions=[*(preceding_instructions or []), instruction],
         address_lookup_table_accounts=[],
     )
 
-    return VersionedTransaction(msg, [user_keypair])
+    return VersionedTransaction(msg, [user_keypair, *(additional_signers or [])])
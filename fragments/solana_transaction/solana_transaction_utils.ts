@@ -56,10 +56,14 @@ export const createBaseTxWithFeePayerAndLifetime = async (feePayer: Address) =>
   return tx;
 };
 
-export const signAndSendTransaction = async (tx: CompilableTransactionMessage, keypair: KeyPairSigner["keyPair"]) => {
+export const signAndSendTransaction = async (
+  tx: CompilableTransactionMessage,
+  keypair: KeyPairSigner["keyPair"],
+  ...additionalKeypairs: KeyPairSigner["keyPair"][]
+) => {
   const client = initRpcClient();
   const compiledTx = compileTransaction(tx);
-  const signedTx = await signTransaction([keypair], compiledTx);
+  const signedTx = await signTransaction([keypair, ...additionalKeypairs], compiledTx);
   const serializedTransaction = getBase64EncodedWireTransaction(signedTx);
   const signature = await client.sendTransaction(serializedTransaction, { encoding: "base64" }).send();
 